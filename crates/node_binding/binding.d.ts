@@ -157,6 +157,7 @@ export const enum BuiltinPluginName {
   ModuleChunkFormatPlugin = 'ModuleChunkFormatPlugin',
   HotModuleReplacementPlugin = 'HotModuleReplacementPlugin',
   LimitChunkCountPlugin = 'LimitChunkCountPlugin',
+  AggressiveSplittingPlugin = 'AggressiveSplittingPlugin',
   WorkerPlugin = 'WorkerPlugin',
   WebWorkerTemplatePlugin = 'WebWorkerTemplatePlugin',
   MergeDuplicateChunksPlugin = 'MergeDuplicateChunksPlugin',
@@ -170,6 +171,11 @@ export const enum BuiltinPluginName {
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
+  NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
+  NaturalChunkIdsPlugin = 'NaturalChunkIdsPlugin',
+  OccurrenceModuleIdsPlugin = 'OccurrenceModuleIdsPlugin',
+  OccurrenceChunkIdsPlugin = 'OccurrenceChunkIdsPlugin',
+  HashedModuleIdsPlugin = 'HashedModuleIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -181,6 +187,7 @@ export const enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -420,6 +427,15 @@ export interface JsModule {
   nameForCondition?: string
 }
 
+export interface JsOriginRecord {
+  module: string
+  moduleIdentifier: string
+  moduleName: string
+  moduleId: string
+  loc: string
+  request: string
+}
+
 export interface JsResolveForSchemeInput {
   resourceData: JsResourceData
   scheme: string
@@ -487,6 +503,7 @@ export interface JsStatsChunk {
   parents?: Array<string>
   children?: Array<string>
   siblings?: Array<string>
+  origins: Array<JsOriginRecord>
 }
 
 export interface JsStatsChunkGroup {
@@ -544,6 +561,10 @@ export interface JsStatsModule {
   source?: string | Buffer
   profile?: JsStatsModuleProfile
   orphan: boolean
+  depth?: number
+  providedExports?: Array<string>
+  usedExports?: boolean | Array<string>
+  optimizationBailout: Array<string>
 }
 
 export interface JsStatsModuleIssuer {
@@ -595,6 +616,13 @@ export interface PathWithInfo {
   info: JsAssetInfo
 }
 
+export interface RawAggressiveSplittingPluginOptions {
+  minSize?: number
+  maxSize?: number
+  chunkOverhead?: number
+  entryChunkMultiplicator?: number
+}
+
 export interface RawAssetGeneratorDataUrl {
   type: "options"
   options?: RawAssetGeneratorDataUrlOptions
@@ -671,6 +699,9 @@ export interface RawCacheGroupOptions {
   automaticNameDelimiter?: string
   minChunks?: number
   minSize?: number
+  minSizeReduction?: number
+  enforceSizeThreshold?: number
+  minRemainingSize?: number
   maxSize?: number
   maxAsyncSize?: number
   maxInitialSize?: number
@@ -695,6 +726,12 @@ export interface RawCacheOptions {
   version: string
 }
 
+export interface RawChunkLoadRetry {
+  maxRetries: number
+  retryDelay: number
+  fallbackPublicPaths: Array<string>
+}
+
 export interface RawChunkOptionNameCtx {
   module: JsModule
 }
@@ -789,9 +826,22 @@ export interface RawEntryPluginOptions {
   options: RawEntryOptions
 }
 
+export interface RawEnvironment {
+  arrowFunction: boolean
+  const: boolean
+  destructuring: boolean
+  forOf: boolean
+  dynamicImport: boolean
+  module: boolean
+  optionalChaining: boolean
+  templateLiteral: boolean
+}
+
 export interface RawExperiments {
   newSplitChunks: boolean
   topLevelAwait: boolean
+  cacheUnaffected: boolean
+  inlineConst: boolean
   rspackFuture: RawRspackFuture
 }
 
@@ -854,6 +904,13 @@ export interface RawGeneratorOptions {
   assetResource?: RawAssetResourceGeneratorOptions
 }
 
+export interface RawHashedModuleIdsPluginOptions {
+  context?: string
+  hashFunction?: string
+  hashDigest?: string
+  hashDigestLength?: number
+}
+
 export interface RawHtmlRspackPluginOptions {
   /** emitted file name in output path */
   filename?: string
@@ -871,6 +928,10 @@ export interface RawHtmlRspackPluginOptions {
   chunks?: Array<string>
   excludedChunks?: Array<string>
   sri?: "sha256" | "sha384" | "sha512"
+  /** "initial" or "all" */
+  sriMode?: "initial" | "all"
+  /** "preload" or "prefetch" */
+  resourceHint?: "preload" | "prefetch"
   minify?: boolean
   title?: string
   favicon?: string
@@ -995,7 +1056,7 @@ export interface RawModuleRule {
  *   - a `None` on rust side and handled by js side `getOptions` when
  * using with `loader`.
  *   - a `Some(string)` on rust side, deserialized by `serde_json::from_str`
- * and passed to rust side loader in [get_builtin_loader] when using with
+ * and passed to the rust side loader registered as a builtin loader when using with
  * `builtin_loader`.
  */
 export interface RawModuleRuleUse {
@@ -1015,6 +1076,10 @@ export interface RawNodeOption {
   global: string
 }
 
+export interface RawOccurrenceIdsPluginOptions {
+  prioritiseInitial?: boolean
+}
+
 export interface RawOptimizationOptions {
   removeAvailableModules: boolean
   sideEffects: string
@@ -1035,6 +1100,7 @@ export interface RawOptions {
   module: RawModuleOptions
   devtool: string
   optimization: RawOptimizationOptions
+  performance?: RawPerformanceOptions
   stats: RawStatsOptions
   snapshot: RawSnapshotOptions
   cache: RawCacheOptions
@@ -1042,6 +1108,8 @@ export interface RawOptions {
   node?: RawNodeOption
   profile: boolean
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   builtins: RawBuiltins
 }
 
@@ -1056,6 +1124,9 @@ export interface RawOutputOptions {
   filename: string
   chunkFilename: string
   crossOriginLoading: RawCrossOriginLoading
+  charset: boolean
+  chunkLoadTimeout: number
+  chunkLoadRetry?: RawChunkLoadRetry
   cssFilename: string
   cssChunkFilename: string
   hotUpdateMainFilename: string
@@ -1083,6 +1154,7 @@ export interface RawOutputOptions {
   workerWasmLoading: string
   workerPublicPath: string
   scriptType: "module" | "text/javascript" | "false"
+  environment: RawEnvironment
 }
 
 export interface RawParserOptions {
@@ -1091,6 +1163,13 @@ export interface RawParserOptions {
   javascript?: RawJavascriptParserOptions
 }
 
+export interface RawPerformanceOptions {
+  hints: "false" | "warning" | "error"
+  maxAssetSize: number
+  maxEntrypointSize: number
+  assetFilter?: RawRuleSetCondition
+}
+
 export interface RawPluginImportConfig {
   libraryName: string
   libraryDirectory?: string
@@ -1231,6 +1310,7 @@ export interface RawSplitChunksOptions {
   minChunks?: number
   hidePathInfo?: boolean
   minSize?: number
+  minSizeReduction?: number
   enforceSizeThreshold?: number
   minRemainingSize?: number
   maxSize?: number
@@ -1240,6 +1320,7 @@ export interface RawSplitChunksOptions {
 
 export interface RawStatsOptions {
   colors: boolean
+  diagnosticsFormat?: "graphical" | "json" | "sarif"
 }
 
 export interface RawStyleConfig {
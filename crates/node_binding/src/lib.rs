@@ -96,7 +96,8 @@ impl Rspack {
       plugins,
       AsyncNodeWritableFileSystem::new(env, output_filesystem)
         .map_err(|e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)))?,
-    );
+    )
+    .map_err(|e| Error::from_reason(format!("{e}")))?;
 
     let id = NEXT_COMPILER_ID.fetch_add(1, Ordering::SeqCst);
     unsafe { COMPILERS.insert_if_vacant(id, Box::pin(rspack)) }?;
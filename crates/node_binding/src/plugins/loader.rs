@@ -1,8 +1,9 @@
 use std::{fmt::Debug, path::Path, sync::Arc};
 
-use rspack_binding_options::{get_builtin_loader, JsLoaderAdapter, JsLoaderRunner};
+use rspack_binding_options::{builtin_loaders, JsLoaderAdapter, JsLoaderRunner};
 use rspack_core::{
-  BoxLoader, CompilerOptions, NormalModule, Plugin, ResolveResult, Resolver, BUILTIN_LOADER_PREFIX,
+  ApplyContext, BoxLoader, CompilerOptions, NormalModule, Plugin, PluginContext, ResolveResult,
+  Resolver, BUILTIN_LOADER_PREFIX,
 };
 use rspack_error::{error, Result};
 
@@ -20,6 +21,17 @@ impl Debug for JsLoaderResolver {
 
 #[async_trait::async_trait]
 impl Plugin for JsLoaderResolver {
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    for (name, factory) in builtin_loaders() {
+      ctx.context.register_builtin_loader(name, factory);
+    }
+    Ok(())
+  }
+
   async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
     let contains_inline = module.contains_inline_loader();
     let contains_js_loader = module
@@ -53,7 +65,7 @@ impl Plugin for JsLoaderResolver {
     context: &Path,
     resolver: &Resolver,
     loader_request: &str,
-    loader_options: Option<&str>,
+    _loader_options: Option<&str>,
   ) -> Result<Option<BoxLoader>> {
     let mut rest = None;
     let prev = if let Some(index) = loader_request.find('?') {
@@ -63,10 +75,6 @@ impl Plugin for JsLoaderResolver {
       Path::new(loader_request)
     };
 
-    if loader_request.starts_with(BUILTIN_LOADER_PREFIX) {
-      return Ok(Some(get_builtin_loader(loader_request, loader_options)));
-    }
-
     let resolve_result = resolver
       .resolve(context, &prev.to_string_lossy())
       .map_err(|err| {
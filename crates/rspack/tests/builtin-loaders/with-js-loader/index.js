@@ -0,0 +1,3 @@
+import { answer, fromJsLoader } from "./answer";
+
+console.log(answer, fromJsLoader);
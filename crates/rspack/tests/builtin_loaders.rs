@@ -0,0 +1,183 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use rspack_core::{
+  builtin_loader_factory, ApplyContext, BoxLoader, CompilerOptions, LoaderRunnerContext,
+  ModuleRuleUse, NormalModule, Plugin, PluginContext, PluginExt, Resolver, BUILTIN_LOADER_PREFIX,
+};
+use rspack_error::Result;
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use rspack_testing::test_fixture;
+use serde::Deserialize;
+use testing_macros::fixture;
+
+#[derive(Debug, Deserialize)]
+struct BannerLoaderOptions {
+  banner: String,
+}
+
+/// Prepends `banner` to the module as a comment.
+struct BannerLoader {
+  identifier: Identifier,
+  options: BannerLoaderOptions,
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for BannerLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    let content = std::mem::take(&mut loader_context.content).expect("Content should be available");
+    let source = format!(
+      "/* {} */\n{}",
+      self.options.banner,
+      content.try_into_string()?
+    );
+    loader_context.content = Some(source.into());
+    Ok(())
+  }
+}
+
+impl Identifiable for BannerLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+/// Stands for a JS loader, exporting that it ran.
+struct JsLoader {
+  identifier: Identifier,
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for JsLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    let content = std::mem::take(&mut loader_context.content).expect("Content should be available");
+    let source = format!(
+      "{}\nexport const fromJsLoader = true;\n",
+      content.try_into_string()?
+    );
+    loader_context.content = Some(source.into());
+    Ok(())
+  }
+}
+
+impl Identifiable for JsLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+/// Runs the loaders of a module like the JS loader runner: the builtin loaders are created from
+/// the registry of the compiler, as `runBuiltinLoader` of the binding does.
+struct JsLoaderRunner {
+  identifier: Identifier,
+  loaders: Vec<BoxLoader>,
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for JsLoaderRunner {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    for loader in self.loaders.iter().rev() {
+      let identifier = loader.identifier();
+      if identifier.starts_with(BUILTIN_LOADER_PREFIX) {
+        let options = builtin_loader_options(&loader_context.context.options, &identifier);
+        let builtin = loader_context
+          .context
+          .plugin_driver
+          .builtin_loader_registry
+          .get(&identifier, options.as_deref())?;
+        builtin.run(loader_context).await?;
+      } else {
+        loader.run(loader_context).await?;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl Identifiable for JsLoaderRunner {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+/// The options of `loader` in the rules, as the JS loader runner reads them from the rule set.
+fn builtin_loader_options(options: &CompilerOptions, loader: &str) -> Option<String> {
+  let name = loader.split('?').next().unwrap_or(loader);
+  options
+    .module
+    .rules
+    .iter()
+    .find_map(|rule| match &rule.r#use {
+      ModuleRuleUse::Array(uses) => uses.iter().find(|u| u.loader == name),
+      ModuleRuleUse::Func(_) => None,
+    })
+    .and_then(|u| u.options.clone())
+}
+
+#[derive(Debug)]
+struct TestPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for TestPlugin {
+  fn name(&self) -> &'static str {
+    "TestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.register_builtin_loader(
+      "builtin:banner-loader",
+      builtin_loader_factory(|identifier, options: BannerLoaderOptions| {
+        Arc::new(BannerLoader {
+          identifier: identifier.into(),
+          options,
+        })
+      }),
+    );
+    Ok(())
+  }
+
+  async fn resolve_loader(
+    &self,
+    _compiler_options: &CompilerOptions,
+    _context: &Path,
+    _resolver: &Resolver,
+    loader_request: &str,
+    _loader_options: Option<&str>,
+  ) -> Result<Option<BoxLoader>> {
+    Ok(Some(Arc::new(JsLoader {
+      identifier: loader_request.into(),
+    })))
+  }
+
+  async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
+    // Like the binding, switch to the JS loader runner if there's any JS loader
+    if module
+      .loaders()
+      .iter()
+      .any(|l| !l.identifier().starts_with(BUILTIN_LOADER_PREFIX))
+    {
+      let loaders = std::mem::take(module.loaders_mut_vec());
+      *module.loaders_mut_vec() = vec![Arc::new(JsLoaderRunner {
+        identifier: "js-loader-runner".into(),
+        loaders,
+      })];
+    }
+    Ok(())
+  }
+}
+
+#[fixture("tests/builtin-loaders/*")]
+fn builtin_loaders(fixture_path: PathBuf) {
+  test_fixture(
+    &fixture_path,
+    // Before the resolver of the builtin loaders, which rejects JS loaders
+    Box::new(|plugins, _| plugins.insert(0, TestPlugin.boxed())),
+    None,
+  );
+}
@@ -0,0 +1,276 @@
+use std::path::{Path, PathBuf};
+
+use insta::{assert_snapshot, Settings};
+use rspack_core::{
+  BoxPlugin, Compiler, CompilerOptions, DiagnosticMatcher, DiagnosticSeverityOverride,
+  DiagnosticSeverityRule, DiagnosticsFormat,
+};
+use rspack_error::{codes, Diagnostic};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_javascript::FlagDependencyExportsPlugin;
+use rspack_regex::RspackRegex;
+use rspack_testing::{apply_from_fixture, fixture};
+
+#[tokio::main]
+async fn compile_fixture(
+  fixture_path: &Path,
+  mut_options: impl FnOnce(&mut CompilerOptions, &mut Vec<BoxPlugin>),
+) -> Compiler<AsyncNativeFileSystem> {
+  let (mut options, mut plugins) = apply_from_fixture(fixture_path);
+  mut_options(&mut options, &mut plugins);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
+  compiler.build().await.expect("should build");
+  compiler
+}
+
+/// Build `tests/diagnostics/rules`, which has a missing module and an invalid magic comment.
+fn compile(mut_options: impl FnOnce(&mut CompilerOptions)) -> Compiler<AsyncNativeFileSystem> {
+  compile_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/diagnostics/rules"),
+    |options, _| mut_options(options),
+  )
+}
+
+/// Returns the codes of the warnings and errors.
+fn build(mut_options: impl FnOnce(&mut CompilerOptions)) -> (Vec<String>, Vec<String>) {
+  let compiler = compile(mut_options);
+  let codes = |diagnostics: Vec<&Diagnostic>| {
+    diagnostics
+      .into_iter()
+      .map(|d| d.code().expect("builtin diagnostics should have a code"))
+      .collect()
+  };
+  let compilation = &compiler.compilation;
+  (
+    codes(compilation.get_warnings().collect()),
+    codes(compilation.get_errors().collect()),
+  )
+}
+
+fn code(code: &str) -> DiagnosticMatcher {
+  DiagnosticMatcher {
+    code: Some(code.to_string()),
+    ..Default::default()
+  }
+}
+
+fn regex(source: &str) -> Option<RspackRegex> {
+  Some(RspackRegex::new(source).expect("should be a valid regex"))
+}
+
+#[test]
+fn builtin_codes() {
+  let (warnings, errors) = build(|_| {});
+  assert_eq!(warnings, vec![codes::COMMENT_COMPILATION_WARNING]);
+  assert_eq!(errors, vec![codes::MODULE_NOT_FOUND]);
+}
+
+#[test]
+fn ignore_warnings() {
+  let (warnings, errors) = build(|options| {
+    options.ignore_warnings = vec![DiagnosticMatcher {
+      module: regex(r"index\.js$"),
+      message: regex("webpackPrefetch"),
+      ..Default::default()
+    }];
+  });
+  assert!(warnings.is_empty());
+  assert_eq!(errors, vec![codes::MODULE_NOT_FOUND]);
+
+  // Errors are never ignored
+  let (warnings, errors) = build(|options| {
+    options.ignore_warnings = vec![code(codes::MODULE_NOT_FOUND), code("UnknownWarning")];
+  });
+  assert_eq!(warnings, vec![codes::COMMENT_COMPILATION_WARNING]);
+  assert_eq!(errors, vec![codes::MODULE_NOT_FOUND]);
+}
+
+#[test]
+fn severity_overrides() {
+  let (warnings, errors) = build(|options| {
+    options.diagnostic_severity = vec![
+      DiagnosticSeverityRule {
+        matcher: code(codes::MODULE_NOT_FOUND),
+        severity: DiagnosticSeverityOverride::Warning,
+      },
+      DiagnosticSeverityRule {
+        matcher: code(codes::COMMENT_COMPILATION_WARNING),
+        severity: DiagnosticSeverityOverride::Error,
+      },
+    ];
+  });
+  assert_eq!(warnings, vec![codes::MODULE_NOT_FOUND]);
+  assert_eq!(errors, vec![codes::COMMENT_COMPILATION_WARNING]);
+
+  // The first matching rule wins, and the remapped warnings can be ignored
+  let (warnings, errors) = build(|options| {
+    options.diagnostic_severity = vec![
+      DiagnosticSeverityRule {
+        matcher: code(codes::COMMENT_COMPILATION_WARNING),
+        severity: DiagnosticSeverityOverride::Off,
+      },
+      DiagnosticSeverityRule {
+        matcher: DiagnosticMatcher::default(),
+        severity: DiagnosticSeverityOverride::Warning,
+      },
+    ];
+    options.ignore_warnings = vec![DiagnosticMatcher {
+      message: regex("Can't resolve '\\./missing'"),
+      ..Default::default()
+    }];
+  });
+  assert!(warnings.is_empty());
+  assert!(errors.is_empty());
+}
+
+#[test]
+fn json_format() {
+  let compiler = compile(|options| options.stats.diagnostics_format = DiagnosticsFormat::Json);
+  let report = compiler
+    .compilation
+    .get_stats()
+    .emit_diagnostics_string(true)
+    .expect("should emit diagnostics");
+  let report: serde_json::Value = serde_json::from_str(&report).expect("should be json");
+
+  let [warning, error] = report.as_array().expect("should be an array").as_slice() else {
+    panic!("expected a warning and an error, got {report:#}");
+  };
+  assert_eq!(warning["severity"], "warning");
+  assert_eq!(warning["code"], codes::COMMENT_COMPILATION_WARNING);
+  assert!(warning["message"]
+    .as_str()
+    .is_some_and(|m| m.contains("`webpackPrefetch` expected true or a number")));
+  assert_eq!(warning["spans"][0]["start"]["line"], 2);
+  assert_eq!(warning["spans"][0]["start"]["column"], 1);
+
+  assert_eq!(error["severity"], "error");
+  assert_eq!(error["code"], codes::MODULE_NOT_FOUND);
+  assert!(error["file"]
+    .as_str()
+    .is_some_and(|file| file.ends_with("index.js")));
+  assert!(error["moduleIdentifier"]
+    .as_str()
+    .is_some_and(|identifier| identifier.ends_with("index.js")));
+  // The source of resolve errors is read from the file, the span is the request `"./missing"`
+  assert_eq!(error["spans"][0]["start"]["line"], 1);
+  assert_eq!(error["spans"][0]["start"]["column"], 8);
+  assert_eq!(error["spans"][0]["end"]["column"], 19);
+}
+
+#[test]
+fn sarif_format() {
+  let compiler = compile(|options| options.stats.diagnostics_format = DiagnosticsFormat::Sarif);
+  let report = compiler
+    .compilation
+    .get_stats()
+    .emit_diagnostics_string(true)
+    .expect("should emit diagnostics");
+  let report: serde_json::Value = serde_json::from_str(&report).expect("should be json");
+
+  assert_eq!(report["version"], "2.1.0");
+  let run = &report["runs"][0];
+  assert_eq!(run["columnKind"], "unicodeCodePoints");
+  assert_eq!(
+    run["tool"]["driver"]["rules"],
+    serde_json::json!([
+      { "id": codes::COMMENT_COMPILATION_WARNING },
+      { "id": codes::MODULE_NOT_FOUND }
+    ])
+  );
+  let error = &run["results"][1];
+  assert_eq!(error["ruleId"], codes::MODULE_NOT_FOUND);
+  assert_eq!(error["level"], "error");
+  let location = &error["locations"][0];
+  assert!(location["physicalLocation"]["artifactLocation"]["uri"]
+    .as_str()
+    .is_some_and(|uri| uri.starts_with("file://") && uri.ends_with("index.js")));
+  assert_eq!(location["physicalLocation"]["region"]["startLine"], 1);
+  assert_eq!(location["logicalLocations"][0]["kind"], "module");
+}
+
+/// The codes and rendered reports of the errors.
+fn render_errors(compiler: &Compiler<AsyncNativeFileSystem>) -> String {
+  compiler
+    .compilation
+    .get_errors()
+    .map(|error| {
+      format!(
+        "{}\n{}",
+        error
+          .code()
+          .expect("builtin diagnostics should have a code"),
+        error.render_report(false).expect("should render the error")
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Snapshot the errors reported for the `webassembly/sync` modules, with the webpack based
+/// exports analysis. The default tree shaking reports the same errors for these fixtures,
+/// as the imported modules export all their names themselves.
+#[fixture("tests/diagnostics/wasm/*")]
+fn wasm(fixture_path: PathBuf) {
+  let compiler = compile_fixture(&fixture_path, |options, plugins| {
+    options.experiments.rspack_future.new_treeshaking = true;
+    plugins.push(Box::<FlagDependencyExportsPlugin>::default());
+  });
+  let errors = render_errors(&compiler);
+  assert_eq!(
+    render_errors(&compile_fixture(&fixture_path, |_, _| {})),
+    errors
+  );
+
+  let mut settings = Settings::clone_current();
+  settings.set_snapshot_path(fixture_path.join("snapshot"));
+  settings.set_omit_expression(true);
+  settings.set_prepend_module_to_snapshot(false);
+  settings.bind(|| assert_snapshot!("errors", errors));
+}
+
+/// Build `tests/diagnostics/export-not-found`, returns the messages of the warnings.
+fn export_not_found_warnings(
+  mut_options: impl FnOnce(&mut CompilerOptions, &mut Vec<BoxPlugin>),
+) -> Vec<String> {
+  let compiler = compile_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/diagnostics/export-not-found"),
+    mut_options,
+  );
+  compiler
+    .compilation
+    .get_warnings()
+    .map(|warning| {
+      assert_eq!(warning.code(), Some(codes::EXPORT_NOT_FOUND.to_string()));
+      warning.message()
+    })
+    .collect()
+}
+
+#[test]
+fn export_not_found() {
+  assert_eq!(
+    export_not_found_warnings(|options, plugins| {
+      options.experiments.rspack_future.new_treeshaking = true;
+      plugins.push(Box::<FlagDependencyExportsPlugin>::default());
+    }),
+    vec![
+      "export 'missing' (imported as 'missing') was not found in './module'",
+      "export 'nope' (imported as 'ns') was not found in './module'",
+      "export 'other' (imported as 'other') was not found in './reexport'",
+    ]
+  );
+}
+
+#[test]
+fn export_not_found_without_flagged_exports() {
+  // Only the names a module exports itself are known, not the ones of `export *`
+  assert_eq!(
+    export_not_found_warnings(|_, _| {}),
+    vec![
+      "export 'missing' (imported as 'missing') was not found in './module'",
+      "export 'nope' (imported as 'ns') was not found in './module'",
+    ]
+  );
+}
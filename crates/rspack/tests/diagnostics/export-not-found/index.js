@@ -0,0 +1,5 @@
+import { value, missing } from "./module";
+import * as ns from "./module";
+import { other } from "./reexport";
+
+console.log(value, missing, ns.value, ns.nope, other);
@@ -0,0 +1,3 @@
+import { getResult } from "./wasm.wasm";
+
+export const result = getResult(1);
@@ -0,0 +1,3 @@
+export function getOtherNumber() {
+	return 22;
+}
@@ -0,0 +1,3 @@
+import { getI64 } from "./other.wasm";
+
+export const value = getI64(1);
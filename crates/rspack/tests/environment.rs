@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use insta::{assert_snapshot, Settings};
+use rspack_core::{
+  BoxPlugin, Compiler, CompilerOptions, Environment, ExternalItem, PluginExt, TrustedTypes,
+};
+use rspack_error::Result;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_externals::ExternalsPlugin;
+use rspack_testing::apply_from_fixture;
+
+/// ES5, and every feature of `output.environment` enabled on its own.
+fn environments() -> Vec<(&'static str, Environment)> {
+  let only = |enable: fn(&mut Environment)| {
+    let mut environment = Environment::default();
+    enable(&mut environment);
+    environment
+  };
+  vec![
+    ("es5", Environment::default()),
+    ("arrow-function", only(|e| e.arrow_function = true)),
+    ("const", only(|e| e.r#const = true)),
+    ("destructuring", only(|e| e.destructuring = true)),
+    ("for-of", only(|e| e.for_of = true)),
+    ("dynamic-import", only(|e| e.dynamic_import = true)),
+    ("module", only(|e| e.module = true)),
+    ("optional-chaining", only(|e| e.optional_chaining = true)),
+    ("template-literal", only(|e| e.template_literal = true)),
+  ]
+}
+
+#[tokio::main]
+async fn compile(
+  fixture: &str,
+  mut_options: impl FnOnce(&mut CompilerOptions, &mut Vec<BoxPlugin>),
+) -> (Compiler<AsyncNativeFileSystem>, Result<()>) {
+  let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/environment")
+    .join(fixture);
+  let (mut options, mut plugins) = apply_from_fixture(&fixture_path);
+  mut_options(&mut options, &mut plugins);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
+  let result = compiler.build().await;
+  (compiler, result)
+}
+
+fn asset(compiler: &Compiler<AsyncNativeFileSystem>, filename: &str) -> String {
+  compiler
+    .compilation
+    .assets()
+    .get(filename)
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .unwrap_or_else(|| panic!("should emit {filename}"))
+}
+
+fn snapshot(name: &str, content: String) {
+  let mut settings = Settings::clone_current();
+  settings
+    .set_snapshot_path(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/environment/snapshot"));
+  settings.set_omit_expression(true);
+  settings.set_prepend_module_to_snapshot(false);
+  settings.bind(|| assert_snapshot!(name, content));
+}
+
+/// The runtime code doesn't depend on `dynamicImport` and `module`.
+#[test]
+fn runtime() {
+  for (name, environment) in environments()
+    .into_iter()
+    .filter(|(name, _)| !matches!(*name, "dynamic-import" | "module"))
+  {
+    let (compiler, result) = compile("runtime", |options, _| {
+      options.output.environment = environment;
+      options.output.trusted_types = Some(TrustedTypes {
+        policy_name: Some("rspack".to_string()),
+      });
+    });
+    result.expect("should build");
+    snapshot(&format!("runtime-{name}"), asset(&compiler, "runtime.js"));
+  }
+}
+
+/// `module` externals are imported with `import()` in scripts, and with `import` in modules.
+/// Without `import()` in the environment, scripts still use it, but with a warning.
+#[test]
+fn module_externals() {
+  for (name, environment) in environments()
+    .into_iter()
+    .filter(|(name, _)| matches!(*name, "es5" | "dynamic-import" | "module"))
+  {
+    for output_module in [false, true] {
+      let (compiler, result) = compile("externals", |options, plugins| {
+        options.output.environment = environment;
+        options.output.module = output_module;
+        plugins.push(
+          ExternalsPlugin::new(
+            "module".to_string(),
+            vec![ExternalItem::String("external-lib".to_string())],
+          )
+          .boxed(),
+        );
+      });
+      let content = match result {
+        Ok(()) => {
+          let warnings = compiler
+            .compilation
+            .get_warnings()
+            .map(|warning| {
+              format!(
+                "{}: {}",
+                warning.code().unwrap_or_default(),
+                warning.message()
+              )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+          format!(
+            "{}\n\n# Warnings\n\n{warnings}",
+            asset(&compiler, "main.js")
+          )
+        }
+        Err(error) => error.to_string(),
+      };
+      let output = if output_module { "module" } else { "script" };
+      snapshot(&format!("externals-{output}-{name}"), content);
+    }
+  }
+}
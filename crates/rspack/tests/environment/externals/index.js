@@ -0,0 +1,3 @@
+import * as lib from "external-lib";
+
+console.log(lib);
@@ -0,0 +1,3 @@
+import("./async").then(function ({ value }) {
+	console.log(value);
+});
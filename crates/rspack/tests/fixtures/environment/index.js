@@ -0,0 +1,3 @@
+import("./async").then(({ value }) => {
+	console.log(value);
+});
@@ -0,0 +1,6 @@
+import { shared } from "./shared";
+import { once } from "./once";
+
+console.log(shared, once);
+import("./lazy-a").then(({ a }) => console.log(a));
+import("./lazy-b").then(({ b }) => console.log(b));
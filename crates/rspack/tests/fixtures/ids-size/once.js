@@ -0,0 +1 @@
+export const once = "once";
@@ -0,0 +1,3 @@
+import c from "pkg-c";
+
+console.log(c);
@@ -0,0 +1,4 @@
+import a from "pkg-a";
+import { feature } from "renamed-b/feature";
+
+console.log(a, feature);
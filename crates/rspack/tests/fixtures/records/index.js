@@ -0,0 +1,6 @@
+import { a } from "./a";
+
+console.log(a);
+import("./lazy").then(({ lazy }) => {
+	console.log(lazy);
+});
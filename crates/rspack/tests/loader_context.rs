@@ -0,0 +1,100 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rspack_core::{
+  builtin_loader_factory,
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, CompilerOptions, DependencyCategory, LoaderContextExt, LoaderRunnerContext, Logger,
+  Plugin, PluginContext, PluginExt, ResolveResult,
+};
+use rspack_error::{error, Result};
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use rspack_testing::test_fixture_insta;
+use serde::de::IgnoredAny;
+use testing_macros::fixture;
+
+/// Resolves the request in the module, emits the resolved file to `assets` and exports its name.
+struct FileLoader {
+  identifier: Identifier,
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for FileLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    let content = std::mem::take(&mut loader_context.content).expect("Content should be available");
+    let request = content.try_into_string()?.trim().to_string();
+    let context = loader_context
+      .resource_path
+      .parent()
+      .expect("should have parent")
+      .to_path_buf();
+    let ResolveResult::Resource(resource) = loader_context
+      .resolve(&context, &request, DependencyCategory::Url)
+      .await?
+    else {
+      return Err(error!("{request} is ignored"));
+    };
+
+    let file_name = resource
+      .path
+      .file_name()
+      .expect("should have file name")
+      .to_string_lossy();
+    let name = format!("assets/{file_name}");
+    let source = std::fs::read(&resource.path).map_err(|e| error!("{e}"))?;
+    loader_context.emit_file(name.clone(), RawSource::from(source).boxed(), None);
+    loader_context
+      .get_logger(None)
+      .log(format!("emitted {name}"));
+
+    loader_context.content = Some(format!("export default {name:?};").into());
+    Ok(())
+  }
+}
+
+impl Identifiable for FileLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+#[derive(Debug)]
+struct TestPlugin;
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> &'static str {
+    "TestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.register_builtin_loader(
+      "builtin:file-loader",
+      builtin_loader_factory(|identifier, _: IgnoredAny| {
+        Arc::new(FileLoader {
+          identifier: identifier.into(),
+        })
+      }),
+    );
+    Ok(())
+  }
+}
+
+#[fixture("tests/loader-context/*")]
+fn loader_context(fixture_path: PathBuf) {
+  let compiler = test_fixture_insta(
+    &fixture_path,
+    &|filename| !filename.contains("runtime.js"),
+    Box::new(|plugins, _| plugins.push(TestPlugin.boxed())),
+  );
+
+  let compilation = &compiler.compilation;
+  let resolved = fixture_path.join("data/answer.txt");
+  assert!(compilation.file_dependencies.contains(&resolved));
+  assert!(compilation
+    .get_logging()
+    .iter()
+    .any(|logs| logs.key().starts_with("builtin:file-loader|")));
+}
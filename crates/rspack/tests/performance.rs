@@ -0,0 +1,83 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use insta::{assert_snapshot, Settings};
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  AssetInfo, BoxPlugin, CompilationAsset, Compiler, Plugin, PluginContext,
+  PluginProcessAssetsOutput, ProcessAssetsArgs,
+};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::{apply_from_fixture, fixture};
+
+#[tokio::main]
+async fn compile_with(
+  fixture_path: &Path,
+  extra_plugins: Vec<BoxPlugin>,
+) -> Compiler<AsyncNativeFileSystem> {
+  let (options, mut plugins) = apply_from_fixture(fixture_path);
+  plugins.extend(extra_plugins);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
+  compiler.build().await.expect("should build");
+  compiler
+}
+
+fn compile(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem> {
+  compile_with(fixture_path, vec![])
+}
+
+/// Snapshot the size limit diagnostics of the `performance` options in `test.config.json`.
+#[fixture("tests/performance/*")]
+fn performance(fixture_path: PathBuf) {
+  let compiler = compile(&fixture_path);
+  let diagnostics = compiler
+    .compilation
+    .get_stats()
+    .emit_diagnostics_string(true)
+    .expect("should emit diagnostics");
+
+  let mut settings = Settings::clone_current();
+  settings.set_snapshot_path(fixture_path.join("snapshot"));
+  settings.set_omit_expression(true);
+  settings.set_prepend_module_to_snapshot(false);
+  settings.bind(|| assert_snapshot!("diagnostics", diagnostics));
+}
+
+/// Emits an asset bigger than any limit which is only used in development.
+#[derive(Debug)]
+struct EmitDevelopmentAssetPlugin;
+
+#[async_trait]
+impl Plugin for EmitDevelopmentAssetPlugin {
+  async fn process_assets_stage_additional(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    args.compilation.emit_asset(
+      "development.js".to_string(),
+      CompilationAsset::new(
+        Some(RawSource::from("//".repeat(10000)).boxed()),
+        AssetInfo::default().with_development(true),
+      ),
+    );
+    Ok(())
+  }
+}
+
+#[test]
+fn development_assets_are_excluded() {
+  let compiler = compile_with(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/performance/asset-warning"),
+    vec![Box::new(EmitDevelopmentAssetPlugin)],
+  );
+  assert!(compiler.compilation.assets().contains_key("development.js"));
+  let diagnostics = compiler
+    .compilation
+    .get_stats()
+    .emit_diagnostics_string(true)
+    .expect("should emit diagnostics");
+  assert!(diagnostics.contains("main.js"));
+  assert!(!diagnostics.contains("development.js"));
+}
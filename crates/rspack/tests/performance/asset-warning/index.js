@@ -0,0 +1,3 @@
+import { messages } from "./messages";
+
+console.log(messages.join("\n"));
@@ -0,0 +1,32 @@
+export const messages = [
+  "Message number 0 which makes the bundle bigger than the budget",
+  "Message number 1 which makes the bundle bigger than the budget",
+  "Message number 2 which makes the bundle bigger than the budget",
+  "Message number 3 which makes the bundle bigger than the budget",
+  "Message number 4 which makes the bundle bigger than the budget",
+  "Message number 5 which makes the bundle bigger than the budget",
+  "Message number 6 which makes the bundle bigger than the budget",
+  "Message number 7 which makes the bundle bigger than the budget",
+  "Message number 8 which makes the bundle bigger than the budget",
+  "Message number 9 which makes the bundle bigger than the budget",
+  "Message number 10 which makes the bundle bigger than the budget",
+  "Message number 11 which makes the bundle bigger than the budget",
+  "Message number 12 which makes the bundle bigger than the budget",
+  "Message number 13 which makes the bundle bigger than the budget",
+  "Message number 14 which makes the bundle bigger than the budget",
+  "Message number 15 which makes the bundle bigger than the budget",
+  "Message number 16 which makes the bundle bigger than the budget",
+  "Message number 17 which makes the bundle bigger than the budget",
+  "Message number 18 which makes the bundle bigger than the budget",
+  "Message number 19 which makes the bundle bigger than the budget",
+  "Message number 20 which makes the bundle bigger than the budget",
+  "Message number 21 which makes the bundle bigger than the budget",
+  "Message number 22 which makes the bundle bigger than the budget",
+  "Message number 23 which makes the bundle bigger than the budget",
+  "Message number 24 which makes the bundle bigger than the budget",
+  "Message number 25 which makes the bundle bigger than the budget",
+  "Message number 26 which makes the bundle bigger than the budget",
+  "Message number 27 which makes the bundle bigger than the budget",
+  "Message number 28 which makes the bundle bigger than the budget",
+  "Message number 29 which makes the bundle bigger than the budget",
+];
@@ -0,0 +1,4 @@
+import * as lib from "./lib";
+
+console.log(lib);
+import("./unrelated").then(({ unrelated }) => console.log(unrelated));
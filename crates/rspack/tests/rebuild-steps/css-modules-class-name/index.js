@@ -0,0 +1,4 @@
+import styles from "./style.module.css";
+
+console.log(styles.title);
+module.hot.accept("./style.module.css", () => console.log(styles.title));
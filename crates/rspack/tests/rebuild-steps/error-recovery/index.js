@@ -0,0 +1,3 @@
+import { value } from "./value";
+
+console.log(value);
@@ -0,0 +1,4 @@
+import { value } from "./value";
+
+console.log(value);
+module.hot.accept("./value", () => console.log(value));
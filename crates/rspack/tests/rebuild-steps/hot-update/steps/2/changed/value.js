@@ -0,0 +1 @@
+export { extra as value } from "./extra";
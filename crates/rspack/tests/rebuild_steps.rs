@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+
+use rspack_core::PluginExt;
+use rspack_plugin_javascript::{
+  FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, MangleExportsPlugin,
+};
+use rspack_testing::{fixture, test_rebuild_steps_fixture};
+
+#[fixture("tests/rebuild-steps/*")]
+fn rebuild_steps(fixture_path: PathBuf) {
+  test_rebuild_steps_fixture(
+    &fixture_path,
+    &|filename| !filename.contains("runtime.js"),
+    Box::new(|plugins, options| {
+      // The module memory caches are only read by the webpack based tree shaking and code generation
+      if options.experiments.cache_unaffected {
+        options.experiments.rspack_future.new_treeshaking = true;
+        plugins.push(FlagDependencyExportsPlugin.boxed());
+        plugins.push(FlagDependencyUsagePlugin::new(false).boxed());
+        plugins.push(MangleExportsPlugin::new(true).boxed());
+      }
+    }),
+  );
+}
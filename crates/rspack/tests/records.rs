@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+
+use rspack_core::{Compiler, CompilerOptions, LogType, PluginExt};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_aggressive_splitting::{
+  AggressiveSplittingPlugin, AggressiveSplittingPluginOptions,
+};
+use rspack_testing::apply_from_fixture;
+
+struct BuildResult {
+  /// Ids and module identifiers of the chunks, sorted
+  chunks: Vec<(Option<String>, Vec<String>)>,
+  logs: Vec<String>,
+}
+
+/// Build `tests/records/aggressive-splitting`, which has a lazy chunk bigger than `max_size`.
+#[tokio::main]
+async fn build(mut_options: impl FnOnce(&mut CompilerOptions)) -> BuildResult {
+  let fixture_path =
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/records/aggressive-splitting");
+  let (mut options, mut plugins) = apply_from_fixture(&fixture_path);
+  mut_options(&mut options);
+  plugins.push(
+    AggressiveSplittingPlugin::new(AggressiveSplittingPluginOptions {
+      min_size: 150.0,
+      max_size: 250.0,
+      ..Default::default()
+    })
+    .boxed(),
+  );
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
+  compiler.build().await.expect("should build");
+
+  let compilation = &compiler.compilation;
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .values()
+    .map(|chunk| {
+      let mut modules = compilation
+        .chunk_graph
+        .get_chunk_module_identifiers(&chunk.ukey)
+        .iter()
+        .map(|module| module.to_string())
+        .collect::<Vec<_>>();
+      modules.sort();
+      (chunk.id.clone(), modules)
+    })
+    .collect::<Vec<_>>();
+  chunks.sort();
+  let logs = compilation
+    .get_logging()
+    .get("rspack.AggressiveSplittingPlugin")
+    .map(|logs| {
+      logs
+        .iter()
+        .filter_map(|log| match log {
+          LogType::Log { message } => Some(message.clone()),
+          _ => None,
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  BuildResult { chunks, logs }
+}
+
+fn records_path() -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/records/aggressive-splitting/dist/records.json")
+}
+
+#[test]
+fn aggressive_splitting_records_round_trip() {
+  let _ = std::fs::remove_file(records_path());
+
+  let first = build(|options| {
+    options.records_output_path = Some(records_path());
+  });
+  assert_eq!(
+    first.logs,
+    vec!["2 chunks from aggressive splitting, 2 new splits"]
+  );
+
+  // The splits are restored from the records instead of being made again
+  let second = build(|options| {
+    options.records_input_path = Some(records_path());
+  });
+  assert_eq!(
+    second.logs,
+    vec!["2 chunks from aggressive splitting, 0 new splits"]
+  );
+  assert_eq!(first.chunks, second.chunks);
+}
@@ -0,0 +1 @@
+import("./parts").then(({ default: parts }) => console.log(parts));
@@ -0,0 +1,6 @@
+import { greet } from "@app/greet";
+import { shared } from "shared-lib";
+import config from "config";
+import theme from "theme";
+
+console.log(greet(config.name), shared, theme.color);
@@ -0,0 +1,105 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use rspack_core::{
+  ApplyContext, CompilerOptions, Plugin, PluginContext, PluginExt, ResolveHookArgs,
+  ResolveHookResult, ResolverPlugin,
+};
+use rspack_error::Result;
+use rspack_testing::test_fixture;
+use testing_macros::fixture;
+
+/// Resolves `@app/*` to `src`, bare specifiers to `shared_modules`, the `redirects` of the
+/// closest `package.json` through the other hooks, and the built files in `lib` of `shared_modules`
+/// to their sources.
+#[derive(Debug)]
+struct TestResolverPlugin;
+
+impl ResolverPlugin for TestResolverPlugin {
+  fn name(&self) -> &'static str {
+    "TestResolverPlugin"
+  }
+
+  fn alias(&self, args: &ResolveHookArgs) -> Result<Option<String>> {
+    let (Some(rest), Some(description_data)) =
+      (args.request.strip_prefix("@app/"), args.description_data)
+    else {
+      return Ok(None);
+    };
+    let aliased = description_data.path().join("src").join(rest);
+    Ok(Some(aliased.to_string_lossy().to_string()))
+  }
+
+  fn described_resolve(&self, args: &ResolveHookArgs) -> Result<ResolveHookResult> {
+    let Some(description_data) = args.description_data else {
+      return Ok(ResolveHookResult::Continue);
+    };
+    let redirect = description_data
+      .json()
+      .get("redirects")
+      .and_then(|redirects| redirects.get(args.request))
+      .and_then(|redirect| redirect.as_str());
+    Ok(match redirect {
+      Some(redirect) => ResolveHookResult::Redirect {
+        path: description_data.path().to_path_buf(),
+        request: redirect.to_string(),
+      },
+      None => ResolveHookResult::Continue,
+    })
+  }
+
+  fn module(&self, args: &ResolveHookArgs) -> Result<ResolveHookResult> {
+    let Some(description_data) = args.description_data else {
+      return Ok(ResolveHookResult::Continue);
+    };
+    let shared_modules = description_data.path().join("shared_modules");
+    Ok(if shared_modules.join(args.request).exists() {
+      ResolveHookResult::Redirect {
+        path: shared_modules,
+        request: format!("./{}", args.request),
+      }
+    } else {
+      ResolveHookResult::Continue
+    })
+  }
+
+  fn raw_file(&self, file: &Path, _args: &ResolveHookArgs) -> Result<Option<PathBuf>> {
+    let file = file.to_string_lossy();
+    if !file.contains("shared_modules") {
+      return Ok(None);
+    }
+    let source = PathBuf::from(file.replace("/lib/", "/src/"));
+    Ok(source.exists().then_some(source))
+  }
+}
+
+#[derive(Debug)]
+struct TestPlugin;
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> &'static str {
+    "TestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .register_resolver_plugin(Arc::new(TestResolverPlugin));
+    Ok(())
+  }
+}
+
+#[fixture("tests/resolver-plugins/*")]
+fn resolver_plugins(fixture_path: PathBuf) {
+  test_fixture(
+    &fixture_path,
+    Box::new(|plugins, _| plugins.push(TestPlugin.boxed())),
+    None,
+  );
+}
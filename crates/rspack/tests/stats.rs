@@ -0,0 +1,131 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use insta::{assert_snapshot, Settings};
+use rspack_core::{
+  Compiler, MangleExportsOption, PluginExt, StatsModule, StatsUsedExports, UsedExportsOption,
+};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_javascript::{
+  FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, MangleExportsPlugin,
+  ModuleConcatenationPlugin, SideEffectsFlagPlugin,
+};
+use rspack_testing::{apply_from_fixture, fixture};
+
+/// Build with the webpack based tree shaking, which fills the exports info.
+#[tokio::main]
+async fn compile(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem> {
+  let (mut options, mut plugins) = apply_from_fixture(fixture_path);
+  options.experiments.rspack_future.new_treeshaking = true;
+  options.optimization.provided_exports = true;
+  plugins.push(Box::<FlagDependencyExportsPlugin>::default());
+  plugins.push(
+    FlagDependencyUsagePlugin::new(matches!(
+      options.optimization.used_exports,
+      UsedExportsOption::Global
+    ))
+    .boxed(),
+  );
+  if options.optimization.side_effects.is_enable() {
+    plugins.push(Box::<SideEffectsFlagPlugin>::default());
+  }
+  if options.optimization.mangle_exports.is_enable() {
+    plugins.push(
+      MangleExportsPlugin::new(!matches!(
+        options.optimization.mangle_exports,
+        MangleExportsOption::Size
+      ))
+      .boxed(),
+    );
+  }
+  if options.optimization.concatenate_modules {
+    plugins.push(Box::new(ModuleConcatenationPlugin));
+  }
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
+  compiler.build().await.expect("should build");
+  compiler
+}
+
+fn format_used_exports(used_exports: &Option<StatsUsedExports>) -> String {
+  match used_exports {
+    None => "null".to_string(),
+    Some(StatsUsedExports::Bool(used)) => used.to_string(),
+    Some(StatsUsedExports::Vec(used)) => format!("[{}]", used.join(", ")),
+  }
+}
+
+fn write_module(out: &mut String, module: &StatsModule, indent: &str) {
+  writeln!(out, "{indent}{}", module.name).expect("should write");
+  if let Some(depth) = module.depth {
+    writeln!(out, "{indent}  depth: {depth}").expect("should write");
+  }
+  if let Some(provided_exports) = &module.provided_exports {
+    writeln!(
+      out,
+      "{indent}  provided exports: [{}]",
+      provided_exports.join(", ")
+    )
+    .expect("should write");
+  }
+  writeln!(
+    out,
+    "{indent}  used exports: {}",
+    format_used_exports(&module.used_exports)
+  )
+  .expect("should write");
+  for bailout in &module.optimization_bailout {
+    writeln!(out, "{indent}  bailout: {bailout}").expect("should write");
+  }
+  for inner in module.modules.iter().flatten() {
+    write_module(out, inner, &format!("{indent}    "));
+  }
+}
+
+/// Snapshot the modules and chunks in stats, without the runtime modules.
+#[fixture("tests/stats/*")]
+fn stats(fixture_path: PathBuf) {
+  let compiler = compile(&fixture_path);
+  let stats = compiler.compilation.get_stats();
+  let is_runtime_module = |module: &StatsModule| module.name.starts_with("webpack/runtime/");
+
+  let mut out = String::from("# Modules\n\n");
+  for module in stats
+    .get_modules(false, false, true, false)
+    .expect("should get modules")
+    .iter()
+    .filter(|module| !is_runtime_module(module))
+  {
+    write_module(&mut out, module, "");
+  }
+
+  out.push_str("\n# Chunks\n");
+  for chunk in stats
+    .get_chunks(true, false, false, false, true, false)
+    .expect("should get chunks")
+  {
+    writeln!(out, "\nchunk {}", chunk.id.as_deref().unwrap_or_default()).expect("should write");
+    for origin in &chunk.origins {
+      writeln!(
+        out,
+        "  origin: {} {} {}",
+        origin.module_name, origin.loc, origin.request
+      )
+      .expect("should write");
+    }
+    for module in chunk
+      .modules
+      .iter()
+      .flatten()
+      .filter(|module| !is_runtime_module(module))
+    {
+      write_module(&mut out, module, "  ");
+    }
+  }
+
+  let mut settings = Settings::clone_current();
+  settings.set_snapshot_path(fixture_path.join("snapshot"));
+  settings.set_omit_expression(true);
+  settings.set_prepend_module_to_snapshot(false);
+  settings.bind(|| assert_snapshot!("stats", out));
+}
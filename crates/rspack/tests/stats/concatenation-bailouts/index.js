@@ -0,0 +1,5 @@
+import { a } from "./a";
+import { c } from "./c";
+import { d } from "./d";
+
+console.log(a, c, d, require("./cjs"));
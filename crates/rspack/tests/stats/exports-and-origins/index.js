@@ -0,0 +1,5 @@
+import { a } from "./a";
+
+import("./lazy").then(({ lazy }) => {
+  console.log(a, lazy);
+});
@@ -0,0 +1,2 @@
+export { b } from "./a";
+export const lazy = "lazy";
@@ -0,0 +1,5 @@
+export const RED = 0;
+export const GREEN = 1;
+export const NAME = "color";
+const UNSET = void 0;
+export { UNSET };
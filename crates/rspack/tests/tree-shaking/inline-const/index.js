@@ -0,0 +1,8 @@
+import { RED, NAME, UNSET } from "./enum";
+import { GREEN } from "./reexport";
+import * as mixed from "./mixed";
+import { counter, list } from "./mixed";
+
+console.log(RED, NAME, UNSET, { GREEN });
+console.log(mixed.SIZE.toString(), mixed.ENABLED, counter, list);
+console.log(mixed.MAX, mixed.MIN);
@@ -0,0 +1,6 @@
+export const SIZE = -10;
+export const ENABLED = true;
+export let counter = 0;
+export const list = [1, 2];
+export const MAX = 1e400;
+export const MIN = -1e400;
@@ -0,0 +1 @@
+export { GREEN } from "./enum";
@@ -0,0 +1,4 @@
+import "./registry";
+import { getState } from "./store";
+
+getState();
@@ -0,0 +1 @@
+export { createRegistry, logger } from "./utils";
@@ -0,0 +1,7 @@
+import { createRegistry } from "./reexport";
+
+const registry = createRegistry();
+
+export function register(name) {
+	registry.set(name, true);
+}
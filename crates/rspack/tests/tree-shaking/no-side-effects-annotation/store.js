@@ -0,0 +1,10 @@
+import { createStore, track } from "./utils";
+import { logger } from "./reexport";
+
+export const store = createStore();
+export const log = logger("store");
+export const tracked = track("store");
+
+export function getState() {
+	return 1;
+}
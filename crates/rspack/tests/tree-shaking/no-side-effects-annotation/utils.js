@@ -0,0 +1,17 @@
+/*#__NO_SIDE_EFFECTS__*/
+export function createRegistry() {
+	return new Map();
+}
+
+export const createStore = /*#__NO_SIDE_EFFECTS__*/ () => ({ state: {} });
+
+/*#__NO_SIDE_EFFECTS__*/
+function createLogger(name) {
+	return { name };
+}
+
+export { createLogger as logger };
+
+export function track(name) {
+	console.log(name);
+}
@@ -1,8 +1,9 @@
 use napi_derive::napi;
 use rspack_core::{
   CompilerOptions, Context, Experiments, IncrementalRebuild, IncrementalRebuildMakeState,
-  ModuleOptions, Optimization, OutputOptions, Target, TreeShaking,
+  ModuleOptions, Optimization, OutputOptions, PerformanceOptions, PluginExt, Target, TreeShaking,
 };
+use rspack_plugin_size_limits::SizeLimitsPlugin;
 use serde::Deserialize;
 
 mod raw_builtins;
@@ -16,6 +17,7 @@ mod raw_module;
 mod raw_node;
 mod raw_optimization;
 mod raw_output;
+mod raw_performance;
 mod raw_resolve;
 mod raw_snapshot;
 mod raw_split_chunks;
@@ -32,6 +34,7 @@ pub use raw_module::*;
 pub use raw_node::*;
 pub use raw_optimization::*;
 pub use raw_output::*;
+pub use raw_performance::*;
 pub use raw_resolve::*;
 pub use raw_snapshot::*;
 pub use raw_split_chunks::*;
@@ -56,6 +59,7 @@ pub struct RawOptions {
   pub module: RawModuleOptions,
   pub devtool: String,
   pub optimization: RawOptimizationOptions,
+  pub performance: Option<RawPerformanceOptions>,
   pub stats: RawStatsOptions,
   pub snapshot: RawSnapshotOptions,
   pub cache: RawCacheOptions,
@@ -63,6 +67,8 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub profile: bool,
   pub bail: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   pub builtins: RawBuiltins,
 }
 
@@ -86,15 +92,25 @@ impl RawOptions {
       },
       new_split_chunks: self.experiments.new_split_chunks,
       top_level_await: self.experiments.top_level_await,
+      cache_unaffected: self.experiments.cache_unaffected,
+      inline_const: self.experiments.inline_const,
       rspack_future: self.experiments.rspack_future.into(),
     };
     let optimization: Optimization = IS_ENABLE_NEW_SPLIT_CHUNKS
       .set(&experiments.new_split_chunks, || {
         self.optimization.try_into()
       })?;
-    let stats = self.stats.into();
+    let stats = self.stats.try_into()?;
     let snapshot = self.snapshot.into();
     let node = self.node.map(|n| n.into());
+    let performance: PerformanceOptions = self
+      .performance
+      .map(TryInto::try_into)
+      .transpose()?
+      .unwrap_or_default();
+    if performance.hints.is_enable() {
+      plugins.push(SizeLimitsPlugin.boxed());
+    }
 
     let mut builtins = self.builtins.apply(plugins)?;
     if experiments.rspack_future.new_treeshaking {
@@ -114,10 +130,16 @@ impl RawOptions {
       cache,
       snapshot,
       optimization,
+      performance,
       node,
       dev_server: Default::default(),
       profile: self.profile,
       bail: self.bail,
+      records_input_path: self.records_input_path.map(Into::into),
+      records_output_path: self.records_output_path.map(Into::into),
+      // Only set from Rust, `ignoreWarnings` of the JS config is applied by `IgnoreWarningsPlugin`
+      ignore_warnings: Default::default(),
+      diagnostic_severity: Default::default(),
       builtins,
     })
   }
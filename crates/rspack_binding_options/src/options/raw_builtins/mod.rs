@@ -1,7 +1,9 @@
+mod raw_aggressive_splitting;
 mod raw_banner;
 mod raw_bundle_info;
 mod raw_copy;
 mod raw_html;
+mod raw_ids;
 mod raw_limit_chunk_count;
 mod raw_mf;
 mod raw_progress;
@@ -13,10 +15,12 @@ use napi_derive::napi;
 use rspack_core::{BoxPlugin, Define, DefinePlugin, PluginExt, Provide, ProvidePlugin};
 use rspack_error::Result;
 use rspack_ids::{
-  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin,
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedModuleIdsPlugin,
+  NamedChunkIdsPlugin, NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+  OccurrenceChunkIdsPlugin, OccurrenceModuleIdsPlugin,
 };
 use rspack_napi_shared::NapiResultExt;
+use rspack_plugin_aggressive_splitting::AggressiveSplittingPlugin;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
@@ -55,14 +59,19 @@ use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
-use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin};
+use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin, SyncWasmPlugin};
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
 pub use self::{
-  raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
-  raw_html::RawHtmlRspackPluginOptions, raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
-  raw_mf::RawContainerPluginOptions, raw_progress::RawProgressPluginOptions,
+  raw_aggressive_splitting::RawAggressiveSplittingPluginOptions,
+  raw_banner::RawBannerPluginOptions,
+  raw_copy::RawCopyRspackPluginOptions,
+  raw_html::RawHtmlRspackPluginOptions,
+  raw_ids::{RawHashedModuleIdsPluginOptions, RawOccurrenceIdsPluginOptions},
+  raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
+  raw_mf::RawContainerPluginOptions,
+  raw_progress::RawProgressPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use self::{
@@ -95,6 +104,7 @@ pub enum BuiltinPluginName {
   ModuleChunkFormatPlugin,
   HotModuleReplacementPlugin,
   LimitChunkCountPlugin,
+  AggressiveSplittingPlugin,
   WorkerPlugin,
   WebWorkerTemplatePlugin,
   MergeDuplicateChunksPlugin,
@@ -108,6 +118,11 @@ pub enum BuiltinPluginName {
   DeterministicModuleIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
+  NaturalModuleIdsPlugin,
+  NaturalChunkIdsPlugin,
+  OccurrenceModuleIdsPlugin,
+  OccurrenceChunkIdsPlugin,
+  HashedModuleIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
@@ -119,6 +134,7 @@ pub enum BuiltinPluginName {
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -200,9 +216,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::ChunkPrefetchPreloadPlugin => {
         plugins.push(ChunkPrefetchPreloadPlugin.boxed());
@@ -226,6 +240,13 @@ impl BuiltinPlugin {
         .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::AggressiveSplittingPlugin => {
+        let plugin = AggressiveSplittingPlugin::new(
+          downcast_into::<RawAggressiveSplittingPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::WorkerPlugin => {
         plugins.push(WorkerPlugin.boxed());
       }
@@ -283,6 +304,30 @@ impl BuiltinPlugin {
       BuiltinPluginName::DeterministicChunkIdsPlugin => {
         plugins.push(DeterministicChunkIdsPlugin::default().boxed())
       }
+      BuiltinPluginName::NaturalModuleIdsPlugin => {
+        plugins.push(NaturalModuleIdsPlugin::default().boxed())
+      }
+      BuiltinPluginName::NaturalChunkIdsPlugin => {
+        plugins.push(NaturalChunkIdsPlugin::default().boxed())
+      }
+      BuiltinPluginName::OccurrenceModuleIdsPlugin => {
+        let options = downcast_into::<RawOccurrenceIdsPluginOptions>(self.options)?;
+        plugins.push(
+          OccurrenceModuleIdsPlugin::new(options.prioritise_initial.unwrap_or_default()).boxed(),
+        )
+      }
+      BuiltinPluginName::OccurrenceChunkIdsPlugin => {
+        let options = downcast_into::<RawOccurrenceIdsPluginOptions>(self.options)?;
+        plugins.push(
+          OccurrenceChunkIdsPlugin::new(options.prioritise_initial.unwrap_or_default()).boxed(),
+        )
+      }
+      BuiltinPluginName::HashedModuleIdsPlugin => plugins.push(
+        HashedModuleIdsPlugin::new(
+          downcast_into::<RawHashedModuleIdsPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::RealContentHashPlugin => plugins.push(RealContentHashPlugin.boxed()),
       BuiltinPluginName::RemoveEmptyChunksPlugin => plugins.push(RemoveEmptyChunksPlugin.boxed()),
       BuiltinPluginName::EnsureChunkConditionsPlugin => {
@@ -300,6 +345,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::new().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => plugins.push(SyncWasmPlugin::new().boxed()),
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin.boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
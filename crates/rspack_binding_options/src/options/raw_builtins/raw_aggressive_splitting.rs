@@ -0,0 +1,31 @@
+use napi_derive::napi;
+use rspack_plugin_aggressive_splitting::AggressiveSplittingPluginOptions;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawAggressiveSplittingPluginOptions {
+  // Chunks are split into parts bigger than this size.
+  pub min_size: Option<f64>,
+  // Chunks bigger than this size are split.
+  pub max_size: Option<f64>,
+  // Constant overhead for a chunk.
+  pub chunk_overhead: Option<f64>,
+  // Multiplicator for initial chunks.
+  pub entry_chunk_multiplicator: Option<f64>,
+}
+
+impl From<RawAggressiveSplittingPluginOptions> for AggressiveSplittingPluginOptions {
+  fn from(value: RawAggressiveSplittingPluginOptions) -> Self {
+    let default = Self::default();
+    Self {
+      min_size: value.min_size.unwrap_or(default.min_size),
+      max_size: value.max_size.unwrap_or(default.max_size),
+      chunk_overhead: value.chunk_overhead.unwrap_or(default.chunk_overhead),
+      entry_chunk_multiplicator: value
+        .entry_chunk_multiplicator
+        .unwrap_or(default.entry_chunk_multiplicator),
+    }
+  }
+}
@@ -3,8 +3,10 @@ use std::str::FromStr;
 
 use napi_derive::napi;
 use rspack_plugin_html::config::HtmlInject;
+use rspack_plugin_html::config::HtmlResourceHint;
 use rspack_plugin_html::config::HtmlRspackPluginOptions;
 use rspack_plugin_html::config::HtmlScriptLoading;
+use rspack_plugin_html::config::HtmlSriMode;
 use rspack_plugin_html::sri::HtmlSriHashFunction;
 use serde::Deserialize;
 use serde::Serialize;
@@ -12,6 +14,8 @@ use serde::Serialize;
 pub type RawHtmlScriptLoading = String;
 pub type RawHtmlInject = String;
 pub type RawHtmlSriHashFunction = String;
+pub type RawHtmlSriMode = String;
+pub type RawHtmlResourceHint = String;
 pub type RawHtmlFilename = String;
 
 #[derive(Deserialize, Debug, Serialize)]
@@ -39,6 +43,12 @@ pub struct RawHtmlRspackPluginOptions {
   pub excluded_chunks: Option<Vec<String>>,
   #[napi(ts_type = "\"sha256\" | \"sha384\" | \"sha512\"")]
   pub sri: Option<RawHtmlSriHashFunction>,
+  /// "initial" or "all"
+  #[napi(ts_type = "\"initial\" | \"all\"")]
+  pub sri_mode: Option<RawHtmlSriMode>,
+  /// "preload" or "prefetch"
+  #[napi(ts_type = "\"preload\" | \"prefetch\"")]
+  pub resource_hint: Option<RawHtmlResourceHint>,
   pub minify: Option<bool>,
   pub title: Option<String>,
   pub favicon: Option<String>,
@@ -56,6 +66,16 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       HtmlSriHashFunction::from_str(s).unwrap_or_else(|_| panic!("Invalid sri value: {s}"))
     });
 
+    let sri_mode = value
+      .sri_mode
+      .as_ref()
+      .map(|s| HtmlSriMode::from_str(s).unwrap_or_else(|_| panic!("Invalid sriMode value: {s}")))
+      .unwrap_or_default();
+
+    let resource_hint = value.resource_hint.as_ref().map(|s| {
+      HtmlResourceHint::from_str(s).unwrap_or_else(|_| panic!("Invalid resourceHint value: {s}"))
+    });
+
     HtmlRspackPluginOptions {
       filename: value.filename.unwrap_or_else(|| String::from("index.html")),
       template: value.template,
@@ -67,6 +87,8 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       chunks: value.chunks,
       excluded_chunks: value.excluded_chunks,
       sri,
+      sri_mode,
+      resource_hint,
       minify: value.minify.unwrap_or_default(),
       title: value.title,
       favicon: value.favicon,
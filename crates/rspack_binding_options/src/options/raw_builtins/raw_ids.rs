@@ -0,0 +1,46 @@
+use napi_derive::napi;
+use rspack_ids::HashedModuleIdsPluginOptions;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawOccurrenceIdsPluginOptions {
+  // Prioritise the modules or chunks which are in initial chunks.
+  pub prioritise_initial: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawHashedModuleIdsPluginOptions {
+  pub context: Option<String>,
+  pub hash_function: Option<String>,
+  pub hash_digest: Option<String>,
+  pub hash_digest_length: Option<u32>,
+}
+
+impl TryFrom<RawHashedModuleIdsPluginOptions> for HashedModuleIdsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHashedModuleIdsPluginOptions) -> rspack_error::Result<Self> {
+    let default = Self::default();
+    Ok(Self {
+      context: value.context,
+      hash_function: value
+        .hash_function
+        .map(|f| f.as_str().try_into())
+        .transpose()?
+        .unwrap_or(default.hash_function),
+      hash_digest: value
+        .hash_digest
+        .map(|d| d.as_str().try_into())
+        .transpose()?
+        .unwrap_or(default.hash_digest),
+      hash_digest_length: value
+        .hash_digest_length
+        .map(|l| l as usize)
+        .unwrap_or(default.hash_digest_length),
+    })
+  }
+}
@@ -15,6 +15,8 @@ pub struct RawRspackFuture {
 pub struct RawExperiments {
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  pub cache_unaffected: bool,
+  pub inline_const: bool,
   pub rspack_future: RawRspackFuture,
 }
 
@@ -21,8 +21,6 @@ use {
   rspack_napi_shared::NapiResultExt,
 };
 
-use crate::get_builtin_loader;
-
 /// Loader Runner for JavaScript environment
 #[derive(Clone)]
 pub enum JsLoaderRunner {
@@ -332,7 +330,13 @@ pub async fn run_builtin_loader(
 ) -> Result<JsLoaderContext> {
   use rspack_loader_runner::__private::loader::LoaderItemList;
 
-  let loader = get_builtin_loader(&builtin, options);
+  // The builtin loaders registered by the plugins of the compiler running the loader
+  let loader = loader_context
+    .context_external
+    .plugin_driver
+    .builtin_loader_registry
+    .get(&builtin, options)
+    .map_err(|e| Error::from_reason(e.to_string()))?;
   let loader_item = loader.clone().into();
   let list = &[loader_item];
   let additional_data = {
@@ -6,42 +6,41 @@ use derivative::Derivative;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use rspack_core::{
-  AssetGeneratorDataUrl, AssetGeneratorDataUrlOptions, AssetGeneratorOptions,
-  AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions,
-  AssetResourceGeneratorOptions, BoxLoader, DescriptionData, DynamicImportMode, FuncUseCtx,
-  GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions, JavascriptParserOrder,
+  builtin_loader_factory, AssetGeneratorDataUrl, AssetGeneratorDataUrlOptions,
+  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
+  AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
+  BuiltinLoaderFactory, DescriptionData, DynamicImportMode, FuncUseCtx, GeneratorOptions,
+  GeneratorOptionsByModuleType, JavascriptParserOptions, JavascriptParserOrder,
   JavascriptParserUrl, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
   ModuleRuleUseLoader, ModuleType, ParserOptions, ParserOptionsByModuleType,
 };
 use rspack_error::{error, miette::IntoDiagnostic};
-use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
-use rspack_loader_swc::SWC_LOADER_IDENTIFIER;
+use rspack_loader_react_refresh::{ReactRefreshLoader, REACT_REFRESH_LOADER_IDENTIFIER};
+use rspack_loader_swc::{SwcLoader, SwcLoaderJsOptions, SWC_LOADER_IDENTIFIER};
 use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use rspack_napi_shared::{get_napi_env, NapiResultExt};
-use serde::Deserialize;
+use serde::{de::IgnoredAny, Deserialize};
 
 pub use self::js_loader::JsLoaderAdapter;
 pub use self::js_loader::*;
 use crate::RawResolveOptions;
 
-pub fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
-  if builtin.starts_with(SWC_LOADER_IDENTIFIER) {
-    return Arc::new(
-      rspack_loader_swc::SwcLoader::new(
-        serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
-          panic!("Could not parse builtin:swc-loader options:{options:?},error: {e:?}")
-        }),
-      )
-      .with_identifier(builtin.into()),
-    );
-  }
-  if builtin.starts_with(REACT_REFRESH_LOADER_IDENTIFIER) {
-    return Arc::new(
-      rspack_loader_react_refresh::ReactRefreshLoader::default().with_identifier(builtin.into()),
-    );
-  }
-
-  unreachable!("Unexpected builtin loader: {builtin}")
+/// The builtin loaders shipped with rspack.
+pub fn builtin_loaders() -> Vec<(&'static str, BuiltinLoaderFactory)> {
+  vec![
+    (
+      SWC_LOADER_IDENTIFIER,
+      builtin_loader_factory(|identifier, options: SwcLoaderJsOptions| {
+        Arc::new(SwcLoader::new(options).with_identifier(identifier.into()))
+      }),
+    ),
+    (
+      REACT_REFRESH_LOADER_IDENTIFIER,
+      builtin_loader_factory(|identifier, _: IgnoredAny| {
+        Arc::new(ReactRefreshLoader::default().with_identifier(identifier.into()))
+      }),
+    ),
+  ]
 }
 
 /// `loader` is for both JS and Rust loaders.
@@ -49,7 +48,7 @@ pub fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
 ///   - a `None` on rust side and handled by js side `getOptions` when
 /// using with `loader`.
 ///   - a `Some(string)` on rust side, deserialized by `serde_json::from_str`
-/// and passed to rust side loader in [get_builtin_loader] when using with
+/// and passed to the rust side loader registered as a builtin loader when using with
 /// `builtin_loader`.
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
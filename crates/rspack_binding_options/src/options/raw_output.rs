@@ -1,6 +1,7 @@
 use napi_derive::napi;
 use rspack_core::{
-  CrossOriginLoading, LibraryCustomUmdObject, LibraryName, LibraryNonUmdObject, LibraryOptions,
+  ChunkLoadRetry, CrossOriginLoading, Environment, LibraryCustomUmdObject, LibraryName,
+  LibraryNonUmdObject, LibraryOptions,
 };
 use rspack_core::{LibraryAuxiliaryComment, OutputOptions, TrustedTypes};
 use serde::Deserialize;
@@ -145,6 +146,54 @@ impl From<RawCrossOriginLoading> for CrossOriginLoading {
   }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawChunkLoadRetry {
+  pub max_retries: u32,
+  pub retry_delay: u32,
+  pub fallback_public_paths: Vec<String>,
+}
+
+impl From<RawChunkLoadRetry> for ChunkLoadRetry {
+  fn from(value: RawChunkLoadRetry) -> Self {
+    Self {
+      max_retries: value.max_retries,
+      retry_delay: value.retry_delay,
+      fallback_public_paths: value.fallback_public_paths,
+    }
+  }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawEnvironment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+  pub destructuring: bool,
+  pub for_of: bool,
+  pub dynamic_import: bool,
+  pub module: bool,
+  pub optional_chaining: bool,
+  pub template_literal: bool,
+}
+
+impl From<RawEnvironment> for Environment {
+  fn from(value: RawEnvironment) -> Self {
+    Self {
+      arrow_function: value.arrow_function,
+      r#const: value.r#const,
+      destructuring: value.destructuring,
+      for_of: value.for_of,
+      dynamic_import: value.dynamic_import,
+      module: value.module,
+      optional_chaining: value.optional_chaining,
+      template_literal: value.template_literal,
+    }
+  }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -159,6 +208,9 @@ pub struct RawOutputOptions {
   pub filename: String,
   pub chunk_filename: String,
   pub cross_origin_loading: RawCrossOriginLoading,
+  pub charset: bool,
+  pub chunk_load_timeout: u32,
+  pub chunk_load_retry: Option<RawChunkLoadRetry>,
   pub css_filename: String,
   pub css_chunk_filename: String,
   pub hot_update_main_filename: String,
@@ -187,6 +239,7 @@ pub struct RawOutputOptions {
   pub worker_public_path: String,
   #[napi(ts_type = r#""module" | "text/javascript" | "false""#)]
   pub script_type: String,
+  pub environment: RawEnvironment,
 }
 
 impl TryFrom<RawOutputOptions> for OutputOptions {
@@ -206,6 +259,9 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       filename: value.filename.into(),
       chunk_filename: value.chunk_filename.into(),
       cross_origin_loading: value.cross_origin_loading.into(),
+      charset: value.charset,
+      chunk_load_timeout: value.chunk_load_timeout,
+      chunk_load_retry: value.chunk_load_retry.map(Into::into).unwrap_or_default(),
       css_filename: value.css_filename.into(),
       css_chunk_filename: value.css_chunk_filename.into(),
       hot_update_main_filename: value.hot_update_main_filename.into(),
@@ -220,8 +276,8 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       module: value.module,
       trusted_types: value.trusted_types.map(Into::into),
       source_map_filename: value.source_map_filename.into(),
-      hash_function: value.hash_function.as_str().into(),
-      hash_digest: value.hash_digest.as_str().into(),
+      hash_function: value.hash_function.as_str().try_into()?,
+      hash_digest: value.hash_digest.as_str().try_into()?,
       hash_digest_length: value.hash_digest_length as usize,
       hash_salt: value.hash_salt.into(),
       async_chunks: value.async_chunks,
@@ -229,6 +285,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       worker_wasm_loading: value.worker_wasm_loading.as_str().into(),
       worker_public_path: value.worker_public_path,
       script_type: value.script_type,
+      environment: value.environment.into(),
     })
   }
 }
@@ -0,0 +1,29 @@
+use napi_derive::napi;
+use rspack_core::{PerformanceHints, PerformanceOptions};
+use serde::Deserialize;
+
+use crate::RawRuleSetCondition;
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawPerformanceOptions {
+  #[napi(ts_type = r#""false" | "warning" | "error""#)]
+  pub hints: String,
+  pub max_asset_size: f64,
+  pub max_entrypoint_size: f64,
+  pub asset_filter: Option<RawRuleSetCondition>,
+}
+
+impl TryFrom<RawPerformanceOptions> for PerformanceOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawPerformanceOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      hints: PerformanceHints::from(value.hints.as_str()),
+      max_asset_size: value.max_asset_size,
+      max_entrypoint_size: value.max_entrypoint_size,
+      asset_filter: value.asset_filter.map(TryInto::try_into).transpose()?,
+    })
+  }
+}
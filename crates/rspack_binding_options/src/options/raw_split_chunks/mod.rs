@@ -45,7 +45,7 @@ pub struct RawSplitChunksOptions {
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
   pub min_size: Option<f64>,
-  //   pub min_size_reduction: usize,
+  pub min_size_reduction: Option<f64>,
   pub enforce_size_threshold: Option<f64>,
   pub min_remaining_size: Option<f64>,
   // layer: String,
@@ -84,9 +84,9 @@ pub struct RawCacheGroupOptions {
   //   pub max_initial_requests: usize,
   pub min_chunks: Option<u32>,
   pub min_size: Option<f64>,
-  //   pub min_size_reduction: usize,
-  //   pub enforce_size_threshold: usize,
-  //   pub min_remaining_size: usize,
+  pub min_size_reduction: Option<f64>,
+  pub enforce_size_threshold: Option<f64>,
+  pub min_remaining_size: Option<f64>,
   // layer: String,
   pub max_size: Option<f64>,
   pub max_async_size: Option<f64>,
@@ -125,6 +125,11 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
     let empty_sizes = SplitChunkSizes::empty();
 
     let overall_min_size = create_sizes(raw_opts.min_size);
+    let overall_min_size_reduction = create_sizes(raw_opts.min_size_reduction);
+    // The overall `min_remaining_size` falls back to the overall `min_size` if it's not specified
+    let overall_min_remaining_size =
+      create_sizes(raw_opts.min_remaining_size).merge(&overall_min_size);
+    let overall_enforce_size_threshold = create_sizes(raw_opts.enforce_size_threshold);
     let overall_max_size = create_sizes(raw_opts.max_size);
     let overall_max_async_size = create_sizes(raw_opts.max_async_size).merge(&overall_max_size);
     let overall_max_initial_size = create_sizes(raw_opts.max_initial_size).merge(&overall_max_size);
@@ -146,6 +151,24 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             &overall_min_size
           });
 
+          let min_size_reduction = create_sizes(v.min_size_reduction).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_size_reduction
+          });
+
+          let min_remaining_size = create_sizes(v.min_remaining_size).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_remaining_size
+          });
+
+          let enforce_size_threshold = create_sizes(v.enforce_size_threshold).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_enforce_size_threshold
+          });
+
           let max_size = create_sizes(v.max_size);
 
           let max_async_size = create_sizes(v.max_async_size)
@@ -197,6 +220,9 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             }),
             min_chunks,
             min_size,
+            min_size_reduction,
+            min_remaining_size,
+            enforce_size_threshold,
             automatic_name_delimiter: v
               .automatic_name_delimiter
               .unwrap_or(overall_automatic_name_delimiter.clone()),
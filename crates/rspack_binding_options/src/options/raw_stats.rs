@@ -7,12 +7,22 @@ use serde::Deserialize;
 #[napi(object)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  #[napi(ts_type = r#""graphical" | "json" | "sarif""#)]
+  pub diagnostics_format: Option<String>,
 }
 
-impl From<RawStatsOptions> for StatsOptions {
-  fn from(value: RawStatsOptions) -> Self {
-    Self {
+impl TryFrom<RawStatsOptions> for StatsOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStatsOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       colors: value.colors,
-    }
+      diagnostics_format: value
+        .diagnostics_format
+        .as_deref()
+        .map(TryInto::try_into)
+        .transpose()?
+        .unwrap_or_default(),
+    })
   }
 }
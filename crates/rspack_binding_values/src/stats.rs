@@ -236,6 +236,10 @@ pub struct JsStatsModule {
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
   pub orphan: bool,
+  pub depth: Option<u32>,
+  pub provided_exports: Option<Vec<String>>,
+  pub used_exports: Option<Either<bool, Vec<String>>>,
+  pub optimization_bailout: Vec<String>,
 }
 
 impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
@@ -276,6 +280,13 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       source,
       profile: stats.profile.map(|p| p.into()),
       orphan: stats.orphan,
+      depth: stats.depth.map(|d| d as u32),
+      provided_exports: stats.provided_exports,
+      used_exports: stats.used_exports.map(|used_exports| match used_exports {
+        rspack_core::StatsUsedExports::Bool(b) => Either::A(b),
+        rspack_core::StatsUsedExports::Vec(v) => Either::B(v),
+      }),
+      optimization_bailout: stats.optimization_bailout,
     })
   }
 }
@@ -364,6 +375,30 @@ pub struct JsStatsChunk {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Vec<JsOriginRecord>,
+}
+
+#[napi(object)]
+pub struct JsOriginRecord {
+  pub module: String,
+  pub module_identifier: String,
+  pub module_name: String,
+  pub module_id: String,
+  pub loc: String,
+  pub request: String,
+}
+
+impl From<rspack_core::StatsOriginRecord> for JsOriginRecord {
+  fn from(stats: rspack_core::StatsOriginRecord) -> Self {
+    Self {
+      module: stats.module,
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      module_id: stats.module_id,
+      loc: stats.loc,
+      request: stats.request,
+    }
+  }
 }
 
 impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
@@ -385,6 +420,7 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       parents: stats.parents,
       children: stats.children,
       siblings: stats.siblings,
+      origins: stats.origins.into_iter().map(Into::into).collect(),
     })
   }
 }
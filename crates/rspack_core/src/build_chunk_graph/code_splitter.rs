@@ -140,6 +140,14 @@ impl<'me> CodeSplitter<'me> {
       }
       entrypoint.set_entry_point_chunk(chunk.ukey);
       entrypoint.connect_chunk(chunk);
+      for dep in &dependencies {
+        let request = compilation
+          .module_graph
+          .dependency_by_id(dep)
+          .and_then(|dep| dep.as_module_dependency())
+          .map(|dep| dep.request().to_string());
+        entrypoint.add_origin(None, None, request);
+      }
 
       compilation
         .named_chunk_groups
@@ -568,6 +576,13 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       .module_graph
       .block_by_id(&block_id)
       .expect("should have block");
+    let origin_request = block
+      .get_dependencies()
+      .first()
+      .and_then(|dep| self.compilation.module_graph.dependency_by_id(dep))
+      .and_then(|dep| dep.as_module_dependency())
+      .map(|dep| dep.request().to_string());
+    let origin = (*block.parent(), block.loc().copied(), origin_request);
     let item_chunk_group = self
       .compilation
       .chunk_group_by_ukey
@@ -740,6 +755,14 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       self.block_chunk_groups.insert(block_id, cgi);
     }
 
+    if let Some(cgi) = c.or(entrypoint) {
+      self
+        .compilation
+        .chunk_group_by_ukey
+        .expect_get_mut(&cgi)
+        .add_origin(Some(origin.0), origin.1, origin.2);
+    }
+
     if let Some(c) = c {
       let connect_list = self.queue_connect.entry(item_chunk_group_ukey).or_default();
       connect_list.insert(c);
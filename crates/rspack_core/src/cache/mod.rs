@@ -9,10 +9,12 @@ use std::{
 use crate::CompilerOptions;
 
 mod local;
+mod module_mem_cache;
 mod occasion;
 mod snapshot;
 mod storage;
 pub use local::*;
+pub use module_mem_cache::{ModuleMemCache, ModuleMemCaches};
 use occasion::{
   BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
 };
@@ -27,6 +29,7 @@ pub struct Cache {
   pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
+  pub module_mem_caches: Option<ModuleMemCaches>,
 }
 
 impl Cache {
@@ -45,6 +48,10 @@ impl Cache {
       ),
       code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
       create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      module_mem_caches: options
+        .experiments
+        .cache_unaffected
+        .then(ModuleMemCaches::default),
     }
   }
 
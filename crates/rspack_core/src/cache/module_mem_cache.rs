@@ -0,0 +1,370 @@
+use std::{
+  any::Any,
+  collections::VecDeque,
+  hash::{Hash, Hasher},
+  sync::{Arc, Mutex},
+};
+
+use dashmap::DashMap;
+use rspack_hash::RspackHashDigest;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+use rustc_hash::FxHasher;
+
+use crate::{
+  get_chunk_from_ukey, AffectType, BoxModule, Compilation, DependenciesBlock, Logger, ModuleGraph,
+  ModuleGraphConnection, ModuleIdentifier,
+};
+
+/// A memory cache bound to a module, it's dropped as soon as the module or the modules it
+/// references change. Plugins store the results they derive from the module under their own
+/// keys, aligned with webpack's `moduleMemCaches`.
+#[derive(Default)]
+pub struct ModuleMemCache {
+  data: DashMap<u64, Arc<dyn Any + Send + Sync>>,
+}
+
+impl std::fmt::Debug for ModuleMemCache {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ModuleMemCache")
+      .field("len", &self.data.len())
+      .finish()
+  }
+}
+
+impl ModuleMemCache {
+  pub fn get<T: Any + Send + Sync>(&self, key: impl Hash) -> Option<Arc<T>> {
+    self
+      .data
+      .get(&hash_key(key))
+      .and_then(|value| value.clone().downcast::<T>().ok())
+  }
+
+  pub fn set<T: Any + Send + Sync>(&self, key: impl Hash, value: T) {
+    self.data.insert(hash_key(key), Arc::new(value));
+  }
+}
+
+fn hash_key(key: impl Hash) -> u64 {
+  let mut hasher = FxHasher::default();
+  key.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[derive(Debug)]
+struct CachedModuleMemCache {
+  build_hash: RspackHashDigest,
+  references: Vec<Option<ModuleIdentifier>>,
+  mem_cache: Arc<ModuleMemCache>,
+  chunk_graph: Option<ChunkGraphModuleMemCache>,
+}
+
+#[derive(Debug)]
+struct ChunkGraphModuleMemCache {
+  references: ChunkGraphReferences,
+  mem_cache: Arc<ModuleMemCache>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ChunkGraphReferences {
+  id: Option<String>,
+  modules: Vec<(ModuleIdentifier, Option<String>)>,
+  blocks: Vec<Option<String>>,
+}
+
+/// The module memory caches kept across compilations, enabled by `experiments.cacheUnaffected`.
+#[derive(Debug, Default)]
+pub struct ModuleMemCaches {
+  modules: Mutex<IdentifierMap<CachedModuleMemCache>>,
+}
+
+impl ModuleMemCaches {
+  /// A module is affected when it's rebuilt with a different hash, one of its dependencies
+  /// resolves to another module, or a module it references is affected and the dependency
+  /// could affect the referencing module. Only unaffected modules keep their memory cache.
+  pub fn compute_affected_modules(
+    &self,
+    compilation: &Compilation,
+  ) -> IdentifierMap<Arc<ModuleMemCache>> {
+    let module_graph = &compilation.module_graph;
+    let mut cache = self.modules.lock().expect("should lock module mem caches");
+    cache.retain(|identifier, _| module_graph.module_by_identifier(identifier).is_some());
+
+    let mut module_mem_caches = IdentifierMap::default();
+    let mut affected_modules = IdentifierSet::default();
+    let mut infected_modules = IdentifierSet::default();
+    let mut stat_new = 0;
+    let mut stat_changed = 0;
+    let mut stat_references_changed = 0;
+    let mut stat_unchanged = 0;
+    let mut stat_without_build = 0;
+
+    for (identifier, module) in module_graph.modules() {
+      let Some(build_hash) = module
+        .build_info()
+        .as_ref()
+        .and_then(|build_info| build_info.hash.clone())
+      else {
+        infected_modules.insert(*identifier);
+        cache.remove(identifier);
+        stat_without_build += 1;
+        continue;
+      };
+      let references = compute_references(module_graph, module);
+      match cache.get(identifier) {
+        Some(cached) if cached.build_hash == build_hash && cached.references == references => {
+          module_mem_caches.insert(*identifier, cached.mem_cache.clone());
+          stat_unchanged += 1;
+          continue;
+        }
+        Some(cached) if cached.build_hash == build_hash => stat_references_changed += 1,
+        Some(_) => stat_changed += 1,
+        None => stat_new += 1,
+      }
+      let mem_cache = Arc::new(ModuleMemCache::default());
+      cache.insert(
+        *identifier,
+        CachedModuleMemCache {
+          build_hash,
+          references,
+          mem_cache: mem_cache.clone(),
+          chunk_graph: None,
+        },
+      );
+      module_mem_caches.insert(*identifier, mem_cache);
+      affected_modules.insert(*identifier);
+    }
+
+    // Changes of modules without a build hash can't be tracked, the modules referencing them
+    // are never cached.
+    let mut queue = infected_modules.iter().copied().collect::<VecDeque<_>>();
+    let mut direct_only_infected_modules = IdentifierSet::default();
+    while let Some(module) = queue.pop_front() {
+      for (referencing_module, connections) in
+        module_graph.get_incoming_connections_by_origin_module(&module)
+      {
+        let Some(referencing_module) = referencing_module else {
+          continue;
+        };
+        if infected_modules.contains(&referencing_module) {
+          continue;
+        }
+        match reduce_affect_type(module_graph, &connections) {
+          AffectType::False => {}
+          AffectType::True => {
+            direct_only_infected_modules.insert(referencing_module);
+          }
+          AffectType::Transitive => {
+            infected_modules.insert(referencing_module);
+            queue.push_back(referencing_module);
+          }
+        }
+      }
+    }
+    infected_modules.extend(direct_only_infected_modules);
+    for module in &infected_modules {
+      module_mem_caches.remove(module);
+      cache.remove(module);
+    }
+
+    let mut queue = affected_modules.iter().copied().collect::<VecDeque<_>>();
+    let mut direct_only_affected_modules = IdentifierSet::default();
+    while let Some(module) = queue.pop_front() {
+      for (referencing_module, connections) in
+        module_graph.get_incoming_connections_by_origin_module(&module)
+      {
+        let Some(referencing_module) = referencing_module else {
+          continue;
+        };
+        if infected_modules.contains(&referencing_module)
+          || affected_modules.contains(&referencing_module)
+        {
+          continue;
+        }
+        match reduce_affect_type(module_graph, &connections) {
+          AffectType::False => continue,
+          AffectType::True => {
+            if !direct_only_affected_modules.insert(referencing_module) {
+              continue;
+            }
+          }
+          AffectType::Transitive => {
+            affected_modules.insert(referencing_module);
+            queue.push_back(referencing_module);
+          }
+        }
+        let mem_cache = Arc::new(ModuleMemCache::default());
+        if let Some(cached) = cache.get_mut(&referencing_module) {
+          cached.mem_cache = mem_cache.clone();
+          cached.chunk_graph = None;
+        }
+        module_mem_caches.insert(referencing_module, mem_cache);
+      }
+    }
+    affected_modules.extend(direct_only_affected_modules);
+
+    let modules_count = module_graph.modules().len();
+    let logger = compilation.get_logger("rspack.Compilation");
+    logger.log(format!(
+      "{}% ({} affected + {} infected of {}) modules flagged as affected ({} new modules, {} changed, {} references changed, {} unchanged, {} were not built)",
+      (affected_modules.len() + infected_modules.len()) * 100 / modules_count.max(1),
+      affected_modules.len(),
+      infected_modules.len(),
+      modules_count,
+      stat_new,
+      stat_changed,
+      stat_references_changed,
+      stat_unchanged,
+      stat_without_build
+    ));
+
+    module_mem_caches
+  }
+
+  /// Narrow down the memory caches of `Compilation::module_mem_caches` to the modules whose
+  /// module id, the ids of the modules they reference and the chunks of their blocks are
+  /// unchanged, the results depending on the chunk graph (e.g. code generation) can be
+  /// cached in them.
+  pub fn compute_affected_modules_with_chunk_graph(
+    &self,
+    compilation: &Compilation,
+  ) -> IdentifierMap<Arc<ModuleMemCache>> {
+    let Some(module_mem_caches) = &compilation.module_mem_caches else {
+      return Default::default();
+    };
+    let mut cache = self.modules.lock().expect("should lock module mem caches");
+
+    let mut module_mem_caches_with_chunk_graph = IdentifierMap::default();
+    let mut stat_new = 0;
+    let mut stat_changed = 0;
+    let mut stat_unchanged = 0;
+
+    for identifier in module_mem_caches.keys() {
+      let (Some(cached), Some(module)) = (
+        cache.get_mut(identifier),
+        compilation.module_graph.module_by_identifier(identifier),
+      ) else {
+        continue;
+      };
+      let references = compute_chunk_graph_references(compilation, module);
+      match &cached.chunk_graph {
+        Some(chunk_graph) if chunk_graph.references == references => {
+          module_mem_caches_with_chunk_graph.insert(*identifier, chunk_graph.mem_cache.clone());
+          stat_unchanged += 1;
+          continue;
+        }
+        Some(_) => stat_changed += 1,
+        None => stat_new += 1,
+      }
+      let mem_cache = Arc::new(ModuleMemCache::default());
+      cached.chunk_graph = Some(ChunkGraphModuleMemCache {
+        references,
+        mem_cache: mem_cache.clone(),
+      });
+      module_mem_caches_with_chunk_graph.insert(*identifier, mem_cache);
+    }
+
+    let logger = compilation.get_logger("rspack.Compilation");
+    logger.log(format!(
+      "{}% modules flagged as affected by chunk graph ({} new modules, {} changed, {} unchanged)",
+      stat_changed * 100 / (stat_new + stat_changed + stat_unchanged).max(1),
+      stat_new,
+      stat_changed,
+      stat_unchanged
+    ));
+
+    module_mem_caches_with_chunk_graph
+  }
+}
+
+fn reduce_affect_type(
+  module_graph: &ModuleGraph,
+  connections: &[ModuleGraphConnection],
+) -> AffectType {
+  let mut affected = AffectType::False;
+  for connection in connections {
+    let Some(dependency) = module_graph.dependency_by_id(&connection.dependency_id) else {
+      continue;
+    };
+    match dependency.could_affect_referencing_module() {
+      AffectType::Transitive => return AffectType::Transitive,
+      AffectType::True => affected = AffectType::True,
+      AffectType::False => {}
+    }
+  }
+  affected
+}
+
+/// The modules the dependencies of `module` resolve to, in the order of the dependencies.
+fn compute_references(
+  module_graph: &ModuleGraph,
+  module: &BoxModule,
+) -> Vec<Option<ModuleIdentifier>> {
+  let resolve = |dependencies: &[_]| {
+    dependencies
+      .iter()
+      .map(|dependency| {
+        module_graph
+          .module_identifier_by_dependency_id(dependency)
+          .copied()
+      })
+      .collect::<Vec<_>>()
+  };
+  let mut references = resolve(module.get_dependencies());
+  let mut queue = module.get_blocks().iter().copied().collect::<VecDeque<_>>();
+  while let Some(block_id) = queue.pop_front() {
+    let Some(block) = module_graph.block_by_id(&block_id) else {
+      continue;
+    };
+    references.extend(resolve(block.get_dependencies()));
+    queue.extend(block.get_blocks().iter().copied());
+  }
+  references
+}
+
+fn compute_chunk_graph_references(
+  compilation: &Compilation,
+  module: &BoxModule,
+) -> ChunkGraphReferences {
+  let module_graph = &compilation.module_graph;
+  let get_module_id = |identifier: ModuleIdentifier| {
+    compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(&identifier)
+      .and_then(|cgm| cgm.id.clone())
+  };
+
+  let mut modules = module_graph
+    .get_outgoing_connections(module)
+    .into_iter()
+    .map(|connection| connection.module_identifier)
+    .collect::<Vec<_>>();
+  modules.sort_unstable();
+  modules.dedup();
+
+  let mut blocks = vec![];
+  let mut queue = module.get_blocks().iter().copied().collect::<VecDeque<_>>();
+  while let Some(block_id) = queue.pop_front() {
+    match compilation
+      .chunk_graph
+      .get_block_chunk_group(&block_id, &compilation.chunk_group_by_ukey)
+    {
+      Some(chunk_group) => blocks.extend(chunk_group.chunks.iter().map(|chunk| {
+        get_chunk_from_ukey(chunk, &compilation.chunk_by_ukey).and_then(|chunk| chunk.id.clone())
+      })),
+      None => blocks.push(None),
+    }
+    if let Some(block) = module_graph.block_by_id(&block_id) {
+      queue.extend(block.get_blocks().iter().copied());
+    }
+  }
+
+  ChunkGraphReferences {
+    id: get_module_id(module.identifier()),
+    modules: modules
+      .into_iter()
+      .map(|identifier| (identifier, get_module_id(identifier)))
+      .collect(),
+    blocks,
+  }
+}
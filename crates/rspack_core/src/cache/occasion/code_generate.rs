@@ -1,8 +1,13 @@
+use std::{
+  collections::hash_map::DefaultHasher,
+  hash::{Hash, Hasher},
+};
+
 use rspack_error::Result;
 use rspack_identifier::Identifier;
 
 use crate::{cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
-use crate::{RuntimeSpec, RuntimeSpecSet};
+use crate::{get_runtime_key, ConnectionState, RuntimeSpec, RuntimeSpecSet};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
@@ -23,6 +28,38 @@ impl CodeGenerateOccasion {
     compilation: &Compilation,
     generator: G,
   ) -> Result<(Vec<(CodeGenerationResult, RuntimeSpec)>, bool)>
+  where
+    G: Fn(&'a BoxModule, RuntimeSpecSet) -> Result<Vec<(CodeGenerationResult, RuntimeSpec)>>,
+  {
+    // only cache normal module in the module memory cache as well
+    let mem_cache = compilation
+      .module_mem_caches_with_chunk_graph
+      .as_ref()
+      .and_then(|caches| caches.get(&module.identifier()))
+      .filter(|_| module.as_normal_module().is_some());
+    let Some(mem_cache) = mem_cache else {
+      return self.use_storage(module, runtimes, compilation, generator);
+    };
+
+    let key = (
+      "CodeGenerateOccasion",
+      get_mem_cache_key(module, &runtimes, compilation),
+    );
+    if let Some(data) = mem_cache.get::<Vec<(CodeGenerationResult, RuntimeSpec)>>(key) {
+      return Ok(((*data).clone(), true));
+    }
+    let (data, from_cache) = self.use_storage(module, runtimes, compilation, generator)?;
+    mem_cache.set(key, data.clone());
+    Ok((data, from_cache))
+  }
+
+  fn use_storage<'a, G>(
+    &self,
+    module: &'a BoxModule,
+    runtimes: RuntimeSpecSet,
+    compilation: &Compilation,
+    generator: G,
+  ) -> Result<(Vec<(CodeGenerationResult, RuntimeSpec)>, bool)>
   where
     G: Fn(&'a BoxModule, RuntimeSpecSet) -> Result<Vec<(CodeGenerationResult, RuntimeSpec)>>,
   {
@@ -68,3 +105,56 @@ impl CodeGenerateOccasion {
     Ok((data, false))
   }
 }
+
+/// The module memory cache already tracks the module itself and the ids of the modules it
+/// references, what's left is the usage of the exports of the module and the referenced
+/// modules, which changes without the modules being affected.
+fn get_mem_cache_key(
+  module: &BoxModule,
+  runtimes: &RuntimeSpecSet,
+  compilation: &Compilation,
+) -> u64 {
+  let module_graph = &compilation.module_graph;
+  let connections = module_graph.get_outgoing_connections(module);
+  let mut referenced_modules = connections
+    .iter()
+    .map(|connection| connection.module_identifier)
+    .collect::<Vec<_>>();
+  referenced_modules.sort_unstable();
+  referenced_modules.dedup();
+
+  let mut runtimes = runtimes
+    .values()
+    .into_iter()
+    .map(|runtime| (get_runtime_key(runtime.clone()), runtime))
+    .collect::<Vec<_>>();
+  runtimes.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+  let mut hasher = DefaultHasher::new();
+  for (runtime_key, runtime) in runtimes {
+    runtime_key.hash(&mut hasher);
+    let mut active_states = connections
+      .iter()
+      .map(|connection| {
+        let state = match connection.get_active_state(module_graph, Some(runtime)) {
+          ConnectionState::Bool(false) => 0,
+          ConnectionState::Bool(true) => 1,
+          ConnectionState::CircularConnection => 2,
+          ConnectionState::TransitiveOnly => 3,
+        };
+        (connection.module_identifier, state)
+      })
+      .collect::<Vec<_>>();
+    active_states.sort_unstable();
+    active_states.hash(&mut hasher);
+    for identifier in std::iter::once(module.identifier()).chain(referenced_modules.iter().copied())
+    {
+      module_graph.get_exports_info(&identifier).id.update_hash(
+        module_graph,
+        &mut hasher,
+        Some(runtime),
+      );
+    }
+  }
+  hasher.finish()
+}
@@ -8,7 +8,7 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::{get_chunk_from_ukey, Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey};
 use crate::{ChunkLoading, ChunkUkey, Compilation, Filename};
-use crate::{LibraryOptions, ModuleIdentifier, PublicPath, RuntimeSpec};
+use crate::{LibraryOptions, ModuleIdentifier, PublicPath, RealDependencyLocation, RuntimeSpec};
 
 impl DatabaseItem for ChunkGroup {
   fn ukey(&self) -> rspack_database::Ukey<Self> {
@@ -16,6 +16,13 @@ impl DatabaseItem for ChunkGroup {
   }
 }
 
+#[derive(Debug, Clone)]
+pub struct OriginRecord {
+  pub module_id: Option<ModuleIdentifier>,
+  pub loc: Option<RealDependencyLocation>,
+  pub request: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ChunkGroup {
   pub ukey: ChunkGroupUkey,
@@ -34,6 +41,7 @@ pub struct ChunkGroup {
   // Entrypoint
   pub(crate) runtime_chunk: Option<ChunkUkey>,
   pub(crate) entry_point_chunk: Option<ChunkUkey>,
+  origins: Vec<OriginRecord>,
 }
 
 impl ChunkGroup {
@@ -53,6 +61,7 @@ impl ChunkGroup {
       runtime_chunk: None,
       entry_point_chunk: None,
       index: None,
+      origins: vec![],
     }
   }
 
@@ -142,6 +151,23 @@ impl ChunkGroup {
     self.async_entrypoints.iter()
   }
 
+  pub fn add_origin(
+    &mut self,
+    module_id: Option<ModuleIdentifier>,
+    loc: Option<RealDependencyLocation>,
+    request: Option<String>,
+  ) {
+    self.origins.push(OriginRecord {
+      module_id,
+      loc,
+      request,
+    });
+  }
+
+  pub fn origins(&self) -> &Vec<OriginRecord> {
+    &self.origins
+  }
+
   pub fn ancestors(&self, chunk_group_by_ukey: &ChunkGroupByUkey) -> HashSet<ChunkGroupUkey> {
     let mut queue = vec![];
     let mut ancestors = HashSet::default();
@@ -26,10 +26,11 @@ use tracing::instrument;
 use super::{
   hmr::CompilationRecords,
   make::{MakeParam, RebuildDepsBuilder},
+  records::{AggressiveSplitRecord, Records},
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
-  cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
+  cache::{use_code_splitting_cache, Cache, CodeSplittingCache, ModuleMemCache},
   get_chunk_from_ukey, get_mut_chunk_from_ukey, is_source_equal,
   tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
   AddQueue, AddQueueHandler, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs,
@@ -64,6 +65,10 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// Records read from `records_input_path` or kept from the last build
+  pub input_records: Option<Arc<Records>>,
+  /// Chunk splits made by the AggressiveSplittingPlugin, written to the output records
+  pub aggressive_splits: Vec<AggressiveSplitRecord>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -98,10 +103,16 @@ pub struct Compilation {
   pub code_generation_results: CodeGenerationResults,
   pub code_generated_modules: IdentifierSet,
   pub cache: Arc<Cache>,
+  /// Memory caches of the modules unaffected since the last compilation, only available with
+  /// `experiments.cacheUnaffected`
+  pub module_mem_caches: Option<IdentifierMap<Arc<ModuleMemCache>>>,
+  /// Memory caches of the modules whose ids and chunk graph references are unchanged as well
+  pub module_mem_caches_with_chunk_graph: Option<IdentifierMap<Arc<ModuleMemCache>>>,
   pub code_splitting_cache: CodeSplittingCache,
   pub hash: Option<RspackHashDigest>,
   // lazy compilation visit module
   pub lazy_visit_modules: std::collections::HashSet<String>,
+  pub used_module_ids: HashSet<String>,
   pub used_chunk_ids: HashSet<String>,
   pub include_module_ids: IdentifierSet,
 
@@ -133,6 +144,8 @@ impl Compilation {
     Self {
       hot_index: 0,
       records,
+      input_records: None,
+      aggressive_splits: Default::default(),
       options,
       module_graph,
       dependency_factories: Default::default(),
@@ -165,9 +178,12 @@ impl Compilation {
       code_generation_results: Default::default(),
       code_generated_modules: Default::default(),
       cache,
+      module_mem_caches: None,
+      module_mem_caches_with_chunk_graph: None,
       code_splitting_cache: Default::default(),
       hash: None,
       lazy_visit_modules: Default::default(),
+      used_module_ids: Default::default(),
       used_chunk_ids: Default::default(),
 
       file_dependencies: Default::default(),
@@ -347,12 +363,19 @@ impl Compilation {
     &self.entrypoints
   }
 
+  /// Diagnostics are filtered and remapped by [CompilerOptions::apply_diagnostic_rules] on the way in.
   pub fn push_diagnostic(&mut self, diagnostic: Diagnostic) {
-    self.diagnostics.push(diagnostic);
+    self
+      .diagnostics
+      .extend(self.options.apply_diagnostic_rules(diagnostic));
   }
 
   pub fn push_batch_diagnostic(&mut self, diagnostics: Vec<Diagnostic>) {
-    self.diagnostics.extend(diagnostics);
+    self.diagnostics.extend(
+      diagnostics
+        .into_iter()
+        .filter_map(|diagnostic| self.options.apply_diagnostic_rules(diagnostic)),
+    );
   }
 
   pub fn get_errors(&self) -> impl Iterator<Item = &Diagnostic> {
@@ -465,7 +488,13 @@ impl Compilation {
 
     params.push(make_failed_module);
     params.push(make_failed_dependencies);
-    self.update_module_graph(params).await
+    self.update_module_graph(params).await?;
+
+    // Resolutions change once the dependencies are installed again
+    self
+      .build_dependencies
+      .extend(self.resolver_factory.pnp_manifest_paths());
+    Ok(())
   }
 
   pub async fn rebuild_module(
@@ -921,6 +950,7 @@ impl Compilation {
                   build_queue: self.build_queue.clone(),
                   process_dependencies_queue: self.process_dependencies_queue.clone(),
                   build_time_execution_queue: self.build_time_execution_queue.clone(),
+                  logging: self.logging.clone(),
                 });
               }
               AddTaskResult::ModuleReused { module, .. } => {
@@ -1398,6 +1428,7 @@ impl Compilation {
 
   #[instrument(name = "compilation::create_module_assets", skip_all)]
   async fn create_module_assets(&mut self, _plugin_driver: SharedPluginDriver) {
+    let mut module_assets = vec![];
     for (module_identifier, module) in self.module_graph.modules() {
       if let Some(build_info) = module.build_info() {
         for asset in build_info.asset_filenames.iter() {
@@ -1411,8 +1442,18 @@ impl Compilation {
           }
           // already emitted asset by loader, so no need to re emit here
         }
+        // assets of native loaders are kept in build info, so they are emitted for cached modules too
+        module_assets.extend(
+          build_info
+            .assets
+            .iter()
+            .map(|(filename, asset)| (filename.clone(), asset.clone())),
+        );
       }
     }
+    for (filename, asset) in module_assets {
+      self.emit_asset(filename, asset);
+    }
   }
 
   #[instrument(skip_all)]
@@ -1541,6 +1582,15 @@ impl Compilation {
   #[instrument(name = "compilation:finish", skip_all)]
   pub async fn finish(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     let logger = self.get_logger("rspack.Compilation");
+    // `finish` runs again after make with the new tree shaking
+    if let Some(module_mem_caches) = &self.cache.module_mem_caches
+      && self.module_mem_caches.is_none()
+    {
+      let start = logger.time("compute affected modules");
+      self.module_mem_caches = Some(module_mem_caches.compute_affected_modules(self));
+      logger.time_end(start);
+    }
+
     let start = logger.time("finish modules");
     plugin_driver.finish_modules(self).await?;
     logger.time_end(start);
@@ -1589,6 +1639,13 @@ impl Compilation {
 
     self.assign_runtime_ids();
 
+    if let Some(module_mem_caches) = &self.cache.module_mem_caches {
+      let start = logger.time("compute affected modules with chunk graph");
+      self.module_mem_caches_with_chunk_graph =
+        Some(module_mem_caches.compute_affected_modules_with_chunk_graph(self));
+      logger.time_end(start);
+    }
+
     let start = logger.time("optimize code generation");
     plugin_driver.optimize_code_generation(self).await?;
     logger.time_end(start);
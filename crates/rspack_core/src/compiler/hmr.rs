@@ -1,6 +1,6 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
 use rspack_hash::RspackHashDigest;
@@ -10,7 +10,8 @@ use rustc_hash::FxHashSet as HashSet;
 
 use super::MakeParam;
 use crate::{
-  fast_set, get_chunk_from_ukey, ChunkKind, Compilation, Compiler, ModuleGraph, RuntimeSpec,
+  fast_set, get_chunk_from_ukey, ChunkKind, Compilation, Compiler, ModuleGraph, Records,
+  RuntimeSpec,
 };
 
 impl<T> Compiler<T>
@@ -26,8 +27,7 @@ where
     let old = self.compilation.get_stats();
     let old_hash = self.compilation.hash.clone();
 
-    let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation);
-    // TODO: should use `records`
+    let old_runtime_modules = collect_runtime_modules(old.compilation);
 
     let mut all_old_runtime: RuntimeSpec = Default::default();
     for entry_ukey in old.compilation.get_chunk_graph_entries() {
@@ -48,7 +48,7 @@ where
     let records = CompilationRecords {
       old_chunks,
       all_old_runtime,
-      old_all_modules,
+      old_records: self.records.clone().unwrap_or_default(),
       old_runtime_modules,
       old_hash,
     };
@@ -84,6 +84,7 @@ where
       }
 
       new_compilation.hot_index = self.compilation.hot_index + 1;
+      new_compilation.input_records = self.records.clone();
 
       let is_incremental_rebuild_make = self.options.is_incremental_rebuild_make_enabled();
       if is_incremental_rebuild_make {
@@ -147,37 +148,14 @@ where
 pub struct CompilationRecords {
   pub old_chunks: Vec<(String, RuntimeSpec)>,
   pub all_old_runtime: RuntimeSpec,
-  pub old_all_modules: IdentifierMap<(RspackHashDigest, String)>,
+  /// The module ids and module hashes of the last compilation
+  pub old_records: Arc<Records>,
   pub old_runtime_modules: IdentifierMap<String>,
   pub old_hash: Option<RspackHashDigest>,
 }
 
-pub fn collect_changed_modules(
-  compilation: &Compilation,
-) -> (
-  IdentifierMap<(RspackHashDigest, String)>,
-  IdentifierMap<String>,
-) {
-  let modules_map = compilation
-    .chunk_graph
-    .chunk_graph_module_by_module_identifier
-    .par_iter()
-    .filter_map(|(identifier, cgm)| {
-      let cid = cgm.id.as_deref();
-      // TODO: Determine how to calc module hash if module related to multiple runtime code
-      // gen
-      if let Some(code_generation_result) = compilation.code_generation_results.get_one(identifier)
-        && let Some(module_hash) = &code_generation_result.hash
-        && let Some(cid) = cid
-      {
-        Some((*identifier, (module_hash.clone(), cid.to_string())))
-      } else {
-        None
-      }
-    })
-    .collect::<IdentifierMap<_>>();
-
-  let old_runtime_modules = compilation
+pub fn collect_runtime_modules(compilation: &Compilation) -> IdentifierMap<String> {
+  compilation
     .runtime_modules
     .iter()
     .map(|(identifier, module)| {
@@ -189,7 +167,5 @@ pub fn collect_changed_modules(
           .to_string(),
       )
     })
-    .collect();
-
-  (modules_map, old_runtime_modules)
+    .collect()
 }
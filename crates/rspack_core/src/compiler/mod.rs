@@ -3,6 +3,7 @@ mod execute_module;
 mod hmr;
 mod make;
 mod queue;
+mod records;
 
 use std::collections::hash_map::Entry;
 use std::ops::Deref;
@@ -18,9 +19,10 @@ use swc_core::ecma::atoms::Atom;
 use tracing::instrument;
 
 pub use self::compilation::*;
-pub use self::hmr::{collect_changed_modules, CompilationRecords};
+pub use self::hmr::{collect_runtime_modules, CompilationRecords};
 pub use self::make::MakeParam;
 pub use self::queue::*;
+pub use self::records::*;
 use crate::cache::Cache;
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
@@ -46,6 +48,8 @@ where
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// records of the last build, see `records_input_path` and `records_output_path`
+  pub records: Option<Arc<Records>>,
 }
 
 impl<T> Compiler<T>
@@ -53,20 +57,24 @@ where
   T: AsyncWritableFileSystem + Send + Sync,
 {
   #[instrument(skip_all)]
-  pub fn new(options: CompilerOptions, plugins: Vec<BoxPlugin>, output_filesystem: T) -> Self {
+  pub fn new(
+    options: CompilerOptions,
+    plugins: Vec<BoxPlugin>,
+    output_filesystem: T,
+  ) -> Result<Self> {
     #[cfg(debug_assertions)]
     {
       if let Ok(mut debug_info) = crate::debug_info::DEBUG_INFO.lock() {
         debug_info.with_context(options.context.to_string());
       }
     }
-    let resolver_factory = Arc::new(ResolverFactory::new(options.resolve.clone()));
-    let loader_resolver_factory = Arc::new(ResolverFactory::new(options.resolve_loader.clone()));
-    let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
+    let (plugin_driver, options) = PluginDriver::new(options, plugins)?;
+    let resolver_factory = plugin_driver.resolver_factory.clone();
+    let loader_resolver_factory = plugin_driver.loader_resolver_factory.clone();
     let cache = Arc::new(Cache::new(options.clone()));
     let is_new_treeshaking = options.is_new_tree_shaking();
     assert!(!(options.is_new_tree_shaking() && options.builtins.tree_shaking.enable()), "Can't enable builtins.tree_shaking and `experiments.rspack_future.new_treeshaking` at the same time");
-    Self {
+    Ok(Self {
       options: options.clone(),
       compilation: Compilation::new(
         options,
@@ -83,7 +91,8 @@ where
       loader_resolver_factory,
       cache,
       emitted_asset_versions: Default::default(),
-    }
+      records: None,
+    })
   }
 
   pub async fn run(&mut self) -> Result<()> {
@@ -98,6 +107,12 @@ where
     // TODO: maybe it's better to use external entries.
     self.plugin_driver.resolver_factory.clear_cache();
 
+    if self.records.is_none()
+      && let Some(records_input_path) = &self.options.records_input_path
+    {
+      self.records = Records::read(records_input_path).await?.map(Arc::new);
+    }
+
     fast_set(
       &mut self.compilation,
       Compilation::new(
@@ -110,6 +125,7 @@ where
         self.cache.clone(),
       ),
     );
+    self.compilation.input_records = self.records.clone();
 
     self
       .compile(vec![MakeParam::ForceBuildDeps(Default::default())])
@@ -289,6 +305,10 @@ where
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
     let start = logger.time("done hook");
     self.compilation.done(self.plugin_driver.clone()).await?;
     logger.time_end(start);
@@ -353,6 +373,21 @@ where
     self.plugin_driver.after_emit(&mut self.compilation).await
   }
 
+  async fn emit_records(&mut self) -> Result<()> {
+    let records = Records::from_compilation(&self.compilation);
+    if let Some(records_output_path) = &self.options.records_output_path {
+      if let Some(parent) = records_output_path.parent() {
+        self.output_filesystem.create_dir_all(parent).await?;
+      }
+      self
+        .output_filesystem
+        .write(records_output_path, records.to_json()?.as_bytes())
+        .await?;
+    }
+    self.records = Some(Arc::new(records));
+    Ok(())
+  }
+
   async fn emit_asset(
     &self,
     output_path: &Path,
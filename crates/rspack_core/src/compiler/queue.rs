@@ -8,10 +8,10 @@ use rustc_hash::FxHashSet as HashSet;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
-  cache::Cache, BoxDependency, BuildContext, BuildResult, Compilation, CompilerContext,
-  CompilerOptions, Context, Module, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, Resolve, ResolverFactory,
-  SharedPluginDriver, WorkerQueue,
+  cache::Cache, BoxDependency, BuildContext, BuildResult, Compilation, CompilationLogging,
+  CompilerContext, CompilerOptions, Context, Module, ModuleFactory, ModuleFactoryCreateData,
+  ModuleFactoryResult, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, Resolve,
+  ResolverFactory, SharedPluginDriver, WorkerQueue,
 };
 use crate::{
   BoxModule, DependencyId, ExecuteModuleResult, ExportInfo, ExportsInfo, QueueHandler, UsageState,
@@ -351,6 +351,7 @@ pub struct BuildTask {
   pub build_queue: Option<BuildQueueHandler>,
   pub process_dependencies_queue: Option<ProcessDependenciesQueueHandler>,
   pub build_time_execution_queue: Option<BuildTimeExecutionQueueHandler>,
+  pub logging: CompilationLogging,
 }
 
 #[derive(Debug)]
@@ -399,6 +400,8 @@ impl WorkerTask for BuildTask {
                 build_time_execution_queue: self.build_time_execution_queue.clone(),
                 plugin_driver: plugin_driver.clone(),
                 cache: cache.clone(),
+                logging: self.logging.clone(),
+                emitted_assets: Default::default(),
               },
               plugin_driver: plugin_driver.clone(),
               compiler_options: &compiler_options,
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_fs::{AsyncNativeFileSystem, AsyncReadableFileSystem};
+use serde::{Deserialize, Serialize};
+
+use crate::{contextify, get_runtime_key, Chunk, ChunkKind, Compilation, ModuleIdentifier};
+
+/// The records of a previous build, read from `records_input_path` and written to
+/// `records_output_path`. Module and chunk ids are revived from them by the id plugins.
+/// They are kept in memory between the builds of a compiler even without these paths.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Records {
+  pub modules: ModulesRecords,
+  pub chunks: ChunksRecords,
+  pub hash: Option<String>,
+  /// Hashes of the code generated for the modules, by the record keys of the modules and the
+  /// keys of their runtimes. The hot module replacement compares them to find the updated modules.
+  pub module_hashes: BTreeMap<String, BTreeMap<String, String>>,
+  pub aggressive_splits: Vec<AggressiveSplitRecord>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModulesRecords {
+  pub by_identifier: BTreeMap<String, String>,
+  pub used_ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChunksRecords {
+  pub by_name: BTreeMap<String, String>,
+  pub by_source: BTreeMap<String, String>,
+  pub used_ids: Vec<String>,
+}
+
+/// A chunk split made by the AggressiveSplittingPlugin. `modules` are record keys of the
+/// modules moved into the split chunk, and `size` is their total size when the split was made.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AggressiveSplitRecord {
+  pub modules: Vec<String>,
+  pub size: f64,
+}
+
+impl Records {
+  /// Returns `None` if the records file doesn't exist yet.
+  pub async fn read(path: &Path) -> Result<Option<Self>> {
+    let content = match AsyncNativeFileSystem.read(path).await {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(e) => return Err(e.into()),
+    };
+    serde_json::from_slice(&content).into_diagnostic().map(Some)
+  }
+
+  pub fn from_compilation(compilation: &Compilation) -> Self {
+    let context = compilation.options.context.as_str();
+    let chunk_graph = &compilation.chunk_graph;
+    let mut records = Self {
+      hash: compilation
+        .hash
+        .as_ref()
+        .map(|hash| hash.encoded().to_string()),
+      aggressive_splits: compilation.aggressive_splits.clone(),
+      ..Default::default()
+    };
+
+    for identifier in compilation.module_graph.modules().keys() {
+      let Some(id) = chunk_graph.get_module_id(*identifier) else {
+        continue;
+      };
+      let key = get_module_record_key(context, identifier);
+      let module_hashes = get_module_hashes(compilation, identifier);
+      if !module_hashes.is_empty() {
+        records.module_hashes.insert(key.clone(), module_hashes);
+      }
+      records.modules.by_identifier.insert(key, id.clone());
+      records.modules.used_ids.push(id.clone());
+    }
+    records.modules.used_ids.sort();
+
+    for chunk in compilation.chunk_by_ukey.values() {
+      if chunk.kind == ChunkKind::HotUpdate {
+        continue;
+      }
+      let Some(id) = &chunk.id else {
+        continue;
+      };
+      if let Some(name) = &chunk.name {
+        records.chunks.by_name.insert(name.clone(), id.clone());
+      }
+      for source in get_chunk_sources(chunk, compilation) {
+        records.chunks.by_source.insert(source, id.clone());
+      }
+      records.chunks.used_ids.push(id.clone());
+    }
+    records.chunks.used_ids.sort();
+
+    records
+  }
+
+  pub fn to_json(&self) -> Result<String> {
+    serde_json::to_string_pretty(self).into_diagnostic()
+  }
+}
+
+/// Module identifiers are stored relative to the context, so the records stay valid
+/// when the project is moved.
+pub fn get_module_record_key(context: &str, identifier: &ModuleIdentifier) -> String {
+  identifier
+    .split('|')
+    .map(|part| contextify(context, part))
+    .collect::<Vec<_>>()
+    .join("|")
+}
+
+/// Hashes of the code generated for the module in each of its runtimes, by the runtime keys.
+/// The code of a module shared by several runtimes may differ between them.
+pub fn get_module_hashes(
+  compilation: &Compilation,
+  identifier: &ModuleIdentifier,
+) -> BTreeMap<String, String> {
+  let Some(code_generation_result_ids) = compilation.code_generation_results.map.get(identifier)
+  else {
+    return BTreeMap::new();
+  };
+  compilation
+    .chunk_graph
+    .get_module_runtimes(*identifier, &compilation.chunk_by_ukey)
+    .values()
+    .into_iter()
+    .filter_map(|runtime| {
+      let hash = code_generation_result_ids
+        .get(runtime)
+        .and_then(|result_id| {
+          compilation
+            .code_generation_results
+            .module_generation_result_map
+            .get(result_id)
+        })
+        .and_then(|code_generation_result| code_generation_result.hash.as_ref())?;
+      Some((get_runtime_key(runtime.clone()), hash.encoded().to_string()))
+    })
+    .collect()
+}
+
+/// Describes a chunk by the chunk groups it belongs to, aligned with `getChunkSources`
+/// of webpack's RecordIdsPlugin.
+pub fn get_chunk_sources(chunk: &Chunk, compilation: &Compilation) -> Vec<String> {
+  let context = compilation.options.context.as_str();
+  let mut sources = vec![];
+  for group_ukey in &chunk.groups {
+    let group = compilation.chunk_group_by_ukey.expect_get(group_ukey);
+    let Some(index) = group.chunks.iter().position(|c| *c == chunk.ukey) else {
+      continue;
+    };
+    if let Some(name) = group.name() {
+      sources.push(format!("{index} {name}"));
+      continue;
+    }
+    for origin in group.origins() {
+      if let Some(module) = &origin.module_id
+        && let Some(request) = &origin.request
+      {
+        sources.push(format!(
+          "{index} {} {request}",
+          get_module_record_key(context, module)
+        ));
+      }
+    }
+  }
+  sources.sort();
+  sources
+}
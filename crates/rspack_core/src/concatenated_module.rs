@@ -418,7 +418,7 @@ impl ConcatenatedModule {
     self.id
   }
 
-  fn get_modules(&self) -> Vec<ConcatenatedInnerModule> {
+  pub fn get_modules(&self) -> Vec<ConcatenatedInnerModule> {
     self.modules.clone()
   }
 }
@@ -516,10 +516,14 @@ impl Module for ConcatenatedModule {
       missing_dependencies: Default::default(),
       build_dependencies: Default::default(),
       asset_filenames: Default::default(),
+      assets: Default::default(),
       harmony_named_exports: Default::default(),
       all_star_exports: Default::default(),
       need_create_require: Default::default(),
       json_data: Default::default(),
+      js_incompatible_exports: Default::default(),
+      no_side_effects_functions: Default::default(),
+      no_side_effects_calls: Default::default(),
     };
     self.clear_diagnostics();
 
@@ -1,4 +1,5 @@
 use std::{
+  fmt,
   hash::{Hash, Hasher},
   sync::atomic::{AtomicU32, Ordering},
 };
@@ -67,6 +68,40 @@ impl DependencyLocation {
   }
 }
 
+/// Line and column of a position in the source of a module. Lines start at 1 and columns at 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourcePosition {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// Same as the `RealDependencyLocation` of webpack, the line and column range of a
+/// [DependencyLocation] in the source of a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RealDependencyLocation {
+  pub start: SourcePosition,
+  pub end: SourcePosition,
+}
+
+impl fmt::Display for RealDependencyLocation {
+  /// Formats like `formatLocation` of webpack, e.g. `2:0-15` or `2:0-3:4`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.start.line == self.end.line {
+      write!(
+        f,
+        "{}:{}-{}",
+        self.start.line, self.start.column, self.end.column
+      )
+    } else {
+      write!(
+        f,
+        "{}:{}-{}:{}",
+        self.start.line, self.start.column, self.end.line, self.end.column
+      )
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct AsyncDependenciesBlock {
   id: AsyncDependenciesBlockId,
@@ -75,13 +110,13 @@ pub struct AsyncDependenciesBlock {
   block_ids: Vec<AsyncDependenciesBlockId>,
   dependency_ids: Vec<DependencyId>,
   dependencies: Vec<BoxDependency>,
-  loc: Option<DependencyLocation>,
+  loc: Option<RealDependencyLocation>,
   parent: ModuleIdentifier,
 }
 
 impl AsyncDependenciesBlock {
   /// modifier should be Dependency.span in most of time
-  pub fn new(parent: ModuleIdentifier, loc: Option<DependencyLocation>) -> Self {
+  pub fn new(parent: ModuleIdentifier, loc: Option<RealDependencyLocation>) -> Self {
     Self {
       id: AsyncDependenciesBlockId::new(),
       group_options: Default::default(),
@@ -127,7 +162,7 @@ impl AsyncDependenciesBlock {
     std::mem::take(&mut self.blocks)
   }
 
-  pub fn loc(&self) -> Option<&DependencyLocation> {
+  pub fn loc(&self) -> Option<&RealDependencyLocation> {
     self.loc.as_ref()
   }
 
@@ -174,4 +209,4 @@ impl UpdateRspackHash for AsyncDependenciesBlock {
 #[derive(Debug, Error, Diagnostic)]
 #[diagnostic(code(AsyncDependencyToInitialChunkError))]
 #[error("It's not allowed to load an initial chunk on demand. The chunk name \"{0}\" is already used by an entrypoint.")]
-pub struct AsyncDependenciesToInitialChunkError(pub String, pub Option<DependencyLocation>);
+pub struct AsyncDependenciesToInitialChunkError(pub String, pub Option<RealDependencyLocation>);
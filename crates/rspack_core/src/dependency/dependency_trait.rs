@@ -12,6 +12,15 @@ use super::{DependencyCategory, DependencyId, DependencyType};
 use crate::AsContextDependency;
 use crate::{ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, UsedByExports};
 
+/// How a change of the referenced module affects the module that references it,
+/// `Transitive` also affects the modules referencing the referencing module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AffectType {
+  False,
+  True,
+  Transitive,
+}
+
 pub trait Dependency:
   AsDependencyTemplate
   + AsContextDependency
@@ -84,6 +93,10 @@ pub trait Dependency:
   fn resource_identifier(&self) -> Option<&str> {
     None
   }
+
+  fn could_affect_referencing_module(&self) -> AffectType {
+    AffectType::Transitive
+  }
 }
 
 impl dyn Dependency + '_ {
@@ -37,8 +37,8 @@ pub use static_exports_dependency::StaticExportsDependency;
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  ConnectionState, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ReferencedExport,
-  RuntimeSpec,
+  ConnectionState, InlinableValue, ModuleGraph, ModuleGraphConnection, ModuleIdentifier,
+  ReferencedExport, RuntimeSpec,
 };
 
 #[derive(Debug, Default)]
@@ -52,6 +52,7 @@ pub struct ExportSpec {
   pub hidden: Option<bool>,
   pub from: Option<ModuleGraphConnection>,
   pub from_export: Option<ModuleGraphConnection>,
+  pub inlinable: Option<InlinableValue>,
 }
 
 #[derive(Debug)]
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use itertools::Itertools;
 use rspack_error::{
-  impl_diagnostic_transparent,
+  codes, impl_diagnostic_transparent,
   miette::{self, Diagnostic},
   thiserror::{self, Error},
   DiagnosticExt, Error, TraceableError,
@@ -30,7 +30,7 @@ impl EmptyDependency {
   }
 }
 
-impl_diagnostic_transparent!(EmptyDependency);
+impl_diagnostic_transparent!(code = codes::EMPTY_DEPENDENCY, EmptyDependency);
 
 ///////////////////// Module /////////////////////
 
@@ -51,7 +51,7 @@ impl std::fmt::Display for ModuleBuildError {
 
 impl miette::Diagnostic for ModuleBuildError {
   fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
-    Some(Box::new("ModuleBuildError"))
+    Some(Box::new(codes::MODULE_BUILD_ERROR))
   }
   fn severity(&self) -> Option<miette::Severity> {
     self.0.severity()
@@ -95,11 +95,15 @@ impl miette::Diagnostic for ModuleParseError {
     self.source.severity()
   }
 
+  // Prefer the more specific code of the source, e.g. magic comments warnings
   fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    if let Some(code) = self.source.code() {
+      return Some(code);
+    }
     match self.severity().unwrap_or(miette::Severity::Error) {
       miette::Severity::Advice => unreachable!("miette::Severity::Advice should not be used"),
-      miette::Severity::Warning => Some(Box::new("ModuleParseWarning")),
-      miette::Severity::Error => Some(Box::new("ModuleParseError")),
+      miette::Severity::Warning => Some(Box::new(codes::MODULE_PARSE_WARNING)),
+      miette::Severity::Error => Some(Box::new(codes::MODULE_PARSE_ERROR)),
     }
   }
 
@@ -439,6 +439,138 @@ impl ExportsInfoId {
     let exports_info = mg.get_exports_info_by_id(self);
     exports_info.is_used(runtime, mg)
   }
+
+  /// Hash the provided and used state of the exports in `runtime`, including the mangled names,
+  /// https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/ExportsInfo.js#L798-L817
+  pub fn update_hash(
+    &self,
+    mg: &ModuleGraph,
+    hasher: &mut dyn Hasher,
+    runtime: Option<&RuntimeSpec>,
+  ) {
+    self.update_hash_inner(mg, hasher, runtime, &mut HashSet::default());
+  }
+
+  fn update_hash_inner(
+    &self,
+    mg: &ModuleGraph,
+    hasher: &mut dyn Hasher,
+    runtime: Option<&RuntimeSpec>,
+    visited: &mut HashSet<ExportsInfoId>,
+  ) {
+    if !visited.insert(*self) {
+      return;
+    }
+    let exports_info = self.get_exports_info(mg);
+    for export_info_id in exports_info.exports.values() {
+      export_info_id
+        .get_export_info(mg)
+        .update_hash(mg, hasher, runtime, visited);
+    }
+    for export_info_id in [
+      exports_info._side_effects_only_info,
+      exports_info.other_exports_info,
+    ] {
+      export_info_id
+        .get_export_info(mg)
+        .update_hash(mg, hasher, runtime, visited);
+    }
+    if let Some(redirect_to) = exports_info.redirect_to {
+      redirect_to.update_hash_inner(mg, hasher, runtime, visited);
+    }
+  }
+
+  /// The export targets are not part of the data, they are keyed by dependencies that don't
+  /// outlive a compilation.
+  pub fn get_restore_provided_data(&self, mg: &ModuleGraph) -> RestoreProvidedData {
+    let exports_info = self.get_exports_info(mg);
+    let other_exports_info = exports_info.other_exports_info.get_export_info(mg);
+    let mut data = RestoreProvidedData {
+      exports: vec![],
+      other_provided: other_exports_info.provided,
+      other_can_mangle_provide: other_exports_info.can_mangle_provide,
+      other_terminal_binding: other_exports_info.terminal_binding,
+    };
+    for export_info_id in exports_info.exports.values() {
+      let export_info = export_info_id.get_export_info(mg);
+      if export_info.provided != data.other_provided
+        || export_info.can_mangle_provide != data.other_can_mangle_provide
+        || export_info.terminal_binding != data.other_terminal_binding
+        || export_info.inlinable.is_some()
+        || export_info.exports_info_owned
+      {
+        data.exports.push(RestoreProvidedDataExport {
+          name: export_info
+            .name
+            .clone()
+            .expect("the name of export_info inserted in exports_info can not be `None`"),
+          provided: export_info.provided,
+          can_mangle_provide: export_info.can_mangle_provide,
+          terminal_binding: export_info.terminal_binding,
+          inlinable: export_info.inlinable.clone(),
+          exports_info: export_info
+            .exports_info
+            .filter(|_| export_info.exports_info_owned)
+            .map(|exports_info| Box::new(exports_info.get_restore_provided_data(mg))),
+        });
+      }
+    }
+    data
+  }
+
+  pub fn restore_provided(&self, mg: &mut ModuleGraph, data: &RestoreProvidedData) {
+    let exports_info = self.get_exports_info(mg);
+    let was_empty = exports_info.exports.is_empty();
+    let export_info_id_list = exports_info
+      .exports
+      .values()
+      .copied()
+      .chain([exports_info.other_exports_info])
+      .collect::<Vec<_>>();
+    for export_info_id in export_info_id_list {
+      let export_info = export_info_id.get_export_info_mut(mg);
+      export_info.provided = data.other_provided;
+      export_info.can_mangle_provide = data.other_can_mangle_provide;
+      export_info.terminal_binding = data.other_terminal_binding;
+      export_info.inlinable = None;
+    }
+    for export in &data.exports {
+      let export_info_id = self.get_export_info(&export.name, mg);
+      let export_info = export_info_id.get_export_info_mut(mg);
+      export_info.provided = export.provided;
+      export_info.can_mangle_provide = export.can_mangle_provide;
+      export_info.terminal_binding = export.terminal_binding;
+      export_info.inlinable = export.inlinable.clone();
+      if let Some(nested) = &export.exports_info {
+        export_info_id
+          .create_nested_exports_info(mg)
+          .restore_provided(mg, nested);
+      }
+    }
+    if was_empty {
+      self.get_exports_info_mut(mg)._exports_are_ordered = true;
+    }
+  }
+}
+
+/// The provided exports of a module, used to restore an `ExportsInfo` without running
+/// `FlagDependencyExportsPlugin` for the module again.
+#[derive(Debug, Clone)]
+pub struct RestoreProvidedData {
+  pub exports: Vec<RestoreProvidedDataExport>,
+  pub other_provided: Option<ExportInfoProvided>,
+  pub other_can_mangle_provide: Option<bool>,
+  pub other_terminal_binding: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestoreProvidedDataExport {
+  pub name: Atom,
+  pub provided: Option<ExportInfoProvided>,
+  pub can_mangle_provide: Option<bool>,
+  pub terminal_binding: bool,
+  pub inlinable: Option<InlinableValue>,
+  pub exports_info: Option<Box<RestoreProvidedData>>,
 }
 
 #[derive(Debug)]
@@ -484,6 +616,12 @@ pub enum ProvidedExports {
   True,
   Vec(Vec<Atom>),
 }
+
+pub enum UsedExports {
+  Null,
+  Bool(bool),
+  Vec(Vec<Atom>),
+}
 impl ExportsInfo {
   pub fn new(other_exports_info: ExportInfoId, _side_effects_only_info: ExportInfoId) -> Self {
     Self {
@@ -538,6 +676,49 @@ impl ExportsInfo {
     ProvidedExports::Vec(ret)
   }
 
+  pub fn get_used_exports(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> UsedExports {
+    if self.redirect_to.is_none() {
+      match self.other_exports_info.get_used(mg, runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unknown | UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          return UsedExports::Bool(true);
+        }
+        UsageState::Unused => {}
+      }
+    }
+    let mut ret = vec![];
+    for export_info_id in self.exports.values() {
+      let export_info = export_info_id.get_export_info(mg);
+      match export_info.get_used(runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unknown => return UsedExports::Bool(true),
+        UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          ret.push(export_info.name.clone().unwrap_or("".into()));
+        }
+        UsageState::Unused => {}
+      }
+    }
+    if let Some(redirect_to) = self.redirect_to {
+      match redirect_to
+        .get_exports_info(mg)
+        .get_used_exports(runtime, mg)
+      {
+        UsedExports::Null => return UsedExports::Null,
+        UsedExports::Bool(true) => return UsedExports::Bool(true),
+        UsedExports::Bool(false) => {}
+        UsedExports::Vec(inner) => ret.extend(inner),
+      }
+    }
+    if ret.is_empty() {
+      match self._side_effects_only_info.get_used(mg, runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unused => return UsedExports::Bool(false),
+        _ => {}
+      }
+    }
+    UsedExports::Vec(ret)
+  }
+
   /// exports that are relevant (not unused and potential provided)
   pub fn get_relevant_exports(
     &self,
@@ -1181,6 +1362,8 @@ pub struct ExportInfo {
   pub provided: Option<ExportInfoProvided>,
   pub can_mangle_provide: Option<bool>,
   pub terminal_binding: bool,
+  /// The primitive constant the export is bound to, set with `experiments.inlineConst`
+  pub inlinable: Option<InlinableValue>,
   /// This is rspack only variable, it is used to flag if the target has been initialized
   target_is_set: bool,
   pub id: ExportInfoId,
@@ -1210,6 +1393,7 @@ impl ExportsHash for ExportInfo {
     self.provided.dyn_hash(hasher);
     self.can_mangle_provide.dyn_hash(hasher);
     self.terminal_binding.dyn_hash(hasher);
+    self.inlinable.dyn_hash(hasher);
     self.target_is_set.dyn_hash(hasher);
     self.max_target_is_set.dyn_hash(hasher);
     if let Some(exports_info_id) = self.exports_info {
@@ -1219,7 +1403,33 @@ impl ExportsHash for ExportInfo {
   }
 }
 
-#[derive(Debug, Hash, Clone, Copy)]
+/// A provably immutable primitive an export is bound to, which the modules importing the export
+/// can use in place of accessing it.
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub enum InlinableValue {
+  Null,
+  Undefined,
+  Boolean(bool),
+  /// The number rendered as a JavaScript expression, with the sign
+  Number(Atom),
+  String(Atom),
+}
+
+impl InlinableValue {
+  pub fn render(&self) -> String {
+    match self {
+      InlinableValue::Null => "null".to_string(),
+      InlinableValue::Undefined => "void 0".to_string(),
+      InlinableValue::Boolean(value) => value.to_string(),
+      InlinableValue::Number(value) => value.to_string(),
+      InlinableValue::String(value) => {
+        serde_json::to_string(value.as_ref()).expect("should serialize a string")
+      }
+    }
+  }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
 pub enum ExportInfoProvided {
   True,
   False,
@@ -1281,6 +1491,7 @@ impl ExportInfo {
       .unwrap_or(false);
     let can_mangle_provide = init_from.and_then(|init_from| init_from.can_mangle_provide);
     let can_mangle_use = init_from.and_then(|init_from| init_from.can_mangle_use);
+    let inlinable = init_from.and_then(|init_from| init_from.inlinable.clone());
 
     let target = init_from
       .and_then(|item| {
@@ -1323,6 +1534,7 @@ impl ExportInfo {
       provided,
       can_mangle_provide,
       terminal_binding,
+      inlinable,
       target_is_set,
       max_target_is_set: false,
       id: ExportInfoId::new(),
@@ -1339,6 +1551,65 @@ impl ExportInfo {
     !self.terminal_binding && self.target_is_set && !self.target.is_empty()
   }
 
+  /// Human readable provide state, same as webpack's `ExportInfo.getProvidedInfo`
+  pub fn get_provided_info(&self) -> &'static str {
+    match self.provided {
+      None => "no provided info",
+      Some(ExportInfoProvided::Null) => "maybe provided (runtime-defined)",
+      Some(ExportInfoProvided::True) => "provided",
+      Some(ExportInfoProvided::False) => "not provided",
+    }
+  }
+
+  /// Human readable usage state, same as webpack's `ExportInfo.getUsedInfo`
+  pub fn get_used_info(&self) -> String {
+    if let Some(global_used) = self.global_used {
+      return match global_used {
+        UsageState::Unused => "unused",
+        UsageState::NoInfo => "no usage info",
+        UsageState::Unknown => "maybe used (runtime-defined)",
+        UsageState::Used => "used",
+        UsageState::OnlyPropertiesUsed => "only properties used",
+      }
+      .to_string();
+    }
+    if let Some(used_in_runtime) = &self.used_in_runtime {
+      let mut runtimes_by_state: Vec<(UsageState, Vec<&str>)> = vec![];
+      let mut sorted = used_in_runtime.iter().collect::<Vec<_>>();
+      sorted.sort_by(|a, b| a.0.cmp(b.0));
+      for (runtime, used) in sorted {
+        match runtimes_by_state
+          .iter_mut()
+          .find(|(state, _)| state == used)
+        {
+          Some((_, runtimes)) => runtimes.push(runtime),
+          None => runtimes_by_state.push((*used, vec![runtime])),
+        }
+      }
+      let specific_info = runtimes_by_state
+        .into_iter()
+        .filter_map(|(used, runtimes)| {
+          let runtimes = runtimes.join(", ");
+          match used {
+            UsageState::NoInfo => Some(format!("no usage info in {runtimes}")),
+            UsageState::Unknown => Some(format!("maybe used in {runtimes} (runtime-defined)")),
+            UsageState::Used => Some(format!("used in {runtimes}")),
+            UsageState::OnlyPropertiesUsed => Some(format!("only properties used in {runtimes}")),
+            UsageState::Unused => None,
+          }
+        })
+        .collect::<Vec<_>>();
+      if !specific_info.is_empty() {
+        return specific_info.join("; ");
+      }
+    }
+    if self.has_use_in_runtime_info {
+      "unused".to_string()
+    } else {
+      "no usage info".to_string()
+    }
+  }
+
   pub fn can_mangle(&self) -> Option<bool> {
     match self.can_mangle_provide {
       Some(true) => self.can_mangle_use,
@@ -1617,10 +1888,35 @@ impl ExportInfo {
     false
   }
 
+  fn update_hash(
+    &self,
+    mg: &ModuleGraph,
+    hasher: &mut dyn Hasher,
+    runtime: Option<&RuntimeSpec>,
+    visited: &mut HashSet<ExportsInfoId>,
+  ) {
+    self
+      .used_name
+      .as_ref()
+      .or(self.name.as_ref())
+      .dyn_hash(hasher);
+    self.get_used(runtime).dyn_hash(hasher);
+    self.provided.dyn_hash(hasher);
+    self.terminal_binding.dyn_hash(hasher);
+    self.inlinable.dyn_hash(hasher);
+    if let Some(exports_info) = self.exports_info {
+      exports_info.update_hash_inner(mg, hasher, runtime, visited);
+    }
+  }
+
   pub fn has_used_name(&self) -> bool {
     self.used_name.is_some()
   }
 
+  pub fn used_name(&self) -> Option<&Atom> {
+    self.used_name.as_ref()
+  }
+
   pub fn set_used_name(&mut self, name: Atom) {
     self.used_name = Some(name);
   }
@@ -1648,6 +1944,9 @@ pub enum RuntimeUsageStateType {
 pub enum UsedByExports {
   Set(HashSet<Atom>),
   Bool(bool),
+  /// Like [UsedByExports::Set], but also used when any of the calls to imported functions, which
+  /// are assumed to be `#__NO_SIDE_EFFECTS__` functions, turns out to have side effects.
+  SetOrCalls(HashSet<Atom>, Vec<DependencyId>),
 }
 
 // https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/InnerGraph.js#L319-L338
@@ -1660,18 +1959,26 @@ pub fn get_dependency_used_by_exports_condition(
       let used_by_exports = Arc::new(used_by_exports.clone());
       Some(DependencyCondition::Fn(Arc::new(
         move |_, runtime, module_graph: &ModuleGraph| {
-          let module_identifier = module_graph
-            .parent_module_by_dependency_id(&dependency_id)
-            .expect("should have parent module");
-          let exports_info = module_graph.get_exports_info(&module_identifier);
-          for export_name in used_by_exports.iter() {
-            if exports_info.get_used(UsedName::Str(export_name.clone()), runtime, module_graph)
-              != UsageState::Unused
-            {
-              return ConnectionState::Bool(true);
-            }
-          }
-          ConnectionState::Bool(false)
+          ConnectionState::Bool(is_used_by_exports(
+            dependency_id,
+            &used_by_exports,
+            runtime,
+            module_graph,
+          ))
+        },
+      )))
+    }
+    Some(UsedByExports::SetOrCalls(used_by_exports, calls)) => {
+      let used_by_exports = Arc::new(used_by_exports.clone());
+      let calls = Arc::new(calls.clone());
+      Some(DependencyCondition::Fn(Arc::new(
+        move |_, runtime, module_graph: &ModuleGraph| {
+          ConnectionState::Bool(
+            !calls
+              .iter()
+              .all(|call| is_no_side_effects_call(module_graph, call))
+              || is_used_by_exports(dependency_id, &used_by_exports, runtime, module_graph),
+          )
         },
       )))
     }
@@ -1686,6 +1993,96 @@ pub fn get_dependency_used_by_exports_condition(
   }
 }
 
+fn is_used_by_exports(
+  dependency_id: DependencyId,
+  used_by_exports: &HashSet<Atom>,
+  runtime: Option<&RuntimeSpec>,
+  module_graph: &ModuleGraph,
+) -> bool {
+  let module_identifier = module_graph
+    .parent_module_by_dependency_id(&dependency_id)
+    .expect("should have parent module");
+  let exports_info = module_graph.get_exports_info(&module_identifier);
+  used_by_exports.iter().any(|export_name| {
+    exports_info.get_used(UsedName::Str(export_name.clone()), runtime, module_graph)
+      != UsageState::Unused
+  })
+}
+
+/// Whether the function called through the import dependency is annotated with
+/// `#__NO_SIDE_EFFECTS__` in the module that declares it, following the re-exports.
+pub fn is_no_side_effects_call(module_graph: &ModuleGraph, dependency_id: &DependencyId) -> bool {
+  let Some(dependency) = module_graph.dependency_by_id(dependency_id) else {
+    return false;
+  };
+  let ids = dependency.get_ids(module_graph);
+  let [name] = ids.as_slice() else {
+    return false;
+  };
+  find_imported_export_info(
+    module_graph,
+    dependency_id,
+    name,
+    |module_identifier, name, _| {
+      module_graph
+        .module_by_identifier(module_identifier)
+        .and_then(|module| module.build_info())
+        .is_some_and(|build_info| build_info.no_side_effects_functions.contains(name))
+    },
+  )
+  .is_some()
+}
+
+/// The primitive constant the export read through the import dependency is bound to in the
+/// module that declares it, following the re-exports. The properties read from the export are
+/// left to the caller.
+pub fn get_inlinable_value<'a>(
+  module_graph: &'a ModuleGraph,
+  dependency_id: &DependencyId,
+) -> Option<&'a InlinableValue> {
+  let dependency = module_graph.dependency_by_id(dependency_id)?;
+  let ids = dependency.get_ids(module_graph);
+  let name = ids.first()?;
+  find_imported_export_info(module_graph, dependency_id, name, |_, _, export_info| {
+    export_info.inlinable.is_some()
+  })
+  .and_then(|export_info| export_info.inlinable.as_ref())
+}
+
+/// Find the export `name` imported through the dependency, or the one it re-exports, which `f`
+/// accepts.
+fn find_imported_export_info<'a>(
+  module_graph: &'a ModuleGraph,
+  dependency_id: &DependencyId,
+  name: &Atom,
+  f: impl Fn(&ModuleIdentifier, &Atom, &ExportInfo) -> bool,
+) -> Option<&'a ExportInfo> {
+  let mut module_identifier = *module_graph.module_identifier_by_dependency_id(dependency_id)?;
+  let mut name = name.clone();
+  let mut visited = HashSet::default();
+  loop {
+    let export_info = module_graph
+      .get_exports_info(&module_identifier)
+      .id
+      .get_read_only_export_info(&name, module_graph);
+    if f(&module_identifier, &name, export_info) {
+      return Some(export_info);
+    }
+    if !visited.insert(export_info.id) || !export_info.target_is_set {
+      return None;
+    }
+    let mut targets = export_info.target.values();
+    let (Some(target), None) = (targets.next(), targets.next()) else {
+      return None;
+    };
+    let (Some(connection), Some([export])) = (&target.connection, target.export.as_deref()) else {
+      return None;
+    };
+    module_identifier = connection.module_identifier;
+    name = export.clone();
+  }
+}
+
 /// refer https://github.com/webpack/webpack/blob/d15c73469fd71cf98734685225250148b68ddc79/lib/FlagDependencyUsagePlugin.js#L64
 #[derive(Clone, Debug)]
 pub enum ExtendedReferencedExport {
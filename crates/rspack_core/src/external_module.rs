@@ -1,9 +1,10 @@
 use std::borrow::Cow;
 use std::hash::Hash;
 use std::iter;
+use std::sync::Mutex;
 
 use rspack_core_macros::impl_source_map_config;
-use rspack_error::{error, impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_error::{codes, error, Diagnosable, Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_util::source_map::SourceMapKind;
@@ -15,9 +16,10 @@ use crate::{
   rspack_sources::{BoxSource, RawSource, Source, SourceExt},
   to_identifier, AsyncDependenciesBlockId, BuildContext, BuildInfo, BuildMeta,
   BuildMetaExportsType, BuildResult, ChunkInitFragments, ChunkUkey, CodeGenerationDataUrl,
-  CodeGenerationResult, Compilation, ConcatenationScope, Context, DependenciesBlock, DependencyId,
-  ExternalType, InitFragmentExt, InitFragmentKey, InitFragmentStage, LibIdentOptions, Module,
-  ModuleType, NormalInitFragment, RuntimeGlobals, RuntimeSpec, SourceType,
+  CodeGenerationResult, Compilation, CompilerOptions, ConcatenationScope, Context,
+  DependenciesBlock, DependencyId, ExternalType, InitFragmentExt, InitFragmentKey,
+  InitFragmentStage, LibIdentOptions, Module, ModuleType, NormalInitFragment, RuntimeGlobals,
+  RuntimeSpec, SourceType,
 };
 
 static EXTERNAL_MODULE_JS_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
@@ -93,6 +95,7 @@ pub struct ExternalModule {
   user_request: String,
   build_info: Option<BuildInfo>,
   build_meta: Option<BuildMeta>,
+  diagnostics: Mutex<Vec<Diagnostic>>,
 }
 
 impl ExternalModule {
@@ -106,6 +109,7 @@ impl ExternalModule {
       user_request,
       build_info: None,
       build_meta: None,
+      diagnostics: Default::default(),
       source_map_kind: SourceMapKind::None,
     }
   }
@@ -142,6 +146,15 @@ impl ExternalModule {
     )
   }
 
+  /// Whether the external is loaded by `import()` in the generated code.
+  fn is_dynamic_import(&self, options: &CompilerOptions) -> bool {
+    match self.external_type.as_str() {
+      "import" => true,
+      "module" => !options.output.module,
+      _ => false,
+    }
+  }
+
   fn get_source_for_script_external(
     &self,
     url_and_global: &ExternalRequestValue,
@@ -241,6 +254,11 @@ module.exports = new Promise(function(resolve, reject) {{
       }
       "module" if let Some(request) = request => {
         if compilation.options.output.module {
+          if !compilation.options.output.environment.module {
+            return Err(error!(
+              "The target environment doesn't support EcmaScriptModule syntax so it's not possible to use external type 'module'"
+            ));
+          }
           let id = compilation
             .module_graph
             .module_graph_module_by_identifier(&self.identifier())
@@ -318,7 +336,7 @@ impl Module for ExternalModule {
   }
 
   fn get_diagnostics(&self) -> Vec<Diagnostic> {
-    vec![]
+    self.clone_diagnostics()
   }
 
   fn source_types(&self) -> &[SourceType] {
@@ -390,6 +408,27 @@ impl Module for ExternalModule {
       }
       _ => build_result.build_meta.exports_type = BuildMetaExportsType::Dynamic,
     }
+
+    let mut diagnostics = vec![];
+    let options = build_context.compiler_options;
+    // The environment defaults to no `import()` for web targets, so the user may not have
+    // chosen it, webpack fails to build here
+    if self.is_dynamic_import(options)
+      && options.output.import_function_name == "import"
+      && !options.output.environment.dynamic_import
+    {
+      diagnostics.push(Diagnostic::warn(
+        codes::UNSUPPORTED_DYNAMIC_IMPORT.into(),
+        format!(
+          "The target environment doesn't support 'import()', but it's used to load external type '{}'",
+          self.external_type
+        ),
+      ));
+    }
+    *self
+      .diagnostics
+      .get_mut()
+      .expect("should be able to lock diagnostics") = diagnostics;
     Ok(build_result)
   }
 
@@ -447,7 +486,33 @@ impl Module for ExternalModule {
   }
 }
 
-impl_empty_diagnosable_trait!(ExternalModule);
+impl Diagnosable for ExternalModule {
+  fn add_diagnostic(&self, diagnostic: Diagnostic) {
+    self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .push(diagnostic);
+  }
+
+  fn add_diagnostics(&self, mut diagnostics: Vec<Diagnostic>) {
+    self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .append(&mut diagnostics);
+  }
+
+  fn clone_diagnostics(&self) -> Vec<Diagnostic> {
+    self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .iter()
+      .cloned()
+      .collect()
+  }
+}
 
 impl Hash for ExternalModule {
   fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
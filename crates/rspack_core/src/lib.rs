@@ -12,6 +12,7 @@ pub mod diagnostics;
 mod update_hash;
 pub use dependencies_block::{
   AsyncDependenciesBlock, AsyncDependenciesBlockId, DependenciesBlock, DependencyLocation,
+  RealDependencyLocation, SourcePosition,
 };
 mod fake_namespace_object;
 mod template;
@@ -0,0 +1,107 @@
+use std::{fmt, sync::Arc};
+
+use rspack_error::{error, DiagnosticKind, Result, TraceableError};
+use rustc_hash::FxHashMap as HashMap;
+use serde::de::DeserializeOwned;
+
+use crate::{BoxLoader, BUILTIN_LOADER_PREFIX};
+
+/// Create a builtin loader from the loader request, which may contain a query, and its options
+/// serialized as JSON.
+pub type BuiltinLoaderFactory = Arc<dyn Fn(&str, Option<&str>) -> Result<BoxLoader> + Send + Sync>;
+
+/// Create a [BuiltinLoaderFactory] for a loader whose options are deserialized with serde.
+///
+/// Missing options are deserialized from `{}`.
+pub fn builtin_loader_factory<O, F>(factory: F) -> BuiltinLoaderFactory
+where
+  O: DeserializeOwned,
+  F: Fn(&str, O) -> BoxLoader + Send + Sync + 'static,
+{
+  Arc::new(move |loader_request, options| {
+    let options_source = options.unwrap_or("{}");
+    let options = serde_json::from_str(options_source).map_err(|e| {
+      let offset = options_source
+        .split_inclusive('\n')
+        .take(e.line().saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + e.column().saturating_sub(1);
+      let loader_name = loader_request.split('?').next().unwrap_or(loader_request);
+      TraceableError::from_file(
+        options_source.to_string(),
+        offset,
+        offset + 1,
+        "Invalid loader options".to_string(),
+        format!("Invalid options for builtin loader '{loader_name}': {e}"),
+      )
+      .with_kind(DiagnosticKind::Json)
+    })?;
+    Ok(factory(loader_request, options))
+  })
+}
+
+/// Builtin loaders, keyed by their `builtin:` identifiers.
+///
+/// Plugins register their loaders with
+/// [ApplyContext::register_builtin_loader](crate::ApplyContext::register_builtin_loader),
+/// which makes them usable as `builtin:` loaders in [ModuleRule::use](crate::ModuleRule).
+#[derive(Default, Clone)]
+pub struct BuiltinLoaderRegistry {
+  factories: HashMap<String, BuiltinLoaderFactory>,
+}
+
+impl fmt::Debug for BuiltinLoaderRegistry {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_set().entries(self.factories.keys()).finish()
+  }
+}
+
+impl BuiltinLoaderRegistry {
+  /// Panics:
+  /// Panics if `name` is not starting with `builtin:`.
+  pub fn register(&mut self, name: impl Into<String>, factory: BuiltinLoaderFactory) {
+    let name = name.into();
+    assert!(
+      name.starts_with(BUILTIN_LOADER_PREFIX),
+      "builtin loader '{name}' should start with '{BUILTIN_LOADER_PREFIX}'"
+    );
+    self.factories.insert(name, factory);
+  }
+
+  pub fn extend(&mut self, other: BuiltinLoaderRegistry) {
+    self.factories.extend(other.factories);
+  }
+
+  pub fn contains(&self, name: &str) -> bool {
+    self.factories.contains_key(name)
+  }
+
+  /// Create the loader for `loader_request`, e.g. `builtin:swc-loader??ruleSet[1].rules[0]`.
+  pub fn get(&self, loader_request: &str, options: Option<&str>) -> Result<BoxLoader> {
+    let name = loader_request.split('?').next().unwrap_or(loader_request);
+    let Some(factory) = self.factories.get(name) else {
+      let mut registered = self
+        .factories
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+      registered.sort_unstable();
+      return Err(error!(
+        "Builtin loader '{name}' is not registered, available builtin loaders: {}",
+        registered.join(", ")
+      ));
+    };
+    factory(loader_request, options)
+  }
+}
+
+impl FromIterator<(&'static str, BuiltinLoaderFactory)> for BuiltinLoaderRegistry {
+  fn from_iter<I: IntoIterator<Item = (&'static str, BuiltinLoaderFactory)>>(iter: I) -> Self {
+    let mut registry = Self::default();
+    for (name, factory) in iter {
+      registry.register(name, factory);
+    }
+    registry
+  }
+}
@@ -1,15 +1,23 @@
-use std::sync::Arc;
+use std::{hash::BuildHasherDefault, path::Path, sync::Arc};
 
+use dashmap::DashMap;
+use rspack_error::Result;
 pub use rspack_loader_runner::{run_loaders, Content, Loader, LoaderContext};
+use rspack_sources::BoxSource;
 use rspack_util::source_map::SourceMapKind;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashSet, FxHasher};
 
 use crate::{
-  cache::Cache, AddQueueHandler, BuildQueueHandler, BuildTimeExecutionQueueHandler, Compilation,
-  CompilerOptions, Context, FactorizeQueueHandler, ModuleIdentifier,
-  ProcessDependenciesQueueHandler, ResolverFactory, SharedPluginDriver,
+  cache::Cache, resolve, AddQueueHandler, AssetInfo, BuildQueueHandler,
+  BuildTimeExecutionQueueHandler, Compilation, CompilationAsset, CompilationLogger,
+  CompilationLogging, CompilerOptions, Context, DependencyCategory, DependencyType,
+  FactorizeQueueHandler, ModuleIdentifier, ProcessDependenciesQueueHandler, ResolveArgs,
+  ResolveResult, ResolverFactory, SharedPluginDriver,
 };
 
+/// Assets emitted by the loaders of a module, keyed by filename.
+pub type LoaderEmittedAssets = Arc<DashMap<String, CompilationAsset, BuildHasherDefault<FxHasher>>>;
+
 #[derive(Debug, Clone)]
 pub struct CompilerContext {
   pub options: Arc<CompilerOptions>,
@@ -25,6 +33,8 @@ pub struct CompilerContext {
   pub build_time_execution_queue: Option<BuildTimeExecutionQueueHandler>,
   pub plugin_driver: SharedPluginDriver,
   pub cache: Arc<Cache>,
+  pub logging: CompilationLogging,
+  pub emitted_assets: LoaderEmittedAssets,
 }
 
 #[derive(Debug, Default)]
@@ -76,4 +86,84 @@ impl CompilerContext {
 
 pub type LoaderRunnerContext = CompilerContext;
 
+/// Compilation APIs for native loaders, with the same semantics as the ones of JS loaders.
+#[async_trait::async_trait]
+pub trait LoaderContextExt {
+  /// Resolve `request` from `context`, like `this.getResolve({ dependencyType })` of webpack.
+  /// Files touched by the resolution are added to the dependencies of the module.
+  async fn resolve(
+    &mut self,
+    context: &Path,
+    request: &str,
+    dependency_category: DependencyCategory,
+  ) -> Result<ResolveResult>;
+
+  /// Emit an asset to the compilation, like `this.emitFile` of webpack.
+  fn emit_file(&mut self, name: String, source: BoxSource, info: Option<AssetInfo>);
+
+  /// Get a logger named after the current loader, `name` and the module, like `this.getLogger`.
+  fn get_logger(&self, name: Option<&str>) -> CompilationLogger;
+}
+
+#[async_trait::async_trait]
+impl LoaderContextExt for LoaderContext<'_, LoaderRunnerContext> {
+  async fn resolve(
+    &mut self,
+    context: &Path,
+    request: &str,
+    dependency_category: DependencyCategory,
+  ) -> Result<ResolveResult> {
+    let compiler_context = &self.context;
+    let mut file_dependencies = Default::default();
+    let mut missing_dependencies = Default::default();
+    let result = resolve(
+      ResolveArgs {
+        importer: Some(&compiler_context.module),
+        issuer: Some(self.resource),
+        context: context.to_path_buf().into(),
+        specifier: request,
+        dependency_type: &DependencyType::Unknown,
+        dependency_category: &dependency_category,
+        span: None,
+        resolve_options: None,
+        resolve_to_context: false,
+        optional: false,
+        file_dependencies: &mut file_dependencies,
+        missing_dependencies: &mut missing_dependencies,
+      },
+      &compiler_context.plugin_driver,
+    )
+    .await;
+    self.file_dependencies.extend(file_dependencies);
+    self.missing_dependencies.extend(missing_dependencies);
+    if let Ok(ResolveResult::Resource(resource)) = &result {
+      self.file_dependencies.insert(resource.path.clone());
+    }
+    result
+  }
+
+  fn emit_file(&mut self, name: String, source: BoxSource, info: Option<AssetInfo>) {
+    self.asset_filenames.insert(name.clone());
+    self.context.emitted_assets.insert(
+      name,
+      CompilationAsset::new(Some(source), info.unwrap_or_default()),
+    );
+  }
+
+  fn get_logger(&self, name: Option<&str>) -> CompilationLogger {
+    let current_loader = self.current_loader().to_string();
+    let current_loader = current_loader.split('?').next().unwrap_or_default();
+    let logger_name = [
+      Some(current_loader),
+      name,
+      Some(self.context.module.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("|");
+    CompilationLogger::new(logger_name, self.context.logging.clone())
+  }
+}
+
 pub type BoxLoader = Arc<dyn Loader<LoaderRunnerContext>>;
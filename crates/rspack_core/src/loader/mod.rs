@@ -1,3 +1,5 @@
+mod builtin_loader_registry;
+pub use builtin_loader_registry::*;
 mod loader_runner;
 pub use loader_runner::*;
 mod rspack_loader;
@@ -1,6 +1,6 @@
 use std::sync::Mutex;
 
-use rspack_error::Result;
+use rspack_error::{error, Result};
 use rspack_loader_runner::{Content, LoaderContext, LoaderRunnerPlugin, ResourceData};
 
 use crate::{CompilerContext, NormalModule, SharedPluginDriver};
@@ -37,6 +37,16 @@ impl LoaderRunnerPlugin for RspackLoaderRunnerPlugin<'_> {
       return Ok(result);
     }
 
+    // The packages of Yarn PnP stored in zip archives
+    let file_system = self.plugin_driver.resolver_factory.file_system();
+    let resource_path = &resource_data.resource_path;
+    if file_system.archive_of(resource_path).is_some() {
+      let content = file_system
+        .read(resource_path)
+        .map_err(|e| error!("{e}, failed to read {}", resource_path.display()))?;
+      return Ok(Some(Content::from(content)));
+    }
+
     Ok(None)
   }
 }
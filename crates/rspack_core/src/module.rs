@@ -12,16 +12,16 @@ use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
 use rspack_util::source_map::ModuleSourceMapConfig;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey, CodeGenerationResult, Compilation,
-  CompilerContext, CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule,
-  DependenciesBlock, DependencyId, DependencyTemplate, ExternalModule, ModuleDependency,
-  ModuleGraph, ModuleGraphModule, ModuleType, NormalModule, RawModule, Resolve, RuntimeSpec,
-  SelfModule, SharedPluginDriver, SourceType,
+  CompilationAsset, CompilerContext, CompilerOptions, ConcatenationScope, ConnectionState, Context,
+  ContextModule, DependenciesBlock, DependencyId, DependencyTemplate, ExternalModule,
+  ModuleDependency, ModuleGraph, ModuleGraphModule, ModuleType, NormalModule, RawModule, Resolve,
+  RuntimeSpec, SelfModule, SharedPluginDriver, SourceType,
 };
 pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
@@ -47,10 +47,20 @@ pub struct BuildInfo {
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
   pub asset_filenames: HashSet<String>,
+  /// Assets emitted by native loaders, which are emitted to the compilation with the module.
+  pub assets: HashMap<String, CompilationAsset>,
   pub harmony_named_exports: HashSet<Atom>,
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   pub json_data: Option<JsonValue>,
+  /// Exports of a WebAssembly module that can't be used from JavaScript, with
+  /// the reason, e.g. `i64 as parameter`.
+  pub js_incompatible_exports: Option<HashMap<Atom, String>>,
+  /// Exports that are functions annotated with `#__NO_SIDE_EFFECTS__`.
+  pub no_side_effects_functions: HashSet<Atom>,
+  /// Calls to imported functions the module relies on to be side effect free, it's only side
+  /// effect free when all of them turn out to be `#__NO_SIDE_EFFECTS__` functions.
+  pub no_side_effects_calls: Vec<DependencyId>,
 }
 
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
@@ -337,6 +337,20 @@ impl ModuleGraph {
     mgm.depth
   }
 
+  pub fn get_optimization_bailout(&self, module_id: &ModuleIdentifier) -> &[String] {
+    let mgm = self
+      .module_graph_module_by_identifier(module_id)
+      .expect("should have module graph module");
+    &mgm.optimization_bailout
+  }
+
+  pub fn get_optimization_bailout_mut(&mut self, module_id: &ModuleIdentifier) -> &mut Vec<String> {
+    let mgm = self
+      .module_graph_module_by_identifier_mut(module_id)
+      .expect("should have module graph module");
+    &mut mgm.optimization_bailout
+  }
+
   pub fn set_depth(&mut self, module_id: ModuleIdentifier, depth: usize) {
     let mgm = self
       .module_graph_module_by_identifier_mut(&module_id)
@@ -29,6 +29,7 @@ pub struct ModuleGraphModule {
   pub profile: Option<Box<ModuleProfile>>,
   pub is_async: bool,
   pub depth: Option<usize>,
+  pub optimization_bailout: Vec<String>,
 }
 
 impl ModuleGraphModule {
@@ -53,6 +54,7 @@ impl ModuleGraphModule {
       profile: None,
       is_async: false,
       depth: None,
+      optimization_bailout: vec![],
     }
   }
 
@@ -27,12 +27,12 @@ use serde_json::json;
 
 use crate::{
   add_connection_states, contextify, diagnostics::ModuleBuildError, get_context,
-  impl_build_info_meta, AsyncDependenciesBlockId, BoxLoader, BoxModule, BuildContext, BuildInfo,
-  BuildMeta, BuildResult, CodeGenerationResult, Compilation, ConcatenationScope, ConnectionState,
-  Context, DependenciesBlock, DependencyId, DependencyTemplate, GenerateContext, GeneratorOptions,
-  LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType,
-  ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin,
-  RuntimeSpec, SourceType,
+  impl_build_info_meta, is_no_side_effects_call, AsyncDependenciesBlockId, BoxLoader, BoxModule,
+  BuildContext, BuildInfo, BuildMeta, BuildResult, CodeGenerationResult, Compilation,
+  ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
+  DependencyTemplate, GenerateContext, GeneratorOptions, LibIdentOptions, Module, ModuleDependency,
+  ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult, ParserAndGenerator,
+  ParserOptions, Resolve, RspackLoaderRunnerPlugin, RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -361,6 +361,7 @@ impl Module for NormalModule {
       current_loader: Default::default(),
     };
 
+    let emitted_assets = build_context.compiler_context.emitted_assets.clone();
     let loader_result = run_loaders(
       &self.loaders,
       &self.resource_data,
@@ -453,6 +454,10 @@ impl Module for NormalModule {
     build_info.missing_dependencies = loader_result.missing_dependencies;
     build_info.build_dependencies = loader_result.build_dependencies;
     build_info.asset_filenames = loader_result.asset_filenames;
+    build_info.assets = emitted_assets
+      .iter()
+      .map(|asset| (asset.key().clone(), asset.value().clone()))
+      .collect();
 
     Ok(BuildResult {
       build_info,
@@ -574,9 +579,21 @@ impl Module for NormalModule {
       if let Some(side_effect_free) = mgm.factory_meta.as_ref().and_then(|m| m.side_effect_free) {
         return ConnectionState::Bool(!side_effect_free);
       }
-      if let Some(side_effect_free) = self.build_meta().as_ref().and_then(|m| m.side_effect_free)
-        && side_effect_free
-      {
+      let side_effect_free = self
+        .build_meta()
+        .and_then(|m| m.side_effect_free)
+        .unwrap_or_default();
+      // only side effect free when the imported functions it calls are `#__NO_SIDE_EFFECTS__`
+      let side_effect_free_with_calls = || {
+        self.build_info().is_some_and(|build_info| {
+          !build_info.no_side_effects_calls.is_empty()
+            && build_info
+              .no_side_effects_calls
+              .iter()
+              .all(|call| is_no_side_effects_call(module_graph, call))
+        })
+      };
+      if side_effect_free || side_effect_free_with_calls() {
         // use module chain instead of is_evaluating_side_effects to mut module graph
         if module_chain.contains(&self.identifier()) {
           return ConnectionState::CircularConnection;
@@ -1,7 +1,12 @@
+use std::path::PathBuf;
+
+use rspack_error::Diagnostic;
+
+use super::diagnostics::apply_diagnostic_rules;
 use crate::{
-  Builtins, CacheOptions, Context, DevServerOptions, Experiments, IncrementalRebuildMakeState,
-  Mode, ModuleOptions, NodeOption, Optimization, OutputOptions, Resolve, SnapshotOptions,
-  StatsOptions, Target,
+  Builtins, CacheOptions, Context, DevServerOptions, DiagnosticMatcher, DiagnosticSeverityRule,
+  Experiments, IncrementalRebuildMakeState, Mode, ModuleOptions, NodeOption, Optimization,
+  OutputOptions, PerformanceOptions, Resolve, SnapshotOptions, StatsOptions, Target,
 };
 
 #[derive(Debug)]
@@ -21,8 +26,15 @@ pub struct CompilerOptions {
   pub experiments: Experiments,
   pub node: Option<NodeOption>,
   pub optimization: Optimization,
+  pub performance: PerformanceOptions,
   pub profile: bool,
   pub bail: bool,
+  pub records_input_path: Option<PathBuf>,
+  pub records_output_path: Option<PathBuf>,
+  /// Warnings matching any of these are not reported.
+  pub ignore_warnings: Vec<DiagnosticMatcher>,
+  /// Change the severity of diagnostics, the first matching rule wins.
+  pub diagnostic_severity: Vec<DiagnosticSeverityRule>,
   pub builtins: Builtins,
 }
 
@@ -42,4 +54,10 @@ impl CompilerOptions {
   pub fn is_new_tree_shaking(&self) -> bool {
     self.experiments.rspack_future.new_treeshaking
   }
+
+  /// Apply [CompilerOptions::diagnostic_severity] and [CompilerOptions::ignore_warnings],
+  /// `None` if the diagnostic should not be reported.
+  pub fn apply_diagnostic_rules(&self, diagnostic: Diagnostic) -> Option<Diagnostic> {
+    apply_diagnostic_rules(diagnostic, &self.diagnostic_severity, &self.ignore_warnings)
+  }
 }
@@ -0,0 +1,97 @@
+use rspack_error::{error, miette, Diagnostic, Severity};
+use rspack_regex::RspackRegex;
+
+/// Selects diagnostics by their code, module or message. Every condition which is set
+/// has to match, so an empty matcher matches every diagnostic.
+#[derive(Debug, Default)]
+pub struct DiagnosticMatcher {
+  /// The [code](Diagnostic::code) of the diagnostic, e.g. `ModuleNotFoundError`.
+  pub code: Option<String>,
+  /// Tested against the identifier of the module the diagnostic is reported for,
+  /// which is the issuer for a request that can't be resolved.
+  pub module: Option<RspackRegex>,
+  /// Tested against the message of the diagnostic and of the diagnostics it is caused by.
+  pub message: Option<RspackRegex>,
+}
+
+impl DiagnosticMatcher {
+  pub fn is_match(&self, diagnostic: &Diagnostic) -> bool {
+    if let Some(code) = &self.code
+      && diagnostic.code().as_ref() != Some(code)
+    {
+      return false;
+    }
+    if let Some(module) = &self.module
+      && !diagnostic
+        .module_identifier()
+        .is_some_and(|identifier| module.test(&identifier))
+    {
+      return false;
+    }
+    if let Some(message) = &self.message {
+      let mut messages = std::iter::successors(
+        Some(diagnostic.as_ref() as &dyn miette::Diagnostic),
+        |diagnostic| diagnostic.diagnostic_source(),
+      )
+      .map(|diagnostic| diagnostic.to_string());
+      if !messages.any(|m| message.test(&m)) {
+        return false;
+      }
+    }
+    true
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverityOverride {
+  Error,
+  Warning,
+  /// Drop the diagnostic.
+  Off,
+}
+
+impl TryFrom<&str> for DiagnosticSeverityOverride {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
+    match value {
+      "error" => Ok(Self::Error),
+      "warning" => Ok(Self::Warning),
+      "off" => Ok(Self::Off),
+      _ => Err(error!("Unsupported diagnostic severity: {value}")),
+    }
+  }
+}
+
+/// Report the diagnostics selected by `matcher` with `severity`.
+#[derive(Debug)]
+pub struct DiagnosticSeverityRule {
+  pub matcher: DiagnosticMatcher,
+  pub severity: DiagnosticSeverityOverride,
+}
+
+/// Apply the first matching severity rule, then drop the warnings matching `ignore_warnings`.
+pub(crate) fn apply_diagnostic_rules(
+  diagnostic: Diagnostic,
+  severity_rules: &[DiagnosticSeverityRule],
+  ignore_warnings: &[DiagnosticMatcher],
+) -> Option<Diagnostic> {
+  let diagnostic = match severity_rules
+    .iter()
+    .find(|rule| rule.matcher.is_match(&diagnostic))
+    .map(|rule| rule.severity)
+  {
+    Some(DiagnosticSeverityOverride::Off) => return None,
+    Some(DiagnosticSeverityOverride::Error) => diagnostic.with_severity(Severity::Error),
+    Some(DiagnosticSeverityOverride::Warning) => diagnostic.with_severity(Severity::Warn),
+    None => diagnostic,
+  };
+  if matches!(diagnostic.severity(), Severity::Warn)
+    && ignore_warnings
+      .iter()
+      .any(|matcher| matcher.is_match(&diagnostic))
+  {
+    return None;
+  }
+  Some(diagnostic)
+}
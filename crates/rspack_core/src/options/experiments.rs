@@ -31,5 +31,9 @@ pub struct Experiments {
   pub incremental_rebuild: IncrementalRebuild,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  /// Keep the memory caches of the modules unaffected by the changes across rebuilds
+  pub cache_unaffected: bool,
+  /// Inline the exports bound to primitive constants into the modules importing them
+  pub inline_const: bool,
   pub rspack_future: RspackFuture,
 }
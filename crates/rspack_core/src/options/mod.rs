@@ -35,3 +35,7 @@ mod experiments;
 pub use experiments::*;
 mod node;
 pub use node::*;
+mod performance;
+pub use performance::*;
+mod diagnostics;
+pub use diagnostics::*;
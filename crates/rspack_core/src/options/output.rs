@@ -30,6 +30,9 @@ pub struct OutputOptions {
   pub filename: Filename,
   pub chunk_filename: Filename,
   pub cross_origin_loading: CrossOriginLoading,
+  pub charset: bool,
+  pub chunk_load_timeout: u32,
+  pub chunk_load_retry: ChunkLoadRetry,
   pub css_filename: Filename,
   pub css_chunk_filename: Filename,
   pub hot_update_main_filename: Filename,
@@ -53,6 +56,7 @@ pub struct OutputOptions {
   pub worker_wasm_loading: WasmLoading,
   pub worker_public_path: String,
   pub script_type: String,
+  pub environment: Environment,
 }
 
 impl From<&OutputOptions> for RspackHash {
@@ -66,6 +70,58 @@ pub struct TrustedTypes {
   pub policy_name: Option<String>,
 }
 
+/// The syntax the environment running the generated code supports, it decides
+/// the syntax of the runtime code. Everything off renders ES5 code.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Environment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+  pub destructuring: bool,
+  pub for_of: bool,
+  pub dynamic_import: bool,
+  pub module: bool,
+  pub optional_chaining: bool,
+  pub template_literal: bool,
+}
+
+impl Environment {
+  /// `const` when it's supported, `var` otherwise.
+  pub fn render_const(&self) -> &'static str {
+    if self.r#const {
+      "const"
+    } else {
+      "var"
+    }
+  }
+
+  /// A function which doesn't use `this` or `arguments`, e.g. `(a, b) => {...}`,
+  /// the body is rendered as is.
+  pub fn basic_function(&self, args: &str, body: &str) -> String {
+    if self.arrow_function {
+      format!("({args}) => {{{body}}}")
+    } else {
+      format!("function({args}) {{{body}}}")
+    }
+  }
+
+  /// A function returning the expression, e.g. `(a) => (a + 1)`.
+  pub fn returning_function(&self, return_value: &str, args: &str) -> String {
+    if self.arrow_function {
+      format!("({args}) => ({return_value})")
+    } else {
+      format!("function({args}) {{ return {return_value} }}")
+    }
+  }
+
+  pub fn empty_function(&self) -> &'static str {
+    if self.arrow_function {
+      "() => {}"
+    } else {
+      "function() {}"
+    }
+  }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ChunkLoading {
   Enable(ChunkLoadingType),
@@ -152,6 +208,23 @@ impl std::fmt::Display for CrossOriginLoading {
   }
 }
 
+/// Policy applied when a script, css or wasm chunk fails to load.
+#[derive(Debug, Default)]
+pub struct ChunkLoadRetry {
+  /// Retry a failed request up to `max_retries` times, `0` disables retrying.
+  pub max_retries: u32,
+  /// Delay in milliseconds before the first retry, doubled for every following retry.
+  pub retry_delay: u32,
+  /// Public paths tried in order after the configured public path fails.
+  pub fallback_public_paths: Vec<String>,
+}
+
+impl ChunkLoadRetry {
+  pub fn is_enabled(&self) -> bool {
+    self.max_retries > 0
+  }
+}
+
 pub const FILE_PLACEHOLDER: &str = "[file]";
 pub const BASE_PLACEHOLDER: &str = "[base]";
 pub const NAME_PLACEHOLDER: &str = "[name]";
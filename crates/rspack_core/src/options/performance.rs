@@ -0,0 +1,48 @@
+use crate::RuleSetCondition;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PerformanceHints {
+  #[default]
+  False,
+  Warning,
+  Error,
+}
+
+impl From<&str> for PerformanceHints {
+  fn from(value: &str) -> Self {
+    match value {
+      "warning" => Self::Warning,
+      "error" => Self::Error,
+      _ => Self::False,
+    }
+  }
+}
+
+impl PerformanceHints {
+  pub fn is_enable(&self) -> bool {
+    !matches!(self, Self::False)
+  }
+}
+
+#[derive(Debug)]
+pub struct PerformanceOptions {
+  /// Whether to report oversized assets and entrypoints as warnings or errors.
+  pub hints: PerformanceHints,
+  /// Maximum size of a single emitted asset in bytes.
+  pub max_asset_size: f64,
+  /// Maximum combined size of the assets of an entrypoint in bytes.
+  pub max_entrypoint_size: f64,
+  /// Selects the assets that are taken into account, defaults to every asset except `*.map`.
+  pub asset_filter: Option<RuleSetCondition>,
+}
+
+impl Default for PerformanceOptions {
+  fn default() -> Self {
+    Self {
+      hints: PerformanceHints::False,
+      max_asset_size: 250000.0,
+      max_entrypoint_size: 250000.0,
+      asset_filter: None,
+    }
+  }
+}
@@ -1,4 +1,33 @@
+use rspack_error::error;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+  /// Human readable reports with code frames.
+  #[default]
+  Graphical,
+  /// A JSON array of [JsonDiagnostic](rspack_error::emitter::JsonDiagnostic).
+  Json,
+  /// A SARIF 2.1.0 log.
+  Sarif,
+}
+
+impl TryFrom<&str> for DiagnosticsFormat {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
+    match value {
+      "graphical" => Ok(Self::Graphical),
+      "json" => Ok(Self::Json),
+      "sarif" => Ok(Self::Sarif),
+      _ => Err(error!("Unsupported diagnostics format: {value}")),
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct StatsOptions {
   pub colors: bool,
+  /// Format of [Stats::emit_diagnostics](crate::Stats::emit_diagnostics) and
+  /// [Stats::emit_diagnostics_string](crate::Stats::emit_diagnostics_string).
+  pub diagnostics_format: DiagnosticsFormat,
 }
@@ -9,7 +9,8 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AdditionalModuleRequirementsArgs, AssetEmittedArgs,
-  AssetInfo, BoxLoader, BoxModule, BuildTimeExecutionOption, Chunk, ChunkAssetArgs, ChunkHashArgs,
+  AssetInfo, BoxLoader, BoxModule, BoxResolverPlugin, BuildTimeExecutionOption,
+  BuiltinLoaderFactory, BuiltinLoaderRegistry, Chunk, ChunkAssetArgs, ChunkHashArgs,
   CodeGenerationResults, Compilation, CompilationArgs, CompilationParams, CompilerOptions,
   ContentHashArgs, DependencyId, DoneArgs, FactorizeArgs, JsChunkHashArgs, LoaderRunnerContext,
   MakeParam, Module, ModuleFactoryResult, ModuleIdentifier, ModuleType, NormalModule,
@@ -625,6 +626,9 @@ pub type BoxedParserAndGeneratorBuilder =
 pub struct ApplyContext {
   pub(crate) registered_parser_and_generator_builder:
     DashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  pub(crate) resolver_plugins: Vec<BoxResolverPlugin>,
+  pub(crate) loader_resolver_plugins: Vec<BoxResolverPlugin>,
+  pub(crate) builtin_loader_registry: BuiltinLoaderRegistry,
 }
 
 impl ApplyContext {
@@ -637,4 +641,23 @@ impl ApplyContext {
       .registered_parser_and_generator_builder
       .insert(module_type, parser_and_generator_builder);
   }
+
+  /// Hook into the resolution of modules.
+  pub fn register_resolver_plugin(&mut self, plugin: BoxResolverPlugin) {
+    self.resolver_plugins.push(plugin);
+  }
+
+  /// Hook into the resolution of loaders.
+  pub fn register_loader_resolver_plugin(&mut self, plugin: BoxResolverPlugin) {
+    self.loader_resolver_plugins.push(plugin);
+  }
+
+  /// Make a loader usable as `name`, which should start with `builtin:`.
+  pub fn register_builtin_loader(
+    &mut self,
+    name: impl Into<String>,
+    factory: BuiltinLoaderFactory,
+  ) {
+    self.builtin_loader_registry.register(name, factory);
+  }
 }
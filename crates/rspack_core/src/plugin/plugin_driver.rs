@@ -12,10 +12,10 @@ use tracing::instrument;
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AdditionalModuleRequirementsArgs, ApplyContext,
   AssetEmittedArgs, BoxLoader, BoxModule, BoxedParserAndGeneratorBuilder, BuildTimeExecutionOption,
-  Chunk, ChunkAssetArgs, ChunkContentHash, ChunkHashArgs, CodeGenerationResults, Compilation,
-  CompilationArgs, CompilationParams, CompilerOptions, Content, ContentHashArgs, DependencyId,
-  DoneArgs, FactorizeArgs, JsChunkHashArgs, LoaderRunnerContext, MakeParam, Module,
-  ModuleIdentifier, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
+  BuiltinLoaderRegistry, Chunk, ChunkAssetArgs, ChunkContentHash, ChunkHashArgs,
+  CodeGenerationResults, Compilation, CompilationArgs, CompilationParams, CompilerOptions, Content,
+  ContentHashArgs, DependencyId, DoneArgs, FactorizeArgs, JsChunkHashArgs, LoaderRunnerContext,
+  MakeParam, Module, ModuleIdentifier, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
   NormalModuleBeforeResolveArgs, NormalModuleCreateData, OptimizeChunksArgs, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginAdditionalModuleRequirementsOutput,
   PluginBuildEndHookOutput, PluginChunkHashHookOutput, PluginCompilationHookOutput, PluginContext,
@@ -27,13 +27,15 @@ use crate::{
   PluginRuntimeRequirementsInTreeOutput, PluginThisCompilationHookOutput, ProcessAssetsArgs,
   RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs,
   Resolver, ResolverFactory, RuntimeModule, RuntimeRequirementsInTreeArgs, Stats,
-  ThisCompilationArgs,
+  ThisCompilationArgs, BUILTIN_LOADER_PREFIX,
 };
 
 pub struct PluginDriver {
   pub(crate) options: Arc<CompilerOptions>,
   pub plugins: Vec<Box<dyn Plugin>>,
   pub resolver_factory: Arc<ResolverFactory>,
+  pub loader_resolver_factory: Arc<ResolverFactory>,
+  pub builtin_loader_registry: BuiltinLoaderRegistry,
   // pub registered_parser: HashMap<ModuleType, BoxedParser>,
   pub registered_parser_and_generator_builder: HashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
@@ -56,41 +58,44 @@ impl PluginDriver {
   pub fn new(
     mut options: CompilerOptions,
     plugins: Vec<Box<dyn Plugin>>,
-    resolver_factory: Arc<ResolverFactory>,
-  ) -> (Arc<Self>, Arc<CompilerOptions>) {
-    let registered_parser_and_generator_builder = plugins
-      .iter()
-      .map(|plugin| {
-        let mut apply_context = ApplyContext::default();
-        plugin
-          .apply(
-            PluginContext::with_context(&mut apply_context),
-            &mut options,
-          )
-          .expect("TODO:");
-        apply_context
-      })
-      .flat_map(|apply_context| {
-        apply_context
-          .registered_parser_and_generator_builder
-          .into_iter()
-          .collect::<Vec<_>>()
-      })
-      .collect::<HashMap<ModuleType, BoxedParserAndGeneratorBuilder>>();
-
+  ) -> Result<(Arc<Self>, Arc<CompilerOptions>)> {
+    let mut registered_parser_and_generator_builder = HashMap::default();
+    let mut resolver_plugins = vec![];
+    let mut loader_resolver_plugins = vec![];
+    let mut builtin_loader_registry = BuiltinLoaderRegistry::default();
+    for plugin in &plugins {
+      let mut apply_context = ApplyContext::default();
+      plugin.apply(
+        PluginContext::with_context(&mut apply_context),
+        &mut options,
+      )?;
+      registered_parser_and_generator_builder
+        .extend(apply_context.registered_parser_and_generator_builder);
+      resolver_plugins.extend(apply_context.resolver_plugins);
+      loader_resolver_plugins.extend(apply_context.loader_resolver_plugins);
+      builtin_loader_registry.extend(apply_context.builtin_loader_registry);
+    }
+
+    let resolver_factory =
+      Arc::new(ResolverFactory::new(options.resolve.clone()).with_plugins(resolver_plugins));
+    let loader_resolver_factory = Arc::new(
+      ResolverFactory::new(options.resolve_loader.clone()).with_plugins(loader_resolver_plugins),
+    );
     let options = Arc::new(options);
 
-    (
+    Ok((
       Arc::new(Self {
         options: options.clone(),
         plugins,
         resolver_factory,
+        loader_resolver_factory,
+        builtin_loader_registry,
         // registered_parser,
         registered_parser_and_generator_builder,
         diagnostics: Arc::new(Mutex::new(vec![])),
       }),
       options,
-    )
+    ))
   }
 
   pub fn take_diagnostic(&self) -> Vec<Diagnostic> {
@@ -652,6 +657,13 @@ impl PluginDriver {
     loader_request: &str,
     loader_options: Option<&str>,
   ) -> Result<Option<BoxLoader>> {
+    if loader_request.starts_with(BUILTIN_LOADER_PREFIX) {
+      return self
+        .builtin_loader_registry
+        .get(loader_request, loader_options)
+        .map(Some);
+    }
+
     for plugin in &self.plugins {
       if let Some(loader) = plugin
         .resolve_loader(
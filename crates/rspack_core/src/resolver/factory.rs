@@ -1,9 +1,9 @@
-use std::{hash::BuildHasherDefault, sync::Arc};
+use std::{hash::BuildHasherDefault, path::PathBuf, sync::Arc};
 
 use dashmap::DashMap;
-use rustc_hash::FxHasher;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
 
-use super::resolver_impl::Resolver;
+use super::{plugin::BoxResolverPlugin, resolver_impl::Resolver, zip_fs::ZipOpenFileSystem};
 use crate::{DependencyCategory, Resolve};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -21,6 +21,8 @@ pub struct ResolverFactory {
   /// Different resolvers are used for different resolution strategies such as ESM and CJS.
   /// All resolvers share the same underlying cache.
   resolvers: DashMap<ResolveOptionsWithDependencyType, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
+  /// Plugins hooking into the resolvers, filtered by the options of each resolver.
+  plugins: Vec<BoxResolverPlugin>,
 }
 
 impl Default for ResolverFactory {
@@ -34,14 +36,30 @@ impl ResolverFactory {
     self.resolver.clear_cache();
   }
 
+  /// The file system of the resolvers, which reads the files inside zip archives as well
+  pub fn file_system(&self) -> &ZipOpenFileSystem {
+    self.resolver.file_system()
+  }
+
+  /// Paths of the Yarn PnP manifests read by all resolvers
+  pub fn pnp_manifest_paths(&self) -> HashSet<PathBuf> {
+    self.resolver.pnp_manifest_paths()
+  }
+
   pub fn new(options: Resolve) -> Self {
     Self {
       base_options: options.clone(),
       resolver: Resolver::new(options),
       resolvers: Default::default(),
+      plugins: vec![],
     }
   }
 
+  pub fn with_plugins(mut self, plugins: Vec<BoxResolverPlugin>) -> Self {
+    self.plugins = plugins;
+    self
+  }
+
   pub fn get(&self, options: ResolveOptionsWithDependencyType) -> Arc<Resolver> {
     if let Some(r) = self.resolvers.get(&options) {
       r.clone()
@@ -51,7 +69,17 @@ impl ResolverFactory {
         Some(o) => base_options.merge(*o.clone()),
         None => base_options,
       };
-      let resolver = Arc::new(self.resolver.clone_with_options(merged_options, &options));
+      let plugins = self
+        .plugins
+        .iter()
+        .filter(|plugin| plugin.should_apply(&options))
+        .cloned()
+        .collect();
+      let resolver = Arc::new(
+        self
+          .resolver
+          .clone_with_options(merged_options, &options, plugins),
+      );
       self.resolvers.insert(options, resolver.clone());
       resolver
     }
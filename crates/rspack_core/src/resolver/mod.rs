@@ -1,5 +1,8 @@
 mod factory;
+mod plugin;
+mod pnp;
 mod resolver_impl;
+mod zip_fs;
 
 use std::{fmt, path::PathBuf};
 
@@ -10,7 +13,9 @@ use rspack_loader_runner::DescriptionData;
 use sugar_path::{AsPath, SugarPath};
 
 pub use self::factory::{ResolveOptionsWithDependencyType, ResolverFactory};
+pub use self::plugin::{BoxResolverPlugin, ResolveHookArgs, ResolveHookResult, ResolverPlugin};
 pub use self::resolver_impl::{ResolveInnerOptions, Resolver};
+pub use self::zip_fs::ZipOpenFileSystem;
 use crate::{ResolveArgs, SharedPluginDriver};
 
 static RELATIVE_PATH_REGEX: Lazy<Regex> =
@@ -0,0 +1,145 @@
+use std::{
+  fmt,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+use rspack_error::Result;
+use rspack_loader_runner::DescriptionData;
+
+use super::{
+  resolver_impl::ResolveContext, zip_fs::ZipOpenFileSystem, ResolveOptionsWithDependencyType,
+  ResolveResult,
+};
+use crate::DependencyCategory;
+
+const DESCRIPTION_FILE: &str = "package.json";
+
+pub type BoxResolverPlugin = Arc<dyn ResolverPlugin>;
+
+#[derive(Debug)]
+pub struct ResolveHookArgs<'a> {
+  /// Directory the request is resolved from
+  pub path: &'a Path,
+  pub request: &'a str,
+  pub dependency_category: DependencyCategory,
+  /// The closest `package.json` of `path`
+  pub description_data: Option<&'a DescriptionData>,
+}
+
+#[derive(Debug)]
+pub enum ResolveHookResult {
+  /// Continue with the next hook, or the default resolution.
+  Continue,
+  /// Resolve `request` from `path` instead, e.g. to look up a package in a custom module directory.
+  /// The hooks are run again for the new request, from `alias`.
+  Redirect { path: PathBuf, request: String },
+  /// Finish the resolution with this result, e.g. a virtual package.
+  Resolved(ResolveResult),
+}
+
+/// Hooks run before and after a request is resolved, registered with
+/// [ApplyContext::register_resolver_plugin](crate::ApplyContext::register_resolver_plugin).
+///
+/// The pre-resolve hooks `alias`, `described_resolve` and `module` run in this order for the
+/// requests of modules and for the requests redirected to, and the post-resolve hook `raw_file`
+/// runs for the resolved file. The hooks don't run for the steps inside the resolver, e.g. the
+/// `exports` or `browser` fields of packages, and the `resolve.alias` of the options.
+///
+/// Resolvers are cached per [ResolveOptionsWithDependencyType], so do [ResolverPlugin::should_apply].
+pub trait ResolverPlugin: fmt::Debug + Send + Sync {
+  fn name(&self) -> &'static str;
+
+  /// Whether the plugin applies to the resolver created for `options`.
+  fn should_apply(&self, _options: &ResolveOptionsWithDependencyType) -> bool {
+    true
+  }
+
+  /// Pre-resolve hook aliasing the request. The first plugin returning a request wins.
+  fn alias(&self, _args: &ResolveHookArgs) -> Result<Option<String>> {
+    Ok(None)
+  }
+
+  /// Pre-resolve hook called for every request, with the description file of `path`.
+  fn described_resolve(&self, _args: &ResolveHookArgs) -> Result<ResolveHookResult> {
+    Ok(ResolveHookResult::Continue)
+  }
+
+  /// Pre-resolve hook called for module requests (e.g. `lodash/get`), unless `described_resolve`
+  /// redirected or resolved the request.
+  fn module(&self, _args: &ResolveHookArgs) -> Result<ResolveHookResult> {
+    Ok(ResolveHookResult::Continue)
+  }
+
+  /// Post-resolve hook replacing the resolved file, e.g. with the sources of a package in a
+  /// monorepo. The args are the ones of the last request resolved.
+  fn raw_file(&self, _file: &Path, _args: &ResolveHookArgs) -> Result<Option<PathBuf>> {
+    Ok(None)
+  }
+}
+
+/// Description files looked up by directory, shared by the resolvers of a factory.
+#[derive(Debug)]
+pub struct DescriptionFiles {
+  by_dir: DashMap<PathBuf, Option<DescriptionData>>,
+  file_system: ZipOpenFileSystem,
+}
+
+impl DescriptionFiles {
+  pub fn new(file_system: ZipOpenFileSystem) -> Self {
+    Self {
+      by_dir: Default::default(),
+      file_system,
+    }
+  }
+
+  pub fn clear(&self) {
+    self.by_dir.clear();
+  }
+
+  /// Find the closest `package.json` in `dir` or its ancestors.
+  ///
+  /// The `package.json` found, and the ones looked up before it, are added to the dependencies
+  /// of `resolve_context`, also when the result is cached.
+  pub fn find(&self, dir: &Path, resolve_context: &mut ResolveContext) -> Option<DescriptionData> {
+    let mut visited = vec![];
+    let mut found = None;
+    for dir in dir.ancestors() {
+      if let Some(description_data) = self.by_dir.get(dir) {
+        found = description_data.clone();
+        break;
+      }
+      visited.push(dir.to_path_buf());
+      // Resolutions are synchronous, as the file system reads of oxc_resolver
+      let json = self
+        .file_system
+        .read(&dir.join(DESCRIPTION_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok());
+      if let Some(json) = json {
+        found = Some(DescriptionData::new(dir.to_path_buf(), Arc::new(json)));
+        break;
+      }
+    }
+    for dir in visited {
+      self.by_dir.insert(dir, found.clone());
+    }
+
+    let found_dir = found
+      .as_ref()
+      .map(|description_data| description_data.path());
+    for dir in dir.ancestors() {
+      if Some(dir) == found_dir {
+        resolve_context
+          .file_dependencies
+          .insert(dir.join(DESCRIPTION_FILE));
+        break;
+      }
+      resolve_context
+        .missing_dependencies
+        .insert(dir.join(DESCRIPTION_FILE));
+    }
+    found
+  }
+}
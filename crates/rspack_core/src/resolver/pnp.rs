@@ -0,0 +1,481 @@
+//! Resolution of bare specifiers through a Yarn Plug'n'Play manifest.
+//!
+//! The manifest (`.pnp.data.json`, or the state inlined in `.pnp.cjs`) maps every package to its
+//! location on disk and to the dependencies it declares. A bare specifier is first resolved to
+//! the location of the dependency declared by the issuer package (an "unqualified" path), which
+//! is then resolved as usual.
+//!
+//! Packages stored in the zip archives of the Yarn cache are located inside the archives, e.g.
+//! `.yarn/cache/foo-npm-1.0.0-0a1b2c.zip/node_modules/foo/`, which are read by the
+//! [ZipOpenFileSystem](super::ZipOpenFileSystem) of the resolver.
+//!
+//! See <https://yarnpkg.com/advanced/pnp-spec>
+
+use std::{
+  fmt,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+use regex::Regex;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::Deserialize;
+use sugar_path::SugarPath;
+
+const PNP_DATA_FILE: &str = ".pnp.data.json";
+const PNP_SCRIPT_FILE: &str = ".pnp.cjs";
+const RAW_RUNTIME_STATE: &str = "RAW_RUNTIME_STATE";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackageLocator {
+  /// `None` for the top-level locator
+  pub name: Option<String>,
+  pub reference: Option<String>,
+}
+
+impl fmt::Display for PackageLocator {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match (&self.name, &self.reference) {
+      (Some(name), Some(reference)) => write!(f, "{name}@{reference}"),
+      (Some(name), None) => write!(f, "{name}"),
+      _ => write!(f, "<top-level>"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum DependencyTarget {
+  Reference(String),
+  /// A dependency aliased to another package, e.g. `"foo": "npm:bar@1.0.0"`
+  Alias(String, String),
+}
+
+impl DependencyTarget {
+  fn to_locator(&self, ident: &str) -> PackageLocator {
+    let (name, reference) = match self {
+      Self::Reference(reference) => (ident, reference),
+      Self::Alias(name, reference) => (name.as_str(), reference),
+    };
+    PackageLocator {
+      name: Some(name.to_string()),
+      reference: Some(reference.to_string()),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedPackageInformation {
+  package_location: String,
+  #[serde(default)]
+  package_dependencies: Vec<(String, Option<DependencyTarget>)>,
+  #[serde(default)]
+  discard_from_lookup: bool,
+}
+
+type SerializedPackageReferences = Vec<(Option<String>, SerializedPackageInformation)>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedManifest {
+  #[serde(default)]
+  enable_top_level_fallback: bool,
+  #[serde(default)]
+  ignore_pattern_data: Option<String>,
+  #[serde(default)]
+  fallback_pool: Vec<(String, Option<DependencyTarget>)>,
+  #[serde(default)]
+  fallback_exclusion_list: Vec<(String, Vec<String>)>,
+  #[serde(default)]
+  package_registry_data: Vec<(Option<String>, SerializedPackageReferences)>,
+}
+
+#[derive(Debug)]
+struct PackageInformation {
+  location: PathBuf,
+  dependencies: HashMap<String, Option<DependencyTarget>>,
+}
+
+#[derive(Debug)]
+pub struct PnpManifest {
+  /// Path of `.pnp.data.json` or `.pnp.cjs`
+  pub path: PathBuf,
+  /// Directory of the manifest, package locations are relative to it
+  dir: PathBuf,
+  ignore_pattern: Option<Regex>,
+  enable_top_level_fallback: bool,
+  fallback_pool: HashMap<String, Option<DependencyTarget>>,
+  fallback_exclusion_list: HashMap<String, HashSet<String>>,
+  packages: HashMap<PackageLocator, PackageInformation>,
+  locators_by_location: HashMap<PathBuf, PackageLocator>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PnpError {
+  InvalidManifest {
+    path: PathBuf,
+    reason: String,
+  },
+  /// The issuer package requires a package it doesn't list in its dependencies.
+  UndeclaredDependency {
+    issuer: PackageLocator,
+    issuer_path: PathBuf,
+    dependency: String,
+  },
+  /// The issuer package declares a peer dependency which is not provided by its parent.
+  MissingPeerDependency {
+    issuer: PackageLocator,
+    issuer_path: PathBuf,
+    dependency: String,
+  },
+}
+
+impl fmt::Display for PnpError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidManifest { path, reason } => write!(
+        f,
+        "Failed to read the Yarn PnP manifest '{}': {reason}",
+        path.display()
+      ),
+      Self::UndeclaredDependency {
+        issuer,
+        issuer_path,
+        dependency,
+      } => write!(
+        f,
+        "Package '{issuer}' tried to access '{dependency}', but it isn't declared in its dependencies (required in '{}')",
+        issuer_path.display()
+      ),
+      Self::MissingPeerDependency {
+        issuer,
+        issuer_path,
+        dependency,
+      } => write!(
+        f,
+        "Package '{issuer}' tried to access '{dependency}' (a peer dependency), but it isn't provided by its ancestors (required in '{}')",
+        issuer_path.display()
+      ),
+    }
+  }
+}
+
+impl PnpError {
+  pub fn help(&self) -> Option<String> {
+    match self {
+      Self::UndeclaredDependency {
+        issuer, dependency, ..
+      } => Some(format!(
+        "Add '{dependency}' to the dependencies of '{}'",
+        issuer.name.as_deref().unwrap_or("your project")
+      )),
+      Self::MissingPeerDependency {
+        issuer, dependency, ..
+      } => Some(format!(
+        "Add '{dependency}' to the dependencies of the package that depends on '{}'",
+        issuer.name.as_deref().unwrap_or("your project")
+      )),
+      Self::InvalidManifest { .. } => None,
+    }
+  }
+}
+
+/// Split a bare specifier into the package name and the subpath, e.g. `@scope/foo/bar` into
+/// `@scope/foo` and `bar`.
+fn parse_bare_identifier(specifier: &str) -> Option<(&str, &str)> {
+  let mut separators = specifier.match_indices('/').map(|(index, _)| index);
+  let end = if specifier.starts_with('@') {
+    separators.nth(1)
+  } else {
+    separators.next()
+  };
+  let (ident, subpath) = match end {
+    Some(end) => (&specifier[..end], &specifier[end + 1..]),
+    None => (specifier, ""),
+  };
+  if ident.is_empty() || (ident.starts_with('@') && !ident.contains('/')) {
+    return None;
+  }
+  Some((ident, subpath))
+}
+
+/// Read the runtime state inlined in `.pnp.cjs` as a single quoted string literal.
+fn extract_inlined_state(script: &str) -> Option<String> {
+  let start = script.find(RAW_RUNTIME_STATE)? + RAW_RUNTIME_STATE.len();
+  let rest = script[start..].trim_start().strip_prefix('=')?.trim_start();
+  let mut chars = rest.strip_prefix('\'')?.chars();
+  let mut state = String::new();
+  loop {
+    match chars.next()? {
+      '\'' => return Some(state),
+      '\\' => match chars.next()? {
+        // line continuation
+        '\n' => {}
+        'n' => state.push('\n'),
+        't' => state.push('\t'),
+        c => state.push(c),
+      },
+      c => state.push(c),
+    }
+  }
+}
+
+impl PnpManifest {
+  fn load(path: &Path) -> Result<Self, PnpError> {
+    let invalid = |reason: String| PnpError::InvalidManifest {
+      path: path.to_path_buf(),
+      reason,
+    };
+    let content = std::fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+    let content = if path.ends_with(PNP_SCRIPT_FILE) {
+      extract_inlined_state(&content)
+        .ok_or_else(|| invalid(format!("can't find {RAW_RUNTIME_STATE}")))?
+    } else {
+      content
+    };
+    let manifest: SerializedManifest =
+      serde_json::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+    let dir = path
+      .parent()
+      .expect("manifest should have a parent directory")
+      .to_path_buf();
+
+    let ignore_pattern = manifest
+      .ignore_pattern_data
+      .map(|pattern| Regex::new(&pattern))
+      .transpose()
+      .map_err(|e| invalid(e.to_string()))?;
+
+    let mut packages = HashMap::default();
+    let mut locators_by_location: HashMap<PathBuf, (PackageLocator, bool)> = HashMap::default();
+    for (name, references) in manifest.package_registry_data {
+      for (reference, information) in references {
+        let locator = PackageLocator {
+          name: name.clone(),
+          reference,
+        };
+        let location = dir
+          .join(&information.package_location)
+          .normalize()
+          .to_path_buf();
+        // Several locators can share a location, the last one which is not discarded wins
+        match locators_by_location.get_mut(&location) {
+          Some((_, discarded)) if information.discard_from_lookup => {
+            *discarded &= information.discard_from_lookup;
+          }
+          Some(entry) => *entry = (locator.clone(), false),
+          None => {
+            locators_by_location.insert(
+              location.clone(),
+              (locator.clone(), information.discard_from_lookup),
+            );
+          }
+        }
+        packages.insert(
+          locator,
+          PackageInformation {
+            location,
+            dependencies: information.package_dependencies.into_iter().collect(),
+          },
+        );
+      }
+    }
+
+    Ok(Self {
+      path: path.to_path_buf(),
+      dir,
+      ignore_pattern,
+      enable_top_level_fallback: manifest.enable_top_level_fallback,
+      fallback_pool: manifest.fallback_pool.into_iter().collect(),
+      fallback_exclusion_list: manifest
+        .fallback_exclusion_list
+        .into_iter()
+        .map(|(name, references)| (name, references.into_iter().collect()))
+        .collect(),
+      packages,
+      locators_by_location: locators_by_location
+        .into_iter()
+        .filter(|(_, (_, discarded))| !discarded)
+        .map(|(location, (locator, _))| (location, locator))
+        .collect(),
+    })
+  }
+
+  fn top_level_locator() -> PackageLocator {
+    PackageLocator {
+      name: None,
+      reference: None,
+    }
+  }
+
+  /// Find the package owning `path`.
+  fn find_package_locator(&self, path: &Path) -> Option<&PackageLocator> {
+    let relative = path.strip_prefix(&self.dir).ok()?;
+    if let Some(ignore_pattern) = &self.ignore_pattern
+      && ignore_pattern.is_match(&relative.to_string_lossy())
+    {
+      return None;
+    }
+    path
+      .ancestors()
+      .take_while(|dir| dir.starts_with(&self.dir))
+      .find_map(|dir| self.locators_by_location.get(dir))
+  }
+
+  fn is_excluded_from_fallback(&self, locator: &PackageLocator) -> bool {
+    match (&locator.name, &locator.reference) {
+      (Some(name), Some(reference)) => self
+        .fallback_exclusion_list
+        .get(name)
+        .is_some_and(|references| references.contains(reference)),
+      _ => false,
+    }
+  }
+
+  /// Resolve a bare specifier requested from `issuer_path` to the location of the dependency
+  /// and the subpath requested in it.
+  ///
+  /// Returns `None` if the issuer isn't part of the dependency tree, in which case the
+  /// specifier should be resolved without PnP.
+  pub fn resolve_to_unqualified<'a>(
+    &self,
+    specifier: &'a str,
+    issuer_path: &Path,
+  ) -> Result<Option<(&Path, &'a str)>, PnpError> {
+    let Some((ident, subpath)) = parse_bare_identifier(specifier) else {
+      return Ok(None);
+    };
+    let Some(issuer) = self.find_package_locator(issuer_path) else {
+      return Ok(None);
+    };
+    let Some(issuer_information) = self.packages.get(issuer) else {
+      return Ok(None);
+    };
+
+    let mut dependency = issuer_information.dependencies.get(ident);
+    if dependency.is_none()
+      && self.enable_top_level_fallback
+      && !self.is_excluded_from_fallback(issuer)
+    {
+      dependency = self
+        .packages
+        .get(&Self::top_level_locator())
+        .and_then(|top_level| top_level.dependencies.get(ident))
+        .or_else(|| self.fallback_pool.get(ident));
+    }
+
+    let target = match dependency {
+      Some(Some(target)) => target,
+      Some(None) => {
+        return Err(PnpError::MissingPeerDependency {
+          issuer: issuer.clone(),
+          issuer_path: issuer_path.to_path_buf(),
+          dependency: ident.to_string(),
+        })
+      }
+      None => {
+        return Err(PnpError::UndeclaredDependency {
+          issuer: issuer.clone(),
+          issuer_path: issuer_path.to_path_buf(),
+          dependency: ident.to_string(),
+        })
+      }
+    };
+
+    let locator = target.to_locator(ident);
+    let Some(information) = self.packages.get(&locator) else {
+      return Err(PnpError::InvalidManifest {
+        path: self.path.clone(),
+        reason: format!("missing package information for '{locator}'"),
+      });
+    };
+    Ok(Some((&information.location, subpath)))
+  }
+}
+
+/// Yarn PnP manifests looked up by directory, shared by the resolvers of a factory.
+#[derive(Debug, Default)]
+pub struct PnpManifests {
+  by_dir: DashMap<PathBuf, Result<Option<Arc<PnpManifest>>, PnpError>>,
+}
+
+impl PnpManifests {
+  pub fn clear(&self) {
+    self.by_dir.clear();
+  }
+
+  /// Paths of the manifests loaded so far.
+  pub fn paths(&self) -> HashSet<PathBuf> {
+    self
+      .by_dir
+      .iter()
+      .filter_map(|entry| match entry.value() {
+        Ok(Some(manifest)) => Some(manifest.path.clone()),
+        Err(PnpError::InvalidManifest { path, .. }) => Some(path.clone()),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// Find the closest manifest in `dir` or its ancestors.
+  ///
+  /// A manifest failing to load is cached as well, so it is only read once.
+  pub fn find(&self, dir: &Path) -> Result<Option<Arc<PnpManifest>>, PnpError> {
+    let mut visited = vec![];
+    let mut found = Ok(None);
+    for dir in dir.ancestors() {
+      if let Some(manifest) = self.by_dir.get(dir) {
+        found = manifest.clone();
+        break;
+      }
+      visited.push(dir.to_path_buf());
+      let manifest_path = [PNP_DATA_FILE, PNP_SCRIPT_FILE]
+        .into_iter()
+        .map(|file| dir.join(file))
+        .find(|path| path.is_file());
+      if let Some(manifest_path) = manifest_path {
+        found = PnpManifest::load(&manifest_path).map(|manifest| Some(Arc::new(manifest)));
+        break;
+      }
+    }
+    for dir in visited {
+      self.by_dir.insert(dir, found.clone());
+    }
+    found
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_bare_identifiers() {
+    assert_eq!(parse_bare_identifier("foo"), Some(("foo", "")));
+    assert_eq!(
+      parse_bare_identifier("foo/bar/baz"),
+      Some(("foo", "bar/baz"))
+    );
+    assert_eq!(
+      parse_bare_identifier("@scope/foo"),
+      Some(("@scope/foo", ""))
+    );
+    assert_eq!(
+      parse_bare_identifier("@scope/foo/bar"),
+      Some(("@scope/foo", "bar"))
+    );
+    assert_eq!(parse_bare_identifier("@scope"), None);
+  }
+
+  #[test]
+  fn extract_inlined_runtime_state() {
+    let script =
+      "#!/usr/bin/env node\nconst RAW_RUNTIME_STATE =\n'{\\\n  \"a\": \"it\\'s\"\\\n}';\n";
+    assert_eq!(
+      extract_inlined_state(script).as_deref(),
+      Some("{  \"a\": \"it's\"}")
+    );
+  }
+}
@@ -1,19 +1,32 @@
 use std::{
+  borrow::Cow,
   fmt,
   path::{Path, PathBuf},
   sync::Arc,
 };
 
 use rspack_error::{
+  codes, error,
   miette::{diagnostic, Diagnostic},
-  DiagnosticExt, Severity, TraceableError,
+  DiagnosticExt, MietteExt, Severity, TraceableError,
 };
 use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet as HashSet;
 
-use super::{ResolveResult, Resource};
+use super::{
+  plugin::{BoxResolverPlugin, DescriptionFiles, ResolveHookArgs, ResolveHookResult},
+  pnp::{PnpError, PnpManifest, PnpManifests},
+  zip_fs::ZipOpenFileSystem,
+  ResolveResult, Resource,
+};
+
+type OxcResolver = oxc_resolver::ResolverGeneric<ZipOpenFileSystem>;
 use crate::{AliasMap, DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType};
 
+/// Limits the redirects of [ResolverPlugin](super::ResolverPlugin)s resolving a request, to stop
+/// plugins redirecting to each other
+const MAX_REDIRECTS: usize = 32;
+
 #[derive(Debug, Default, Clone)]
 pub struct ResolveContext {
   /// Files that was found on file system
@@ -22,10 +35,13 @@ pub struct ResolveContext {
   pub missing_dependencies: HashSet<PathBuf>,
 }
 
-/// Proxy to [nodejs_resolver::Error] or [oxc_resolver::ResolveError]
+/// Proxy to [oxc_resolver::ResolveError], a failed resolution through Yarn PnP, or an error
+/// from a [ResolverPlugin](super::ResolverPlugin)
 #[derive(Debug)]
 pub enum ResolveInnerError {
   OxcResolver(oxc_resolver::ResolveError),
+  Pnp(PnpError),
+  Plugin(rspack_error::Error),
 }
 
 /// Proxy to [oxc_resolver::ResolveOptions]
@@ -77,7 +93,17 @@ impl<'a> ResolveInnerOptions<'a> {
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver {
+    resolver: OxcResolver,
+    /// Reads the packages inside zip archives, shared with the resolvers cloned from this one
+    file_system: ZipOpenFileSystem,
+    /// Yarn PnP manifests, shared with the resolvers cloned from this one
+    pnp_manifests: Arc<PnpManifests>,
+    /// Description files passed to the plugins, shared with the resolvers cloned from this one
+    description_files: Arc<DescriptionFiles>,
+    plugins: Vec<BoxResolverPlugin>,
+    dependency_category: DependencyCategory,
+  },
 }
 
 impl Resolver {
@@ -87,14 +113,40 @@ impl Resolver {
 
   fn new_oxc_resolver(options: Resolve) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
-    Self::OxcResolver(resolver)
+    let file_system = ZipOpenFileSystem::default();
+    let resolver = OxcResolver::new_with_file_system(file_system.clone(), options);
+    Self::OxcResolver {
+      resolver,
+      description_files: Arc::new(DescriptionFiles::new(file_system.clone())),
+      file_system,
+      pnp_manifests: Default::default(),
+      plugins: vec![],
+      dependency_category: DependencyCategory::Unknown,
+    }
   }
 
   /// Clear cache for all resolver instances
   pub fn clear_cache(&self) {
     match self {
-      Self::OxcResolver(resolver) => resolver.clear_cache(),
+      Self::OxcResolver {
+        resolver,
+        file_system,
+        pnp_manifests,
+        description_files,
+        ..
+      } => {
+        resolver.clear_cache();
+        file_system.clear();
+        pnp_manifests.clear();
+        description_files.clear();
+      }
+    }
+  }
+
+  /// Paths of the Yarn PnP manifests read by the resolvers sharing the cache of this one
+  pub fn pnp_manifest_paths(&self) -> HashSet<PathBuf> {
+    match self {
+      Self::OxcResolver { pnp_manifests, .. } => pnp_manifests.paths(),
     }
   }
 
@@ -103,43 +155,50 @@ impl Resolver {
     &self,
     options: Resolve,
     options_with_dependency_type: &ResolveOptionsWithDependencyType,
+    plugins: Vec<BoxResolverPlugin>,
   ) -> Self {
     match self {
-      Self::OxcResolver(resolver) => {
+      Self::OxcResolver {
+        resolver,
+        file_system,
+        pnp_manifests,
+        description_files,
+        ..
+      } => {
         let options = to_oxc_resolver_options(
           options,
           options_with_dependency_type.resolve_to_context,
           options_with_dependency_type.dependency_category,
         );
-        let resolver = resolver.clone_with_options(options);
-        Self::OxcResolver(resolver)
+        Self::OxcResolver {
+          resolver: resolver.clone_with_options(options),
+          file_system: file_system.clone(),
+          pnp_manifests: pnp_manifests.clone(),
+          description_files: description_files.clone(),
+          plugins,
+          dependency_category: options_with_dependency_type.dependency_category,
+        }
       }
     }
   }
 
+  /// The file system of the resolver, which reads the files inside zip archives as well
+  pub fn file_system(&self) -> &ZipOpenFileSystem {
+    match self {
+      Self::OxcResolver { file_system, .. } => file_system,
+    }
+  }
+
   /// Return the options from the resolver
   pub fn options(&self) -> ResolveInnerOptions<'_> {
     match self {
-      Self::OxcResolver(resolver) => ResolveInnerOptions::OxcResolver(resolver.options()),
+      Self::OxcResolver { resolver, .. } => ResolveInnerOptions::OxcResolver(resolver.options()),
     }
   }
 
   /// Resolve a specifier to a given path.
   pub fn resolve(&self, path: &Path, request: &str) -> Result<ResolveResult, ResolveInnerError> {
-    match self {
-      Self::OxcResolver(resolver) => match resolver.resolve(path, request) {
-        Ok(r) => Ok(ResolveResult::Resource(Resource {
-          path: r.path().to_path_buf(),
-          query: r.query().map(ToString::to_string),
-          fragment: r.fragment().map(ToString::to_string),
-          description_data: r
-            .package_json()
-            .map(|d| DescriptionData::new(d.directory().to_path_buf(), Arc::clone(d.raw_json()))),
-        })),
-        Err(oxc_resolver::ResolveError::Ignored(_)) => Ok(ResolveResult::Ignored),
-        Err(error) => Err(ResolveInnerError::OxcResolver(error)),
-      },
-    }
+    self.resolve_with_context(path, request, &mut Default::default())
   }
 
   /// Resolve a specifier to a given path.
@@ -148,38 +207,324 @@ impl Resolver {
     path: &Path,
     request: &str,
     resolve_context: &mut ResolveContext,
+  ) -> Result<ResolveResult, ResolveInnerError> {
+    let mut context = ResolveContext::default();
+    let result = self.resolve_inner(path, request, &mut context);
+    // The files inside zip archives are tracked by their archives
+    let file_system = self.file_system();
+    let to_dependency = |path: PathBuf| {
+      let archive = file_system.archive_of(&path).map(Path::to_path_buf);
+      archive.unwrap_or(path)
+    };
+    resolve_context
+      .file_dependencies
+      .extend(context.file_dependencies.into_iter().map(to_dependency));
+    resolve_context
+      .missing_dependencies
+      .extend(context.missing_dependencies.into_iter().map(to_dependency));
+    result
+  }
+
+  fn resolve_inner(
+    &self,
+    path: &Path,
+    request: &str,
+    resolve_context: &mut ResolveContext,
   ) -> Result<ResolveResult, ResolveInnerError> {
     match self {
-      Self::OxcResolver(resolver) => {
-        let mut context = Default::default();
-        let result = resolver.resolve_with_context(path, request, &mut context);
-        resolve_context
-          .file_dependencies
-          .extend(context.file_dependencies);
-        resolve_context
-          .missing_dependencies
-          .extend(context.missing_dependencies);
-        match result {
-          Ok(r) => Ok(ResolveResult::Resource(Resource {
-            path: r.path().to_path_buf(),
-            query: r.query().map(ToString::to_string),
-            fragment: r.fragment().map(ToString::to_string),
-            description_data: r
-              .package_json()
-              .map(|d| DescriptionData::new(d.directory().to_path_buf(), Arc::clone(d.raw_json()))),
-          })),
-          Err(oxc_resolver::ResolveError::Ignored(_)) => Ok(ResolveResult::Ignored),
-          Err(error) => Err(ResolveInnerError::OxcResolver(error)),
+      Self::OxcResolver {
+        resolver,
+        pnp_manifests,
+        description_files,
+        plugins,
+        dependency_category,
+        ..
+      } => {
+        if plugins.is_empty() {
+          return resolve_request(resolver, pnp_manifests, path, request, resolve_context);
+        }
+
+        let mut path = path.to_path_buf();
+        let mut request = request.to_string();
+        let mut redirects = 0;
+        // The hooks are run again for the requests redirected to, until one is resolved
+        let (result, description_data) = loop {
+          let description_data = description_files.find(&path, resolve_context);
+          let mut args = ResolveHookArgs {
+            path: &path,
+            request: &request,
+            dependency_category: *dependency_category,
+            description_data: description_data.as_ref(),
+          };
+          let aliased = plugins
+            .iter()
+            .find_map(|plugin| plugin.alias(&args).transpose())
+            .transpose()
+            .map_err(ResolveInnerError::Plugin)?;
+          if let Some(aliased) = &aliased {
+            args.request = aliased;
+          }
+
+          let mut hook_result = ResolveHookResult::Continue;
+          for plugin in plugins {
+            hook_result = plugin
+              .described_resolve(&args)
+              .map_err(ResolveInnerError::Plugin)?;
+            if !matches!(hook_result, ResolveHookResult::Continue) {
+              break;
+            }
+          }
+          if matches!(hook_result, ResolveHookResult::Continue) && is_bare_specifier(args.request) {
+            for plugin in plugins {
+              hook_result = plugin.module(&args).map_err(ResolveInnerError::Plugin)?;
+              if !matches!(hook_result, ResolveHookResult::Continue) {
+                break;
+              }
+            }
+          }
+
+          match hook_result {
+            ResolveHookResult::Continue => {
+              let result = resolve_request(
+                resolver,
+                pnp_manifests,
+                &path,
+                args.request,
+                resolve_context,
+              )?;
+              if let Some(aliased) = aliased {
+                request = aliased;
+              }
+              break (result, description_data);
+            }
+            ResolveHookResult::Redirect {
+              path: redirected_path,
+              request: redirected_request,
+            } => {
+              redirects += 1;
+              if redirects > MAX_REDIRECTS {
+                return Err(ResolveInnerError::Plugin(error!(
+                  "Too many redirects of resolver plugins when resolving {request} in {}",
+                  path.display()
+                )));
+              }
+              path = redirected_path;
+              request = redirected_request;
+            }
+            ResolveHookResult::Resolved(result) => break (result, description_data),
+          }
+        };
+        let args = ResolveHookArgs {
+          path: &path,
+          request: &request,
+          dependency_category: *dependency_category,
+          description_data: description_data.as_ref(),
+        };
+
+        let ResolveResult::Resource(mut resource) = result else {
+          return Ok(result);
+        };
+        for plugin in plugins {
+          if let Some(file) = plugin
+            .raw_file(&resource.path, &args)
+            .map_err(ResolveInnerError::Plugin)?
+          {
+            resource.path = file;
+            break;
+          }
         }
+        Ok(ResolveResult::Resource(resource))
       }
     }
   }
 }
 
+fn resolve_request(
+  resolver: &OxcResolver,
+  pnp_manifests: &PnpManifests,
+  path: &Path,
+  request: &str,
+  resolve_context: &mut ResolveContext,
+) -> Result<ResolveResult, ResolveInnerError> {
+  resolve_with_pnp(resolver, pnp_manifests, path, request, resolve_context)
+    .unwrap_or_else(|| resolve_with_oxc_resolver(resolver, path, request, resolve_context))
+}
+
+fn resolve_with_oxc_resolver(
+  resolver: &OxcResolver,
+  path: &Path,
+  request: &str,
+  resolve_context: &mut ResolveContext,
+) -> Result<ResolveResult, ResolveInnerError> {
+  let mut context = Default::default();
+  let result = resolver.resolve_with_context(path, request, &mut context);
+  resolve_context
+    .file_dependencies
+    .extend(context.file_dependencies);
+  resolve_context
+    .missing_dependencies
+    .extend(context.missing_dependencies);
+  match result {
+    Ok(r) => Ok(ResolveResult::Resource(Resource {
+      path: r.path().to_path_buf(),
+      query: r.query().map(ToString::to_string),
+      fragment: r.fragment().map(ToString::to_string),
+      description_data: r
+        .package_json()
+        .map(|d| DescriptionData::new(d.directory().to_path_buf(), Arc::clone(d.raw_json()))),
+    })),
+    Err(oxc_resolver::ResolveError::Ignored(_)) => Ok(ResolveResult::Ignored),
+    Err(error) => Err(ResolveInnerError::OxcResolver(error)),
+  }
+}
+
+fn is_bare_specifier(request: &str) -> bool {
+  !(request.is_empty()
+    || request.starts_with(['.', '/', '\\', '#'])
+    || request.contains(':')
+    || Path::new(request).is_absolute())
+}
+
+/// Apply the alias matching a bare specifier before it is resolved through PnP.
+///
+/// Returns the requests the alias points to, in the order they are tried, or the specifier
+/// itself if no alias matches it. Returns `None` if the alias ignores the specifier, which is
+/// left to the resolver.
+fn alias_bare_specifier<'a>(
+  alias: &oxc_resolver::Alias,
+  request: &'a str,
+) -> Option<Vec<Cow<'a, str>>> {
+  for (key, values) in alias {
+    let rest = match key.strip_suffix('$') {
+      Some(key) => (request == key).then_some(""),
+      None if request == key => Some(""),
+      None => request
+        .strip_prefix(key.as_str())
+        .and_then(|rest| rest.strip_prefix('/')),
+    };
+    let Some(rest) = rest else {
+      continue;
+    };
+    return values
+      .iter()
+      .map(|value| match value {
+        oxc_resolver::AliasValue::Path(target) => Some(Cow::Owned(if rest.is_empty() {
+          target.clone()
+        } else {
+          format!("{target}/{rest}")
+        })),
+        oxc_resolver::AliasValue::Ignore => None,
+      })
+      .collect();
+  }
+  Some(vec![Cow::Borrowed(request)])
+}
+
+/// Turn a package located in a `node_modules` directory, as the unplugged packages of Yarn, into
+/// a request from the directory containing `node_modules`, so its `exports` field is respected.
+fn to_node_modules_request(location: &Path, subpath: &str) -> Option<(PathBuf, String)> {
+  let node_modules = location
+    .ancestors()
+    .skip(1)
+    .take(2)
+    .find(|dir| dir.file_name().is_some_and(|name| name == "node_modules"))?;
+  let package_name = location
+    .strip_prefix(node_modules)
+    .ok()?
+    .components()
+    .map(|component| component.as_os_str().to_string_lossy())
+    .collect::<Vec<_>>()
+    .join("/");
+  let request = if subpath.is_empty() {
+    package_name
+  } else {
+    format!("{package_name}/{subpath}")
+  };
+  Some((node_modules.parent()?.to_path_buf(), request))
+}
+
+/// Resolve a bare specifier through the closest Yarn PnP manifest.
+///
+/// Returns `None` if there is no manifest, or the specifier isn't handled by it.
+fn resolve_with_pnp(
+  resolver: &OxcResolver,
+  pnp_manifests: &PnpManifests,
+  path: &Path,
+  request: &str,
+  resolve_context: &mut ResolveContext,
+) -> Option<Result<ResolveResult, ResolveInnerError>> {
+  if !is_bare_specifier(request) {
+    return None;
+  }
+  let requests = alias_bare_specifier(&resolver.options().alias, request)?;
+  if !requests.iter().any(|request| is_bare_specifier(request)) {
+    return None;
+  }
+  let manifest = match pnp_manifests.find(path) {
+    Ok(manifest) => manifest?,
+    Err(error) => return Some(Err(ResolveInnerError::Pnp(error))),
+  };
+
+  // Like the alias of the resolver, the first request which resolves wins
+  let mut result = None;
+  for request in &requests {
+    let resolved = if is_bare_specifier(request) {
+      resolve_with_manifest(resolver, &manifest, path, request, resolve_context)
+    } else {
+      resolve_with_oxc_resolver(resolver, path, request, resolve_context)
+    };
+    if resolved.is_ok() {
+      return Some(resolved);
+    }
+    result.get_or_insert(resolved);
+  }
+  result
+}
+
+fn resolve_with_manifest(
+  resolver: &OxcResolver,
+  manifest: &PnpManifest,
+  path: &Path,
+  request: &str,
+  resolve_context: &mut ResolveContext,
+) -> Result<ResolveResult, ResolveInnerError> {
+  match manifest.resolve_to_unqualified(request, path) {
+    Ok(None) => resolve_with_oxc_resolver(resolver, path, request, resolve_context),
+    Ok(Some((location, subpath))) => {
+      let node_modules_request = resolver
+        .options()
+        .modules
+        .iter()
+        .any(|modules| modules == "node_modules")
+        .then(|| to_node_modules_request(location, subpath))
+        .flatten();
+      match node_modules_request {
+        Some((dir, request)) => {
+          resolve_with_oxc_resolver(resolver, &dir, &request, resolve_context)
+        }
+        None => {
+          let unqualified = location.join(subpath);
+          resolve_with_oxc_resolver(
+            resolver,
+            path,
+            &unqualified.to_string_lossy(),
+            resolve_context,
+          )
+        }
+      }
+    }
+    // The dependency may still be provided by `resolve.fallback` or a `node_modules` directory
+    Err(error) => resolve_with_oxc_resolver(resolver, path, request, resolve_context)
+      .map_err(|_| ResolveInnerError::Pnp(error)),
+  }
+}
+
 impl ResolveInnerError {
   pub fn into_resolve_error(self, args: &ResolveArgs<'_>) -> Box<dyn Diagnostic + Send + Sync> {
     match self {
       Self::OxcResolver(error) => map_oxc_resolver_error(error, args),
+      Self::Pnp(error) => map_pnp_error(error, args),
+      Self::Plugin(error) => error.into(),
     }
   }
 }
@@ -305,7 +650,11 @@ fn map_resolver_error(
 
   let importer = args.importer;
   if importer.is_none() {
-    return diagnostic!("Resolve error: Can't resolve '{request}' in '{context}'").boxed();
+    return diagnostic!(
+      code = codes::MODULE_NOT_FOUND,
+      "Resolve error: Can't resolve '{request}' in '{context}'"
+    )
+    .boxed();
   }
 
   let span = args.span.unwrap_or_default();
@@ -316,6 +665,7 @@ fn map_resolver_error(
     "Resolve error".to_string(),
     message,
   )
+  .with_code(codes::MODULE_NOT_FOUND)
   .with_help(if is_recursion {
     Some("maybe it had cyclic aliases")
   } else {
@@ -331,3 +681,31 @@ fn map_resolver_error(
   )
   .boxed()
 }
+
+fn map_pnp_error(error: PnpError, args: &ResolveArgs<'_>) -> Box<dyn Diagnostic + Send + Sync> {
+  let help = error.help();
+  if args.importer.is_none() {
+    let diagnostic =
+      diagnostic!(code = codes::MODULE_NOT_FOUND, "Resolve error: {}", error).boxed();
+    return match help {
+      Some(help) => diagnostic.with_help(help),
+      None => diagnostic,
+    };
+  }
+
+  let span = args.span.unwrap_or_default();
+  TraceableError::from_empty_file(
+    span.start as usize,
+    span.end as usize,
+    "Resolve error".to_string(),
+    error.to_string(),
+  )
+  .with_code(codes::MODULE_NOT_FOUND)
+  .with_help(help)
+  .with_severity(if args.optional {
+    Severity::Warn
+  } else {
+    Severity::Error
+  })
+  .boxed()
+}
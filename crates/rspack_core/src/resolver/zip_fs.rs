@@ -0,0 +1,296 @@
+//! A file system reading the files inside zip archives as if the archives were directories, like
+//! the `ZipOpenFS` of `@yarnpkg/fslib`, e.g.
+//! `.yarn/cache/foo-npm-1.0.0-0a1b2c.zip/node_modules/foo/index.js`.
+//!
+//! Yarn PnP keeps the packages in the zip archives of its cache, which are only read, so the
+//! archives are read once and kept in memory until the cache is cleared. Only the stored and
+//! deflated entries of zip archives without ZIP64 extensions are supported, as written by Yarn.
+
+use std::{
+  fs, io,
+  path::{Component, Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+use miniz_oxide::inflate::decompress_to_vec;
+use oxc_resolver::{FileMetadata, FileSystem};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIZE: usize = 22;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+#[derive(Debug)]
+struct ZipEntry {
+  method: u16,
+  compressed_size: usize,
+  uncompressed_size: usize,
+  local_header_offset: usize,
+}
+
+#[derive(Debug)]
+struct ZipArchive {
+  data: Vec<u8>,
+  files: HashMap<PathBuf, ZipEntry>,
+  /// Directories, listed in the archive or implied by the paths of the files
+  dirs: HashSet<PathBuf>,
+}
+
+fn invalid_data(path: &Path, reason: &str) -> io::Error {
+  io::Error::new(
+    io::ErrorKind::InvalidData,
+    format!("invalid zip archive '{}': {reason}", path.display()),
+  )
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+  Some(u16::from_le_bytes(
+    data.get(offset..offset + 2)?.try_into().ok()?,
+  ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+  Some(u32::from_le_bytes(
+    data.get(offset..offset + 4)?.try_into().ok()?,
+  ))
+}
+
+impl ZipArchive {
+  fn read(path: &Path) -> io::Result<Self> {
+    let data = fs::read(path)?;
+    Self::parse(data).ok_or_else(|| invalid_data(path, "malformed central directory"))
+  }
+
+  fn parse(data: Vec<u8>) -> Option<Self> {
+    // The end of central directory record is followed by a comment of up to 64 KiB
+    let end = (0..=data.len().checked_sub(END_OF_CENTRAL_DIRECTORY_SIZE)?)
+      .rev()
+      .take(u16::MAX as usize + 1)
+      .find(|offset| read_u32(&data, *offset) == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE))?;
+    let entries = read_u16(&data, end + 10)?;
+    let mut offset = read_u32(&data, end + 16)? as usize;
+
+    let mut files = HashMap::default();
+    let mut dirs = HashSet::default();
+    for _ in 0..entries {
+      if read_u32(&data, offset)? != CENTRAL_DIRECTORY_SIGNATURE {
+        return None;
+      }
+      let name_len = read_u16(&data, offset + 28)? as usize;
+      let extra_len = read_u16(&data, offset + 30)? as usize;
+      let comment_len = read_u16(&data, offset + 32)? as usize;
+      let name = std::str::from_utf8(data.get(offset + 46..offset + 46 + name_len)?).ok()?;
+      let entry = ZipEntry {
+        method: read_u16(&data, offset + 10)?,
+        compressed_size: read_u32(&data, offset + 20)? as usize,
+        uncompressed_size: read_u32(&data, offset + 24)? as usize,
+        local_header_offset: read_u32(&data, offset + 42)? as usize,
+      };
+      offset += 46 + name_len + extra_len + comment_len;
+
+      let entry_path = PathBuf::from(name.trim_end_matches('/'));
+      dirs.extend(entry_path.ancestors().skip(1).map(|dir| dir.to_path_buf()));
+      if name.ends_with('/') {
+        dirs.insert(entry_path);
+      } else {
+        files.insert(entry_path, entry);
+      }
+    }
+
+    Some(Self { data, files, dirs })
+  }
+
+  fn read_file(&self, archive_path: &Path, path: &Path) -> io::Result<Vec<u8>> {
+    let Some(entry) = self.files.get(path) else {
+      return Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!(
+          "'{}' not found in zip archive '{}'",
+          path.display(),
+          archive_path.display()
+        ),
+      ));
+    };
+    let invalid = |reason: &str| invalid_data(archive_path, reason);
+    let header = entry.local_header_offset;
+    if read_u32(&self.data, header) != Some(LOCAL_FILE_HEADER_SIGNATURE) {
+      return Err(invalid("malformed local file header"));
+    }
+    let name_len = read_u16(&self.data, header + 26).unwrap_or_default() as usize;
+    let extra_len = read_u16(&self.data, header + 28).unwrap_or_default() as usize;
+    let start = header + 30 + name_len + extra_len;
+    let compressed = self
+      .data
+      .get(start..start + entry.compressed_size)
+      .ok_or_else(|| invalid("truncated entry"))?;
+    let content = match entry.method {
+      STORED => compressed.to_vec(),
+      DEFLATED => decompress_to_vec(compressed).map_err(|_| invalid("corrupt deflated entry"))?,
+      method => return Err(invalid(&format!("unsupported compression method {method}"))),
+    };
+    if content.len() != entry.uncompressed_size {
+      return Err(invalid("unexpected size of entry"));
+    }
+    Ok(content)
+  }
+}
+
+/// A path inside a zip archive.
+struct ZipPath<'a> {
+  archive: &'a Path,
+  /// Path relative to the root of the archive
+  inner: PathBuf,
+}
+
+/// Split `path` at the first component with the `zip` extension, like `@yarnpkg/fslib`.
+///
+/// The archive itself is read by the native file system, e.g. to import it as an asset.
+fn split_zip_path(path: &Path) -> Option<ZipPath<'_>> {
+  let mut components = path.components();
+  let mut archive_len = 0;
+  loop {
+    let component = components.next()?;
+    archive_len += 1;
+    if let Component::Normal(name) = component
+      && Path::new(name).extension().is_some_and(|ext| ext == "zip")
+    {
+      break;
+    }
+  }
+  let inner = components.as_path();
+  if inner.as_os_str().is_empty() {
+    return None;
+  }
+  let archive = path
+    .ancestors()
+    .nth(path.components().count() - archive_len)?;
+  Some(ZipPath {
+    archive,
+    inner: inner.to_path_buf(),
+  })
+}
+
+/// The native file system, reading the paths inside zip archives from the archives.
+#[derive(Debug, Default, Clone)]
+pub struct ZipOpenFileSystem {
+  /// `None` for paths with the `zip` extension which are not files
+  archives: Arc<DashMap<PathBuf, Option<Arc<ZipArchive>>>>,
+}
+
+impl ZipOpenFileSystem {
+  pub fn clear(&self) {
+    self.archives.clear();
+  }
+
+  /// The zip archive containing `path`, if any.
+  pub fn archive_of<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+    let zip_path = split_zip_path(path)?;
+    self.open(zip_path.archive).ok()??;
+    Some(zip_path.archive)
+  }
+
+  fn open(&self, archive: &Path) -> io::Result<Option<Arc<ZipArchive>>> {
+    if let Some(opened) = self.archives.get(archive) {
+      return Ok(opened.clone());
+    }
+    let opened = if archive.is_file() {
+      Some(Arc::new(ZipArchive::read(archive)?))
+    } else {
+      None
+    };
+    self.archives.insert(archive.to_path_buf(), opened.clone());
+    Ok(opened)
+  }
+
+  /// Open the archive of a path inside a zip archive, `None` for the other paths.
+  fn open_zip_path<'a>(
+    &self,
+    path: &'a Path,
+  ) -> io::Result<Option<(ZipPath<'a>, Arc<ZipArchive>)>> {
+    let Some(zip_path) = split_zip_path(path) else {
+      return Ok(None);
+    };
+    Ok(
+      self
+        .open(zip_path.archive)?
+        .map(|archive| (zip_path, archive)),
+    )
+  }
+
+  /// Read the entire contents of a file, which may be inside a zip archive.
+  pub fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    match self.open_zip_path(path)? {
+      Some((zip_path, archive)) => archive.read_file(zip_path.archive, &zip_path.inner),
+      None => fs::read(path),
+    }
+  }
+}
+
+impl FileSystem for ZipOpenFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    String::from_utf8(self.read(path)?)
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    match self.open_zip_path(path)? {
+      Some((zip_path, archive)) => {
+        if archive.files.contains_key(&zip_path.inner) {
+          Ok(FileMetadata::new(true, false, false))
+        } else if archive.dirs.contains(&zip_path.inner) {
+          Ok(FileMetadata::new(false, true, false))
+        } else {
+          Err(io::ErrorKind::NotFound.into())
+        }
+      }
+      None => {
+        let metadata = fs::metadata(path)?;
+        // An archive is a directory as well, to look up the packages inside of it
+        let is_archive = metadata.is_file()
+          && path.extension().is_some_and(|ext| ext == "zip")
+          && self.open(path).is_ok_and(|archive| archive.is_some());
+        Ok(FileMetadata::new(
+          metadata.is_file(),
+          metadata.is_dir() || is_archive,
+          metadata.is_symlink(),
+        ))
+      }
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    // Symbolic links inside zip archives are not followed
+    if split_zip_path(path).is_some() {
+      return self.metadata(path);
+    }
+    fs::symlink_metadata(path).map(FileMetadata::from)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    match self.open_zip_path(path)? {
+      Some((zip_path, _)) => Ok(dunce::canonicalize(zip_path.archive)?.join(zip_path.inner)),
+      None => dunce::canonicalize(path),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn split_zip_paths() {
+    let zip_path = split_zip_path(Path::new("/cache/foo.zip/node_modules/foo/index.js"))
+      .expect("should be inside a zip archive");
+    assert_eq!(zip_path.archive, Path::new("/cache/foo.zip"));
+    assert_eq!(zip_path.inner, Path::new("node_modules/foo/index.js"));
+
+    assert!(split_zip_path(Path::new("/cache/foo.zip")).is_none());
+    assert!(split_zip_path(Path::new("/cache/foo/index.js")).is_none());
+  }
+}
@@ -190,7 +190,7 @@ pub fn compare_runtime(a: &RuntimeSpec, b: &RuntimeSpec) -> Ordering {
   Ordering::Equal
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct RuntimeSpecMap<T> {
   pub mode: RuntimeMode,
   pub map: HashMap<RuntimeKey, T>,
@@ -199,6 +199,17 @@ pub struct RuntimeSpecMap<T> {
   pub single_value: Option<T>,
 }
 
+impl<T> Default for RuntimeSpecMap<T> {
+  fn default() -> Self {
+    Self {
+      mode: Default::default(),
+      map: Default::default(),
+      single_runtime: None,
+      single_value: None,
+    }
+  }
+}
+
 impl<T> RuntimeSpecMap<T> {
   pub fn size(&self) -> usize {
     let mode = self.mode as usize;
@@ -5,7 +5,7 @@ use swc_core::ecma::atoms::Atom;
 
 bitflags! {
   #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-  pub struct RuntimeGlobals: u64 {
+  pub struct RuntimeGlobals: u128 {
     const REQUIRE_SCOPE = 1 << 0;
 
     const EXPORT_STAR = 1 << 1;
@@ -244,6 +244,21 @@ bitflags! {
 
     // rspack only
     const RSPACK_VERSION = 1 << 62;
+
+    /**
+     * map from chunk id to the subresource integrity of the chunk's script
+     */
+    const SRI_HASHES = 1 << 63;
+
+    /**
+     * object with the compiled WebAssembly.Module of every loaded synchronous WebAssembly module
+     */
+    const WASM_INSTANCES = 1 << 64;
+
+    /**
+     * the retry policy used when loading a chunk fails
+     */
+    const CHUNK_LOAD_RETRY = 1 << 65;
   }
 }
 
@@ -295,6 +310,7 @@ impl RuntimeGlobals {
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
       R::STARTUP_ENTRYPOINT => "__webpack_require__.X",
@@ -327,6 +343,8 @@ impl RuntimeGlobals {
       R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
       // rspack only
       R::RSPACK_VERSION => "__webpack_require__.rv",
+      R::SRI_HASHES => "__webpack_require__.sriHashes",
+      R::CHUNK_LOAD_RETRY => "__webpack_require__.lr",
       _ => unreachable!(),
     }
   }
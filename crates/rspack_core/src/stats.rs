@@ -1,13 +1,17 @@
 use either::Either;
 use itertools::Itertools;
-use rspack_error::emitter::{DiagnosticDisplay, DiagnosticDisplayer};
+use rspack_error::emitter::{
+  DiagnosticDisplay, DiagnosticDisplayer, JsonDiagnosticDisplay, SarifDiagnosticDisplay,
+};
 use rspack_error::emitter::{StdioDiagnosticDisplay, StringDiagnosticDisplay};
-use rspack_error::Result;
+use rspack_error::{miette::IntoDiagnostic, Diagnostic, Result};
 use rspack_sources::Source;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::concatenated_module::ConcatenatedModule;
+use crate::DiagnosticsFormat;
 use crate::{get_chunk_from_ukey, get_chunk_group_from_ukey};
-use crate::{BoxModule, BoxRuntimeModule, Chunk};
+use crate::{BoxModule, BoxRuntimeModule, Chunk, ProvidedExports, RuntimeSpec, UsedExports};
 use crate::{ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType};
 
 #[derive(Debug, Clone)]
@@ -20,17 +24,73 @@ impl<'compilation> Stats<'compilation> {
     Self { compilation }
   }
 
+  /// Print warnings and errors to stderr, in the format of [StatsOptions::diagnostics_format](crate::StatsOptions::diagnostics_format).
   pub fn emit_diagnostics(&self) -> Result<()> {
+    if !matches!(
+      self.compilation.options.stats.diagnostics_format,
+      DiagnosticsFormat::Graphical
+    ) {
+      use std::io::Write;
+      let report = self.emit_diagnostics_string(true)?;
+      return writeln!(std::io::stderr(), "{report}").into_diagnostic();
+    }
     let mut displayer = StdioDiagnosticDisplay::default();
     displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
     displayer.emit_batch_diagnostic(self.compilation.get_errors())
   }
 
+  /// Render warnings and errors, in the format of [StatsOptions::diagnostics_format](crate::StatsOptions::diagnostics_format).
+  /// Structured formats are a single document, with the warnings first.
   pub fn emit_diagnostics_string(&self, sorted: bool) -> Result<String> {
-    let mut displayer = StringDiagnosticDisplay::default().with_sorted(sorted);
-    let warnings = displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
-    let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
-    Ok(format!("{warnings}{errors}"))
+    match self.compilation.options.stats.diagnostics_format {
+      DiagnosticsFormat::Graphical => {
+        let mut displayer = StringDiagnosticDisplay::default().with_sorted(sorted);
+        let warnings = displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
+        let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
+        Ok(format!("{warnings}{errors}"))
+      }
+      DiagnosticsFormat::Json => {
+        JsonDiagnosticDisplay.emit_batch_diagnostic(self.get_diagnostics_with_files(sorted).iter())
+      }
+      DiagnosticsFormat::Sarif => {
+        SarifDiagnosticDisplay.emit_batch_diagnostic(self.get_diagnostics_with_files(sorted).iter())
+      }
+    }
+  }
+
+  /// Warnings and errors with the resource of their module as the file.
+  fn get_diagnostics_with_files(&self, sorted: bool) -> Vec<Diagnostic> {
+    let diagnostics: Box<dyn Iterator<Item = &Diagnostic>> = if sorted {
+      Box::new(
+        self
+          .compilation
+          .get_warnings_sorted()
+          .chain(self.compilation.get_errors_sorted()),
+      )
+    } else {
+      Box::new(
+        self
+          .compilation
+          .get_warnings()
+          .chain(self.compilation.get_errors()),
+      )
+    };
+    diagnostics
+      .map(|d| {
+        let module = d
+          .module_identifier()
+          .and_then(|identifier| {
+            self
+              .compilation
+              .module_graph
+              .module_by_identifier(&identifier)
+          })
+          .and_then(|module| module.as_normal_module());
+        d.clone()
+          .with_file(module.map(|module| module.resource_resolved_data().resource_path.clone()))
+          .with_file_transformed(module.is_some_and(|module| !module.loaders().is_empty()))
+      })
+      .collect()
   }
 }
 
@@ -137,7 +197,7 @@ impl Stats<'_> {
       .module_graph
       .modules()
       .values()
-      .map(|module| self.get_module(module, reasons, module_assets, nested_modules, source))
+      .map(|module| self.get_module(module, None, reasons, module_assets, nested_modules, source))
       .chain(
         self
           .compilation
@@ -149,6 +209,7 @@ impl Stats<'_> {
       )
       .collect::<Result<_>>()?;
     Self::sort_modules(&mut modules);
+    Self::sort_modules_by_depth(&mut modules);
     Ok(modules)
   }
 
@@ -179,7 +240,16 @@ impl Stats<'_> {
             .get_chunk_modules(&c.ukey, &self.compilation.module_graph);
           let mut chunk_modules = chunk_modules
             .into_iter()
-            .map(|m| self.get_module(m, reasons, module_assets, nested_modules, source))
+            .map(|m| {
+              self.get_module(
+                m,
+                Some(&c.runtime),
+                reasons,
+                module_assets,
+                nested_modules,
+                source,
+              )
+            })
             .collect::<Result<Vec<_>>>()?;
           Self::sort_modules(&mut chunk_modules);
           Some(chunk_modules)
@@ -209,6 +279,7 @@ impl Stats<'_> {
           parents,
           children,
           siblings,
+          origins: self.get_chunk_origins(c),
         })
       })
       .collect::<Result<_>>()?;
@@ -361,7 +432,6 @@ impl Stats<'_> {
   }
 
   fn sort_modules(modules: &mut [StatsModule]) {
-    // TODO: sort by module.depth
     modules.sort_unstable_by(|a, b| {
       if a.name.len() != b.name.len() {
         a.name.len().cmp(&b.name.len())
@@ -371,9 +441,26 @@ impl Stats<'_> {
     });
   }
 
+  /// Same as the default `modulesSort: "depth"` of webpack, only used for the modules of the
+  /// compilation. Chunk modules and nested modules are sorted by name.
+  fn sort_modules_by_depth(modules: &mut [StatsModule]) {
+    modules.sort_by(|a, b| {
+      // modules without depth (e.g. runtime modules) are placed last
+      match (a.depth, b.depth) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+      }
+    });
+  }
+
+  /// `runtime` is the runtime of the chunk for chunk modules, the used exports of every runtime
+  /// are merged without it.
   fn get_module<'a>(
     &'a self,
     module: &'a BoxModule,
+    runtime: Option<&RuntimeSpec>,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
@@ -465,8 +552,26 @@ impl Stats<'_> {
       assets
     });
 
-    // TODO: a placeholder for concatenation modules
-    let modules = nested_modules.then(Vec::new);
+    let modules = nested_modules
+      .then(|| -> Result<_> {
+        let Some(concatenated_module) = module.downcast_ref::<ConcatenatedModule>() else {
+          return Ok(Vec::new());
+        };
+        let mut modules = concatenated_module
+          .get_modules()
+          .iter()
+          .filter_map(|inner| {
+            self
+              .compilation
+              .module_graph
+              .module_by_identifier(&inner.id)
+          })
+          .map(|m| self.get_module(m, runtime, reasons.is_some(), module_assets, false, source))
+          .collect::<Result<Vec<_>>>()?;
+        Self::sort_modules(&mut modules);
+        Ok(modules)
+      })
+      .transpose()?;
     let profile = if let Some(p) = mgm.get_profile()
       && let Some(factory) = p.factory.duration()
       && let Some(integration) = p.integration.duration()
@@ -481,10 +586,36 @@ impl Stats<'_> {
       None
     };
 
+    let optimization = &self.compilation.options.optimization;
+    let exports_info = self.compilation.module_graph.get_exports_info(&identifier);
+    let provided_exports = optimization
+      .provided_exports
+      .then(
+        || match exports_info.get_provided_exports(&self.compilation.module_graph) {
+          ProvidedExports::Vec(v) => Some(v.iter().map(|i| i.to_string()).collect()),
+          _ => None,
+        },
+      )
+      .flatten();
+    let used_exports = optimization
+      .used_exports
+      .is_enable()
+      .then(
+        || match exports_info.get_used_exports(runtime, &self.compilation.module_graph) {
+          UsedExports::Null => None,
+          UsedExports::Bool(b) => Some(StatsUsedExports::Bool(b)),
+          UsedExports::Vec(v) => Some(StatsUsedExports::Vec(
+            v.iter().map(|i| i.to_string()).collect(),
+          )),
+        },
+      )
+      .flatten();
+
     Ok(StatsModule {
       r#type: "module",
       module_type: *module.module_type(),
       identifier,
+      depth: mgm.depth,
       name_for_condition: module.name_for_condition().map(|n| n.to_string()),
       name: module
         .readable_identifier(&self.compilation.options.context)
@@ -510,6 +641,9 @@ impl Stats<'_> {
         .chunk_graph
         .get_number_of_module_chunks(identifier)
         == 0,
+      provided_exports,
+      used_exports,
+      optimization_bailout: mgm.optimization_bailout.clone(),
     })
   }
 
@@ -534,6 +668,7 @@ impl Stats<'_> {
       r#type: "module",
       module_type: *module.module_type(),
       identifier: module.identifier(),
+      depth: None,
       name_for_condition: module.name_for_condition().map(|n| n.to_string()),
       name: module.name().to_string(),
       id: Some(String::new()),
@@ -553,8 +688,39 @@ impl Stats<'_> {
         .chunk_graph
         .get_number_of_module_chunks(*identifier)
         == 0,
+      provided_exports: None,
+      used_exports: None,
+      optimization_bailout: Vec::new(),
     })
   }
+
+  fn get_chunk_origins(&self, chunk: &Chunk) -> Vec<StatsOriginRecord> {
+    let mut origins = chunk
+      .groups
+      .iter()
+      .filter_map(|cg| get_chunk_group_from_ukey(cg, &self.compilation.chunk_group_by_ukey))
+      .flat_map(|cg| cg.origins())
+      .map(|origin| {
+        let module = origin
+          .module_id
+          .and_then(|i| self.compilation.module_graph.module_by_identifier(&i));
+        let (module_name, module_id) = module
+          .map(|m| get_stats_module_name_and_id(m, self.compilation))
+          .unzip();
+        StatsOriginRecord {
+          module: origin.module_id.map(|i| i.to_string()).unwrap_or_default(),
+          module_identifier: origin.module_id.map(|i| i.to_string()).unwrap_or_default(),
+          module_name: module_name.unwrap_or_default(),
+          module_id: module_id.flatten().unwrap_or_default(),
+          loc: origin.loc.map(|loc| loc.to_string()).unwrap_or_default(),
+          request: origin.request.clone().unwrap_or_default(),
+        }
+      })
+      .collect::<Vec<_>>();
+    origins.sort_unstable();
+    origins.dedup();
+    origins
+  }
   fn get_chunk_relations(&self, chunk: &Chunk) -> (Vec<String>, Vec<String>, Vec<String>) {
     let mut parents = HashSet::default();
     let mut children = HashSet::default();
@@ -664,6 +830,7 @@ pub struct StatsModule<'a> {
   pub r#type: &'static str,
   pub module_type: ModuleType,
   pub identifier: ModuleIdentifier,
+  pub depth: Option<usize>,
   pub name: String,
   pub name_for_condition: Option<String>,
   pub id: Option<String>,
@@ -679,6 +846,15 @@ pub struct StatsModule<'a> {
   pub source: Option<&'a dyn Source>,
   pub profile: Option<StatsModuleProfile>,
   pub orphan: bool,
+  pub provided_exports: Option<Vec<String>>,
+  pub used_exports: Option<StatsUsedExports>,
+  pub optimization_bailout: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum StatsUsedExports {
+  Bool(bool),
+  Vec(Vec<String>),
 }
 
 #[derive(Debug)]
@@ -702,6 +878,17 @@ pub struct StatsChunk<'a> {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Vec<StatsOriginRecord>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StatsOriginRecord {
+  pub module_id: String,
+  pub loc: String,
+  pub request: String,
+  pub module: String,
+  pub module_identifier: String,
+  pub module_name: String,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,29 @@
+//! Stable codes of the builtin diagnostics, returned by [crate::Diagnostic::code].
+//!
+//! Unlike messages, codes do not change between releases, so they are safe to match
+//! when ignoring warnings or overriding the severity of diagnostics.
+
+/// A request can't be resolved.
+pub const MODULE_NOT_FOUND: &str = "ModuleNotFoundError";
+/// A loader failed to process a module.
+pub const MODULE_BUILD_ERROR: &str = "ModuleBuildError";
+/// A module can't be parsed.
+pub const MODULE_PARSE_ERROR: &str = "ModuleParseError";
+/// A module is parsed, but with a problem worth reporting.
+pub const MODULE_PARSE_WARNING: &str = "ModuleParseWarning";
+/// An empty request, e.g. `import ""`.
+pub const EMPTY_DEPENDENCY: &str = "EmptyDependencyError";
+/// A magic comment, e.g. `/* webpackChunkName: 1 */`, has a value of the wrong type.
+pub const COMMENT_COMPILATION_WARNING: &str = "CommentCompilationWarning";
+/// A CSS `@import` or `url()` request starts with the deprecated `~`.
+pub const CSS_TILDE_DEPRECATION_WARNING: &str = "CssTildeDeprecationWarning";
+/// An imported name is not exported by the imported module.
+pub const EXPORT_NOT_FOUND: &str = "ExportNotFoundError";
+/// A synchronous WebAssembly module imports a name which is not exported by the imported module.
+pub const WEBASSEMBLY_IMPORT_ERROR: &str = "WebAssemblyImportError";
+/// A synchronous WebAssembly module uses a feature which can't be linked, e.g. `i64` in a signature.
+pub const UNSUPPORTED_WEBASSEMBLY_FEATURE: &str = "UnsupportedWebAssemblyFeatureError";
+/// A synchronous WebAssembly module is in an initial chunk, where it can't be compiled asynchronously.
+pub const WEBASSEMBLY_IN_INITIAL_CHUNK: &str = "WebAssemblyInInitialChunkError";
+/// An external is loaded by `import()`, which the target environment doesn't support.
+pub const UNSUPPORTED_DYNAMIC_IMPORT: &str = "UnsupportedDynamicImportWarning";
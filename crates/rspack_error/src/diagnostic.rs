@@ -1,9 +1,14 @@
-use std::{fmt, ops::Deref, sync::Arc};
+use std::{
+  fmt,
+  ops::Deref,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use miette::{GraphicalTheme, IntoDiagnostic, MietteDiagnostic};
 use rspack_identifier::Identifier;
 
-use crate::{graphical::GraphicalReportHandler, Error};
+use crate::{graphical::GraphicalReportHandler, miette_helpers::WithSeverity, Error};
 
 #[derive(Debug, Clone, Default, Copy, PartialEq, Eq, Hash)]
 pub enum RspackSeverity {
@@ -117,6 +122,21 @@ impl Diagnostic {
     self.0.severity().unwrap_or_default().into()
   }
 
+  /// The stable code of the diagnostic, see [crate::codes].
+  pub fn code(&self) -> Option<String> {
+    self.0.code().map(|code| code.to_string())
+  }
+
+  pub fn with_severity(self, severity: Severity) -> Self {
+    if self.severity() == severity {
+      return self;
+    }
+    Self(
+      Error::new(WithSeverity::new(self.0, severity.into())).into(),
+      self.1,
+    )
+  }
+
   pub fn module_identifier(&self) -> Option<Identifier> {
     self.1.module_identifier
   }
@@ -125,11 +145,34 @@ impl Diagnostic {
     self.1.set_module_identifier(module_identifier);
     self
   }
+
+  /// The file the labels of the diagnostic point into, e.g. the resource of the module.
+  pub fn file(&self) -> Option<&Path> {
+    self.1.file.as_deref()
+  }
+
+  pub fn with_file(mut self, file: Option<PathBuf>) -> Self {
+    self.1.file = file;
+    self
+  }
+
+  /// Whether the file is transformed by loaders before it's parsed, so the labels point into the
+  /// transformed source instead of the file on disk.
+  pub fn is_file_transformed(&self) -> bool {
+    self.1.file_transformed
+  }
+
+  pub fn with_file_transformed(mut self, file_transformed: bool) -> Self {
+    self.1.file_transformed = file_transformed;
+    self
+  }
 }
 
 #[derive(Debug, Default, Clone)]
 struct DiagnosticMeta {
   module_identifier: Option<Identifier>,
+  file: Option<PathBuf>,
+  file_transformed: bool,
 }
 
 impl DiagnosticMeta {
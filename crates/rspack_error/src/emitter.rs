@@ -7,6 +7,9 @@ use termcolor::{ColorChoice, StandardStream};
 
 use crate::Diagnostic;
 
+mod json;
+pub use json::*;
+
 pub trait FlushDiagnostic {
   fn flush_diagnostic(&mut self) {}
 }
@@ -0,0 +1,266 @@
+use std::path::Path;
+
+use miette::{IntoDiagnostic, LabeledSpan, SourceCode, SourceSpan};
+use serde::Serialize;
+use serde_json::json;
+use url::Url;
+
+use super::DiagnosticDisplay;
+use crate::Diagnostic;
+
+/// `line` and `column` are 1-based, `column` counts characters.
+/// They are missing if the source of the file is not available.
+///
+/// Positions refer to the source the module is parsed from, which is the source transformed by
+/// the loaders of the module, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct JsonPosition {
+  pub offset: usize,
+  pub line: Option<usize>,
+  pub column: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JsonSpan {
+  pub label: Option<String>,
+  pub start: JsonPosition,
+  pub end: JsonPosition,
+}
+
+/// A [Diagnostic] serialized for tools, e.g. to annotate pull requests or underline errors in editors.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiagnostic {
+  /// `error` or `warning`
+  pub severity: String,
+  pub code: Option<String>,
+  /// Messages of the diagnostic and of the diagnostics it is caused by, one per line.
+  pub message: String,
+  pub file: Option<String>,
+  pub module_identifier: Option<String>,
+  pub spans: Vec<JsonSpan>,
+  pub help: Option<String>,
+}
+
+impl From<&Diagnostic> for JsonDiagnostic {
+  fn from(diagnostic: &Diagnostic) -> Self {
+    let chain = std::iter::successors(
+      Some(diagnostic.as_ref() as &dyn miette::Diagnostic),
+      |diagnostic| diagnostic.diagnostic_source(),
+    )
+    .collect::<Vec<_>>();
+    let message = chain
+      .iter()
+      .map(|diagnostic| diagnostic.to_string())
+      .filter(|message| !message.is_empty())
+      .collect::<Vec<_>>()
+      .join("\n");
+    let help = chain
+      .iter()
+      .filter_map(|diagnostic| diagnostic.help())
+      .map(|help| help.to_string().trim().to_string())
+      .filter(|help| !help.is_empty())
+      .collect::<Vec<_>>();
+    // Labels are usually attached to the innermost diagnostic, e.g. the syntax error of a module parse error
+    let spans = chain
+      .iter()
+      .find_map(|d| {
+        let labels = d.labels()?.collect::<Vec<_>>();
+        // The file on disk is only the parsed source if it's not transformed by loaders
+        let file = diagnostic
+          .file()
+          .filter(|_| !diagnostic.is_file_transformed());
+        Some(to_json_spans(&labels, d.source_code()?, file))
+      })
+      .unwrap_or_default();
+
+    Self {
+      severity: diagnostic.severity().to_string(),
+      code: diagnostic.code(),
+      message,
+      file: diagnostic
+        .file()
+        .map(|file| file.to_string_lossy().to_string()),
+      module_identifier: diagnostic.module_identifier().map(|i| i.to_string()),
+      spans,
+      help: (!help.is_empty()).then(|| help.join("\n")),
+    }
+  }
+}
+
+fn to_json_spans(
+  labels: &[LabeledSpan],
+  source: &dyn SourceCode,
+  file: Option<&Path>,
+) -> Vec<JsonSpan> {
+  // Read the whole source, diagnostics created without the source code, e.g.
+  // resolve errors, fall back to `file` on disk.
+  let contents = source
+    .read_span(&SourceSpan::new(0.into(), 0.into()), 0, usize::MAX)
+    .ok();
+  let mut text = contents
+    .as_ref()
+    .map(|c| c.data().to_vec())
+    .unwrap_or_default();
+  if text.is_empty()
+    && let Some(file) = file
+  {
+    text = std::fs::read(file).unwrap_or_default();
+  }
+
+  labels
+    .iter()
+    .map(|label| JsonSpan {
+      label: label.label().map(ToString::to_string),
+      start: to_json_position(&text, label.offset()),
+      end: to_json_position(&text, label.offset() + label.len()),
+    })
+    .collect()
+}
+
+fn to_json_position(text: &[u8], offset: usize) -> JsonPosition {
+  if text.is_empty() || offset > text.len() {
+    return JsonPosition {
+      offset,
+      line: None,
+      column: None,
+    };
+  }
+  let before = &text[..offset];
+  let line_start = before
+    .iter()
+    .rposition(|b| *b == b'\n')
+    .map_or(0, |i| i + 1);
+  JsonPosition {
+    offset,
+    line: Some(before.iter().filter(|b| **b == b'\n').count() + 1),
+    // Count the leading bytes of UTF-8 sequences
+    column: Some(
+      before[line_start..]
+        .iter()
+        .filter(|b| (**b & 0xC0) != 0x80)
+        .count()
+        + 1,
+    ),
+  }
+}
+
+/// `None` for relative paths, which can't be converted to `file` URIs.
+fn to_file_uri(file: &str) -> Option<String> {
+  Url::from_file_path(file).ok().map(String::from)
+}
+
+/// Convert diagnostics to a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log.
+pub fn to_sarif_log(diagnostics: &[JsonDiagnostic]) -> serde_json::Value {
+  let mut rules = diagnostics
+    .iter()
+    .filter_map(|d| d.code.as_deref())
+    .collect::<Vec<_>>();
+  rules.sort_unstable();
+  rules.dedup();
+
+  let results = diagnostics
+    .iter()
+    .map(|d| {
+      let mut text = d.message.clone();
+      if let Some(help) = &d.help {
+        text.push('\n');
+        text.push_str(help);
+      }
+      let mut location = json!({});
+      if let Some(uri) = d.file.as_deref().and_then(to_file_uri) {
+        let mut region = json!({});
+        if let Some(span) = d.spans.first() {
+          region["byteOffset"] = json!(span.start.offset);
+          region["byteLength"] = json!(span.end.offset - span.start.offset);
+          if let (Some(line), Some(column)) = (span.start.line, span.start.column) {
+            region["startLine"] = json!(line);
+            region["startColumn"] = json!(column);
+          }
+          if let (Some(line), Some(column)) = (span.end.line, span.end.column) {
+            region["endLine"] = json!(line);
+            region["endColumn"] = json!(column);
+          }
+        }
+        location["physicalLocation"] = json!({
+          "artifactLocation": { "uri": uri },
+          "region": region,
+        });
+      }
+      if let Some(module_identifier) = &d.module_identifier {
+        location["logicalLocations"] = json!([{
+          "fullyQualifiedName": module_identifier,
+          "kind": "module",
+        }]);
+      }
+
+      let mut result = json!({
+        "level": d.severity,
+        "message": { "text": text },
+      });
+      if let Some(code) = &d.code {
+        result["ruleId"] = json!(code);
+      }
+      if location.as_object().is_some_and(|l| !l.is_empty()) {
+        result["locations"] = json!([location]);
+      }
+      result
+    })
+    .collect::<Vec<_>>();
+
+  json!({
+    "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+    "version": "2.1.0",
+    "runs": [{
+      "tool": {
+        "driver": {
+          "name": "rspack",
+          "informationUri": "https://www.rspack.dev",
+          "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+        }
+      },
+      "results": results,
+      "columnKind": "unicodeCodePoints",
+    }]
+  })
+}
+
+/// Emit diagnostics as a JSON array of [JsonDiagnostic].
+#[derive(Debug, Default, Clone)]
+pub struct JsonDiagnosticDisplay;
+
+impl DiagnosticDisplay for JsonDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let diagnostics = diagnostics.map(JsonDiagnostic::from).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&diagnostics).into_diagnostic()
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    serde_json::to_string_pretty(&JsonDiagnostic::from(diagnostic)).into_diagnostic()
+  }
+}
+
+/// Emit diagnostics as a SARIF log, e.g. for code scanning in CI.
+#[derive(Debug, Default, Clone)]
+pub struct SarifDiagnosticDisplay;
+
+impl DiagnosticDisplay for SarifDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let diagnostics = diagnostics.map(JsonDiagnostic::from).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&to_sarif_log(&diagnostics)).into_diagnostic()
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    self.emit_batch_diagnostic(std::iter::once(diagnostic))
+  }
+}
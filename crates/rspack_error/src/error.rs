@@ -54,9 +54,18 @@ pub struct TraceableError {
   label: SourceSpan,
   help: Option<String>,
   url: Option<String>,
+  code: Option<String>,
 }
 
 impl Diagnostic for TraceableError {
+  fn code(&self) -> Option<Box<dyn Display + '_>> {
+    self
+      .code
+      .as_ref()
+      .map(Box::new)
+      .map(|c| c as Box<dyn Display>)
+  }
+
   fn severity(&self) -> Option<Severity> {
     Some(self.severity)
   }
@@ -115,6 +124,12 @@ impl TraceableError {
     self
   }
 
+  /// See [crate::codes] for the codes of the builtin diagnostics.
+  pub fn with_code(mut self, code: impl Into<String>) -> Self {
+    self.code = Some(code.into());
+    self
+  }
+
   pub fn from_source_file(
     source_file: &SourceFile,
     start: usize,
@@ -134,6 +149,7 @@ impl TraceableError {
       label: SourceSpan::new(start.into(), end.saturating_sub(start).into()),
       help: None,
       url: None,
+      code: None,
     }
   }
 
@@ -153,6 +169,7 @@ impl TraceableError {
       label: SourceSpan::new(start.into(), end.saturating_sub(start).into()),
       help: None,
       url: None,
+      code: None,
     }
   }
 
@@ -166,6 +183,7 @@ impl TraceableError {
       label: SourceSpan::new(start.into(), end.saturating_sub(start).into()),
       help: None,
       url: None,
+      code: None,
     }
   }
 
@@ -11,6 +11,7 @@ pub use catch_unwind::*;
 pub use diagnostic::*;
 pub use error::*;
 pub use ext::*;
+pub mod codes;
 pub mod emitter;
 
 mod macros;
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use miette::Diagnostic;
 use once_cell::sync::OnceCell;
@@ -91,3 +91,51 @@ impl miette::Diagnostic for WithHelp {
     self.err.diagnostic_source()
   }
 }
+
+/// Wrap diagnostic with another severity.
+#[derive(Debug, Error)]
+#[error("{err}")]
+pub(crate) struct WithSeverity {
+  err: Arc<Error>,
+  severity: miette::Severity,
+}
+
+impl WithSeverity {
+  pub(crate) fn new(err: Arc<Error>, severity: miette::Severity) -> Self {
+    Self { err, severity }
+  }
+}
+
+impl miette::Diagnostic for WithSeverity {
+  fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.err.code()
+  }
+
+  fn severity(&self) -> Option<miette::Severity> {
+    Some(self.severity)
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.err.help()
+  }
+
+  fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.err.url()
+  }
+
+  fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+    self.err.source_code()
+  }
+
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+    self.err.labels()
+  }
+
+  fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+    self.err.related()
+  }
+
+  fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+    self.err.diagnostic_source()
+  }
+}
@@ -3,8 +3,9 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use rspack_error::error;
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
 
@@ -14,12 +15,14 @@ pub enum HashFunction {
   MD4,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      _ => Err(error!("Unsupported hash function: {value}")),
     }
   }
 }
@@ -27,13 +30,17 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      _ => Err(error!("Unsupported hash digest: {value}")),
     }
   }
 }
@@ -128,6 +135,7 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
     };
     Self { encoded }
   }
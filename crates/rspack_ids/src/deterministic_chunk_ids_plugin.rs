@@ -4,6 +4,7 @@ use rspack_core::Plugin;
 
 use crate::id_helpers::{
   assign_deterministic_ids, compare_chunks_natural, get_full_chunk_name, get_used_chunk_ids,
+  revive_chunk_ids,
 };
 
 #[derive(Debug, Default)]
@@ -23,6 +24,7 @@ impl DeterministicChunkIdsPlugin {
 
 impl Plugin for DeterministicChunkIdsPlugin {
   fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
+    revive_chunk_ids(compilation);
     let mut used_ids = get_used_chunk_ids(compilation);
     let used_ids_len = used_ids.len();
 
@@ -0,0 +1,84 @@
+use std::hash::Hasher;
+
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+use crate::id_helpers::{
+  compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
+  get_used_module_ids_and_modules, revive_module_ids,
+};
+
+#[derive(Debug)]
+pub struct HashedModuleIdsPluginOptions {
+  /// Defaults to `options.context`
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashDigest::Base64,
+      hash_digest_length: 4,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedModuleIdsPlugin"
+  }
+
+  fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/HashedModuleIdsPlugin.js
+    revive_module_ids(compilation);
+    let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &mut compilation.chunk_graph;
+    let context = self
+      .options
+      .context
+      .as_deref()
+      .unwrap_or_else(|| compilation.options.context.as_str());
+
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| {
+      compare_modules_by_pre_order_index_or_identifier(module_graph, a, b)
+    });
+
+    for module in modules {
+      let ident = get_full_module_name(module, context);
+      let mut hasher = RspackHash::new(&self.options.hash_function);
+      hasher.write(ident.as_bytes());
+      let hash_id = hasher.digest(&self.options.hash_digest);
+      let hash_id = hash_id.encoded();
+      let mut len = self.options.hash_digest_length.min(hash_id.len());
+      while len < hash_id.len() && used_ids.contains(&hash_id[..len]) {
+        len += 1;
+      }
+      let module_id = hash_id[..len].to_string();
+      chunk_graph.set_module_id(module.identifier(), module_id.clone());
+      used_ids.insert(module_id);
+    }
+    Ok(())
+  }
+}
@@ -13,8 +13,8 @@ use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  compare_runtime, BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, ModuleGraph,
-  ModuleIdentifier,
+  compare_runtime, get_chunk_sources, get_module_record_key, BoxModule, Chunk, ChunkGraph,
+  ChunkUkey, Compilation, ModuleGraph, ModuleIdentifier,
 };
 use rspack_util::{
   comparators::{compare_ids, compare_numbers},
@@ -32,12 +32,7 @@ pub fn get_used_module_ids_and_modules(
   let mut modules = vec![];
   let mut used_ids = HashSet::new();
 
-  // TODO: currently we don't have logic of compilation.usedModuleIds
-  //   if (compilation.usedModuleIds) {
-  //     for (const id of compilation.usedModuleIds) {
-  //         usedIds.add(id + "");
-  //     }
-  //   }
+  used_ids.extend(compilation.used_module_ids.iter().cloned());
 
   compilation
     .module_graph
@@ -58,6 +53,42 @@ pub fn get_used_module_ids_and_modules(
   (used_ids, modules)
 }
 
+/// Reuse the module ids of the records before assigning new ones, aligned with
+/// `reviveModules` of webpack's RecordIdsPlugin.
+pub fn revive_module_ids(compilation: &mut Compilation) {
+  let Some(records) = compilation.input_records.clone() else {
+    return;
+  };
+  let context = compilation.options.context.as_str();
+  let chunk_graph = &mut compilation.chunk_graph;
+
+  let mut used_ids = HashSet::new();
+  let mut modules = vec![];
+  for identifier in compilation.module_graph.modules().keys() {
+    if let Some(module_id) = chunk_graph.get_module_id(*identifier) {
+      used_ids.insert(module_id.clone());
+    } else if chunk_graph.get_number_of_module_chunks(*identifier) != 0 {
+      modules.push(*identifier);
+    }
+  }
+  modules.sort_unstable();
+
+  for identifier in modules {
+    if let Some(id) = records
+      .modules
+      .by_identifier
+      .get(&get_module_record_key(context, &identifier))
+      && used_ids.insert(id.clone())
+    {
+      chunk_graph.set_module_id(identifier, id.clone());
+    }
+  }
+
+  compilation
+    .used_module_ids
+    .extend(records.modules.used_ids.iter().cloned());
+}
+
 pub fn get_short_module_name(module: &BoxModule, context: &str) -> String {
   let lib_ident = module.lib_ident(rspack_core::LibIdentOptions { context });
   if let Some(lib_ident) = lib_ident {
@@ -457,6 +488,49 @@ pub fn get_used_chunk_ids(compilation: &Compilation) -> HashSet<String> {
   used_ids
 }
 
+/// Reuse the chunk ids of the records before assigning new ones, aligned with
+/// `reviveChunks` of webpack's RecordIdsPlugin.
+pub fn revive_chunk_ids(compilation: &mut Compilation) {
+  let Some(records) = compilation.input_records.clone() else {
+    return;
+  };
+
+  let mut used_ids = get_used_chunk_ids(compilation);
+  let mut revived = vec![];
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.id.is_none())
+    .collect::<Vec<_>>();
+  chunks.sort_unstable_by_key(|chunk| chunk.ukey);
+  for chunk in chunks {
+    if let Some(name) = &chunk.name
+      && let Some(id) = records.chunks.by_name.get(name)
+      && used_ids.insert(id.clone())
+    {
+      revived.push((chunk.ukey, id.clone()));
+      continue;
+    }
+    if let Some(id) = get_chunk_sources(chunk, compilation)
+      .iter()
+      .find_map(|source| records.chunks.by_source.get(source))
+      && used_ids.insert(id.clone())
+    {
+      revived.push((chunk.ukey, id.clone()));
+    }
+  }
+
+  for (chunk_ukey, id) in revived {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+    chunk.id = Some(id.clone());
+    chunk.ids = vec![id];
+  }
+
+  compilation
+    .used_chunk_ids
+    .extend(records.chunks.used_ids.iter().cloned());
+}
+
 pub fn assign_ascending_chunk_ids(chunks: &[ChunkUkey], compilation: &mut Compilation) {
   let used_ids = get_used_chunk_ids(compilation);
 
@@ -522,10 +596,13 @@ pub fn compare_chunks_natural(
   a: &Chunk,
   b: &Chunk,
 ) -> Ordering {
-  let name_ordering = compare_ids(
-    &a.name.clone().unwrap_or_default(),
-    &b.name.clone().unwrap_or_default(),
-  );
+  // Like `compareSelect` of webpack, chunks with a name come before the unnamed ones
+  let name_ordering = match (&a.name, &b.name) {
+    (Some(a_name), Some(b_name)) => compare_ids(a_name, b_name),
+    (Some(_), None) => Ordering::Less,
+    (None, Some(_)) => Ordering::Greater,
+    (None, None) => Ordering::Equal,
+  };
   if name_ordering != Ordering::Equal {
     return name_ordering;
   }
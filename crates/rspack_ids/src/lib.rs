@@ -1,3 +1,4 @@
+#![feature(let_chains)]
 #![feature(iter_intersperse)]
 mod deterministic_module_ids_plugin;
 pub use deterministic_module_ids_plugin::*;
@@ -10,3 +11,13 @@ mod stable_named_chunk_ids_plugin;
 pub use stable_named_chunk_ids_plugin::StableNamedChunkIdsPlugin;
 mod deterministic_chunk_ids_plugin;
 pub use deterministic_chunk_ids_plugin::DeterministicChunkIdsPlugin;
+mod natural_module_ids_plugin;
+pub use natural_module_ids_plugin::NaturalModuleIdsPlugin;
+mod natural_chunk_ids_plugin;
+pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
+mod occurrence_module_ids_plugin;
+pub use occurrence_module_ids_plugin::OccurrenceModuleIdsPlugin;
+mod occurrence_chunk_ids_plugin;
+pub use occurrence_chunk_ids_plugin::OccurrenceChunkIdsPlugin;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::{HashedModuleIdsPlugin, HashedModuleIdsPluginOptions};
@@ -4,7 +4,7 @@ use rspack_core::{Chunk, Plugin};
 
 use crate::id_helpers::{
   assign_ascending_chunk_ids, assign_names_par, compare_chunks_natural, get_long_chunk_name,
-  get_short_chunk_name, get_used_chunk_ids,
+  get_short_chunk_name, get_used_chunk_ids, revive_chunk_ids,
 };
 
 #[derive(Debug)]
@@ -28,6 +28,7 @@ impl Plugin for NamedChunkIdsPlugin {
   }
 
   fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
+    revive_chunk_ids(compilation);
     let mut used_ids = get_used_chunk_ids(compilation);
     let chunk_graph = &compilation.chunk_graph;
     let module_graph = &compilation.module_graph;
@@ -40,9 +41,13 @@ impl Plugin for NamedChunkIdsPlugin {
       .chunk_by_ukey
       .values_mut()
       .map(|chunk| {
-        if let Some(name) = &chunk.name {
+        // keep the ids revived from the records
+        if chunk.id.is_none()
+          && let Some(name) = &chunk.name
+        {
           chunk.id = Some(name.clone());
           chunk.ids = vec![name.clone()];
+          used_ids.insert(name.clone());
         }
         chunk
       })
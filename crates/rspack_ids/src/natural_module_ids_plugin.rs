@@ -0,0 +1,32 @@
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{
+  assign_ascending_module_ids, compare_modules_by_pre_order_index_or_identifier,
+  get_used_module_ids_and_modules, revive_module_ids,
+};
+
+#[derive(Debug, Default)]
+pub struct NaturalModuleIdsPlugin;
+
+impl Plugin for NaturalModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NaturalModuleIdsPlugin"
+  }
+
+  fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/NaturalModuleIdsPlugin.js
+    revive_module_ids(compilation);
+    let (used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+    let module_graph = &compilation.module_graph;
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| {
+      compare_modules_by_pre_order_index_or_identifier(module_graph, a, b)
+    });
+    assign_ascending_module_ids(&used_ids, modules, &mut compilation.chunk_graph);
+    Ok(())
+  }
+}
@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+
+use rspack_core::{ChunkUkey, Compilation, Plugin};
+use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural, revive_chunk_ids};
+
+/// Gives the shortest ids to the chunks which are referenced most often,
+/// used by `optimization.chunkIds: "size"`.
+#[derive(Debug, Default)]
+pub struct OccurrenceChunkIdsPlugin {
+  pub prioritise_initial: bool,
+}
+
+impl OccurrenceChunkIdsPlugin {
+  pub fn new(prioritise_initial: bool) -> Self {
+    Self { prioritise_initial }
+  }
+}
+
+impl Plugin for OccurrenceChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceChunkIdsPlugin"
+  }
+
+  fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/OccurrenceChunkIdsPlugin.js
+    revive_chunk_ids(compilation);
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+
+    let occurs_in_initial_chunks_map: HashMap<ChunkUkey, usize> = compilation
+      .chunk_by_ukey
+      .values()
+      .map(|chunk| {
+        let occurs = chunk
+          .groups
+          .iter()
+          .flat_map(|group| {
+            compilation
+              .chunk_group_by_ukey
+              .expect_get(group)
+              .parents_iterable()
+          })
+          .filter(|parent| {
+            compilation
+              .chunk_group_by_ukey
+              .expect_get(parent)
+              .is_initial()
+          })
+          .count();
+        (chunk.ukey, occurs)
+      })
+      .collect();
+
+    let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| {
+      if self.prioritise_initial {
+        let a_entry = occurs_in_initial_chunks_map[&a.ukey];
+        let b_entry = occurs_in_initial_chunks_map[&b.ukey];
+        match b_entry.cmp(&a_entry) {
+          Ordering::Equal => {}
+          ordering => return ordering,
+        }
+      }
+      match b.groups.len().cmp(&a.groups.len()) {
+        Ordering::Equal => compare_chunks_natural(chunk_graph, module_graph, a, b),
+        ordering => ordering,
+      }
+    });
+    let chunks = chunks
+      .into_iter()
+      .map(|chunk| chunk.ukey)
+      .collect::<Vec<_>>();
+
+    assign_ascending_chunk_ids(&chunks, compilation);
+    Ok(())
+  }
+}
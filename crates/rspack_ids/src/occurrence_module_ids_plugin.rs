@@ -0,0 +1,140 @@
+use std::cmp::Ordering;
+
+use rspack_core::{Compilation, ModuleIdentifier, Plugin};
+use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{
+  assign_ascending_module_ids, compare_modules_by_pre_order_index_or_identifier,
+  get_used_module_ids_and_modules, revive_module_ids,
+};
+
+/// Gives the shortest ids to the modules which are referenced most often,
+/// used by `optimization.moduleIds: "size"`.
+#[derive(Debug, Default)]
+pub struct OccurrenceModuleIdsPlugin {
+  pub prioritise_initial: bool,
+}
+
+impl OccurrenceModuleIdsPlugin {
+  pub fn new(prioritise_initial: bool) -> Self {
+    Self { prioritise_initial }
+  }
+}
+
+impl Plugin for OccurrenceModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceModuleIdsPlugin"
+  }
+
+  fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/OccurrenceModuleIdsPlugin.js
+    revive_module_ids(compilation);
+    let (used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+
+    let mut initial_chunk_count_map: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    let mut entry_count_map: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    for module in &modules {
+      let mut initial = 0;
+      let mut entry = 0;
+      for chunk in chunk_graph.get_module_chunks(*module) {
+        if compilation
+          .chunk_by_ukey
+          .expect_get(chunk)
+          .can_be_initial(&compilation.chunk_group_by_ukey)
+        {
+          initial += 1;
+        }
+        if chunk_graph
+          .get_chunk_entry_modules_with_chunk_group_iterable(chunk)
+          .contains_key(module)
+        {
+          entry += 1;
+        }
+      }
+      initial_chunk_count_map.insert(*module, initial);
+      entry_count_map.insert(*module, entry);
+    }
+
+    let count_occurs_in_entry = |module: &ModuleIdentifier| -> usize {
+      module_graph
+        .get_incoming_connections_by_origin_module(module)
+        .into_iter()
+        .filter_map(|(origin_module, connections)| {
+          let origin_module = origin_module?;
+          connections
+            .iter()
+            .any(|c| c.is_target_active(module_graph, None))
+            .then(|| {
+              initial_chunk_count_map
+                .get(&origin_module)
+                .copied()
+                .unwrap_or_default()
+            })
+        })
+        .sum()
+    };
+
+    let count_occurs = |module: &ModuleIdentifier| -> usize {
+      module_graph
+        .get_incoming_connections_by_origin_module(module)
+        .into_iter()
+        .filter_map(|(origin_module, connections)| {
+          let origin_module = origin_module?;
+          let chunk_modules = chunk_graph.get_number_of_module_chunks(origin_module);
+          let active = connections
+            .iter()
+            .filter(|c| c.is_target_active(module_graph, None))
+            .count();
+          Some(active * chunk_modules)
+        })
+        .sum()
+    };
+
+    let mut occurs_in_initial_chunks_map: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    if self.prioritise_initial {
+      for module in &modules {
+        occurs_in_initial_chunks_map.insert(
+          *module,
+          count_occurs_in_entry(module) + initial_chunk_count_map[module] + entry_count_map[module],
+        );
+      }
+    }
+
+    let mut occurs_in_all_chunks_map: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    for module in &modules {
+      occurs_in_all_chunks_map.insert(
+        *module,
+        count_occurs(module)
+          + chunk_graph.get_number_of_module_chunks(*module)
+          + entry_count_map[module],
+      );
+    }
+
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| {
+      if self.prioritise_initial {
+        let a_entry = occurs_in_initial_chunks_map[&a.identifier()];
+        let b_entry = occurs_in_initial_chunks_map[&b.identifier()];
+        match b_entry.cmp(&a_entry) {
+          Ordering::Equal => {}
+          ordering => return ordering,
+        }
+      }
+      let a_occurs = occurs_in_all_chunks_map[&a.identifier()];
+      let b_occurs = occurs_in_all_chunks_map[&b.identifier()];
+      match b_occurs.cmp(&a_occurs) {
+        Ordering::Equal => compare_modules_by_pre_order_index_or_identifier(module_graph, a, b),
+        ordering => ordering,
+      }
+    });
+
+    assign_ascending_module_ids(&used_ids, modules, &mut compilation.chunk_graph);
+    Ok(())
+  }
+}
@@ -3,7 +3,8 @@ use rspack_core::{ChunkUkey, Plugin};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::id_helpers::{
-  assign_ascending_chunk_ids, get_short_module_name, request_to_id, shorten_long_string,
+  assign_ascending_chunk_ids, get_short_module_name, get_used_chunk_ids, request_to_id,
+  revive_chunk_ids, shorten_long_string,
 };
 
 #[derive(Debug)]
@@ -24,6 +25,7 @@ impl StableNamedChunkIdsPlugin {
 impl Plugin for StableNamedChunkIdsPlugin {
   fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
     use rayon::prelude::*;
+    revive_chunk_ids(compilation);
     // code_splitting_chunk means chunks generated in code splitting
     let code_splitting_chunk_to_root_module = compilation
       .chunk_graph
@@ -43,6 +45,10 @@ impl Plugin for StableNamedChunkIdsPlugin {
       .chunk_by_ukey
       .values_mut()
       .filter_map(|chunk| {
+        // Keep the ids revived from the records
+        if chunk.id.is_some() {
+          return None;
+        }
         // If the chunk has a name already, use it as the id
         if let Some(name) = &chunk.name {
           // Initial chunk create in Code Splitting definitely has a name
@@ -86,6 +92,7 @@ impl Plugin for StableNamedChunkIdsPlugin {
       })
       .collect::<Vec<_>>();
 
+    let mut used_ids = get_used_chunk_ids(compilation);
     let name_to_chunks: DashMap<String, FxHashSet<ChunkUkey>> = Default::default();
 
     let chunks_has_no_name = chunks_to_be_named
@@ -147,7 +154,7 @@ impl Plugin for StableNamedChunkIdsPlugin {
       .collect::<Vec<_>>();
 
     name_to_chunks.into_iter().for_each(|(name, chunks)| {
-      if chunks.len() == 1 {
+      if chunks.len() == 1 && used_ids.insert(name.clone()) {
         // The name has no conflicts to other chunks, we just use it as the chunk id
         let chunk = chunks.into_iter().next().expect("Must have a chunk");
         chunk.as_mut(&mut compilation.chunk_by_ukey).id = Some(name.clone());
@@ -161,12 +168,20 @@ impl Plugin for StableNamedChunkIdsPlugin {
           let b_module_ids = compilation.chunk_graph.get_chunk_module_identifiers(b);
           a_module_ids.len().cmp(&b_module_ids.len())
         });
-        chunks.iter().enumerate().for_each(|(index, chunk)| {
+        // Names taken by the ids revived from the records are skipped
+        let mut index = 0;
+        chunks.iter().for_each(|chunk| {
           let chunk = chunk.as_mut(&mut compilation.chunk_by_ukey);
-          let name = if index == 0 {
-            name.to_string()
-          } else {
-            format!("{name}{index}")
+          let name = loop {
+            let name = if index == 0 {
+              name.to_string()
+            } else {
+              format!("{name}{index}")
+            };
+            index += 1;
+            if used_ids.insert(name.clone()) {
+              break name;
+            }
           };
           chunk.id = Some(name.clone());
           chunk.ids.push(name);
@@ -126,6 +126,7 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
         inline_sources_content: true,
         emit_columns: matches!(source_map_kind, SourceMapKind::SourceMap),
         names: Default::default(),
+        keep_mappings: false,
       },
       inline_script: Some(false),
       keep_comments: Some(true),
@@ -148,7 +149,7 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
       loader_context.additional_data.insert(codegen_options);
       loader_context.content = Some("".to_owned().into())
     } else {
-      let TransformOutput { code, map } = ast::stringify(&ast, codegen_options)?;
+      let TransformOutput { code, map, .. } = ast::stringify(&ast, codegen_options)?;
       loader_context.content = Some(code.into());
       loader_context.source_map = map
         .map(|m| SourceMap::from_json(&m))
@@ -43,6 +43,9 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
       webassembly_module_filename: rspack_core::Filename::from_str("").expect("TODO:"),
       chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
       cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
+      charset: true,
+      chunk_load_timeout: 120000,
+      chunk_load_retry: Default::default(),
       unique_name: Default::default(),
       chunk_loading: rspack_core::ChunkLoading::Enable(rspack_core::ChunkLoadingType::Jsonp),
       chunk_loading_global: "webpackChunkwebpack".to_string(),
@@ -71,6 +74,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
       worker_wasm_loading: rspack_core::WasmLoading::Disable,
       worker_public_path: String::new(),
       script_type: String::from("false"),
+      environment: Default::default(),
     },
     target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
     resolve: rspack_core::Resolve::default(),
@@ -82,6 +86,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
     snapshot: Default::default(),
     experiments: Default::default(),
     node: Default::default(),
+    performance: Default::default(),
     optimization: rspack_core::Optimization {
       remove_available_modules: false,
       side_effects: SideEffectOption::False,
@@ -92,10 +97,14 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
       concatenate_modules: Default::default(),
     },
     profile: false,
+    records_input_path: None,
+    records_output_path: None,
+    ignore_warnings: Default::default(),
+    diagnostic_severity: Default::default(),
   };
 
   let (plugin_driver, compiler_options) =
-    PluginDriver::new(compiler_options, vec![], Default::default());
+    PluginDriver::new(compiler_options, vec![]).expect("should create plugin driver");
 
   let (result, _) = run_loaders(
     &[Arc::new(SwcLoader::new(options)) as Arc<dyn Loader<LoaderRunnerContext>>],
@@ -117,6 +126,9 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           webassembly_module_filename: rspack_core::Filename::from_str("").expect("TODO:"),
           chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
           cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
+          charset: true,
+          chunk_load_timeout: 120000,
+          chunk_load_retry: Default::default(),
           unique_name: Default::default(),
           chunk_loading: rspack_core::ChunkLoading::Enable(rspack_core::ChunkLoadingType::Jsonp),
           chunk_loading_global: "webpackChunkwebpack".to_string(),
@@ -145,6 +157,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           worker_wasm_loading: rspack_core::WasmLoading::Disable,
           worker_public_path: String::new(),
           script_type: String::from("false"),
+          environment: Default::default(),
         },
         target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
         resolve: rspack_core::Resolve::default(),
@@ -156,6 +169,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         snapshot: Default::default(),
         experiments: Default::default(),
         node: Default::default(),
+        performance: Default::default(),
         optimization: rspack_core::Optimization {
           remove_available_modules: false,
           side_effects: SideEffectOption::False,
@@ -166,6 +180,10 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           concatenate_modules: Default::default(),
         },
         profile: false,
+        records_input_path: None,
+        records_output_path: None,
+        ignore_warnings: Default::default(),
+        diagnostic_severity: Default::default(),
       }),
       resolver_factory: Default::default(),
       module: "".into(),
@@ -178,6 +196,8 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
       build_time_execution_queue: Default::default(),
       plugin_driver,
       cache: Arc::new(rspack_core::cache::Cache::new(compiler_options)),
+      logging: Default::default(),
+      emitted_assets: Default::default(),
     },
   )
   .await
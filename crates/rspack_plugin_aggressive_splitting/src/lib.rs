@@ -0,0 +1,304 @@
+#![feature(let_chains)]
+
+use async_trait::async_trait;
+use rspack_core::{
+  compare_chunks_with_graph, get_module_record_key, AggressiveSplitRecord, BoxModule, Chunk,
+  ChunkKind, ChunkSizeOptions, ChunkUkey, Compilation, Logger, OptimizeChunksArgs, Plugin,
+  PluginContext, PluginOptimizeChunksOutput,
+};
+use rspack_identifier::{Identifier, IdentifierMap};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+#[derive(Debug, Clone)]
+pub struct AggressiveSplittingPluginOptions {
+  // Chunks are split into parts bigger than this size.
+  pub min_size: f64,
+  // Chunks bigger than this size are split.
+  pub max_size: f64,
+  // Constant overhead for a chunk.
+  pub chunk_overhead: f64,
+  // Multiplicator for initial chunks.
+  pub entry_chunk_multiplicator: f64,
+}
+
+impl Default for AggressiveSplittingPluginOptions {
+  fn default() -> Self {
+    Self {
+      min_size: 30.0 * 1024.0,
+      max_size: 50.0 * 1024.0,
+      chunk_overhead: 0.0,
+      entry_chunk_multiplicator: 1.0,
+    }
+  }
+}
+
+/// Splits chunks into evenly sized parts, which are friendly to HTTP/2 and long-term caching.
+/// The splits are stored in records, so subsequent builds reproduce the same partition.
+#[derive(Debug)]
+pub struct AggressiveSplittingPlugin {
+  options: AggressiveSplittingPluginOptions,
+}
+
+impl AggressiveSplittingPlugin {
+  pub fn new(options: AggressiveSplittingPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+fn get_module_size(module: &BoxModule) -> f64 {
+  module
+    .source_types()
+    .iter()
+    .map(|source_type| module.size(source_type))
+    .sum()
+}
+
+#[derive(Debug, Default)]
+struct SplittingState {
+  name_to_module: HashMap<String, Identifier>,
+  module_to_name: IdentifierMap<String>,
+  /// Chunks created by, or already matching, a split. They are not split again.
+  from_aggressive_splitting: HashSet<ChunkUkey>,
+  chunk_split_data: HashMap<ChunkUkey, AggressiveSplitRecord>,
+}
+
+/// Move the modules of `split` into a new chunk. Return `true` if the split is applied.
+fn apply_split(
+  compilation: &mut Compilation,
+  state: &mut SplittingState,
+  split: &AggressiveSplitRecord,
+) -> bool {
+  let Some(selected_modules) = split
+    .modules
+    .iter()
+    .map(|name| state.name_to_module.get(name).copied())
+    .collect::<Option<Vec<_>>>()
+  else {
+    return false;
+  };
+
+  // Modules have changed since the split was recorded. Webpack compares the hash of the split
+  // chunk in `recordHash` and seals again when it differs, but chunk hashes are only known
+  // after `optimize_chunks` and there is no additional seal here, so the module names and
+  // their total size are validated instead
+  let size: f64 = selected_modules
+    .iter()
+    .filter_map(|module| compilation.module_graph.module_by_identifier(module))
+    .map(get_module_size)
+    .sum();
+  if size != split.size {
+    return false;
+  }
+
+  // Chunks containing all the selected modules
+  let Some(mut selected_chunks) = selected_modules
+    .iter()
+    .map(|module| compilation.chunk_graph.get_module_chunks(*module))
+    .fold(None, |acc: Option<HashSet<ChunkUkey>>, chunks| match acc {
+      Some(mut acc) => {
+        acc.retain(|chunk| chunks.contains(chunk));
+        Some(acc)
+      }
+      None => Some(chunks.iter().copied().collect()),
+    })
+    .map(|chunks| chunks.into_iter().collect::<Vec<_>>())
+  else {
+    return false;
+  };
+  if selected_chunks.is_empty() {
+    return false;
+  }
+
+  // Check if all modules are in the chunk already
+  if let [chunk] = selected_chunks[..]
+    && compilation.chunk_graph.get_number_of_chunk_modules(&chunk) == selected_modules.len()
+  {
+    if !state.from_aggressive_splitting.insert(chunk) {
+      return false;
+    }
+    state.chunk_split_data.insert(chunk, split.clone());
+    return true;
+  }
+
+  selected_chunks.sort_by(|a, b| {
+    compare_chunks_with_graph(&compilation.chunk_graph, &compilation.module_graph, a, b)
+  });
+
+  // Split the chunks into two parts
+  let mut new_chunk = Chunk::new(None, ChunkKind::Normal);
+  new_chunk
+    .chunk_reasons
+    .push("aggressive splitted".to_string());
+  compilation.chunk_graph.add_chunk(new_chunk.ukey);
+  for chunk_ukey in selected_chunks {
+    for module in &selected_modules {
+      compilation
+        .chunk_graph
+        .connect_chunk_and_module(new_chunk.ukey, *module);
+      compilation
+        .chunk_graph
+        .disconnect_chunk_and_module(&chunk_ukey, *module);
+    }
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+    chunk.split(&mut new_chunk, &mut compilation.chunk_group_by_ukey);
+    if let Some(name) = chunk.name.take()
+      && compilation.named_chunks.get(&name) == Some(&chunk_ukey)
+    {
+      compilation.named_chunks.remove(&name);
+    }
+  }
+
+  state.from_aggressive_splitting.insert(new_chunk.ukey);
+  state.chunk_split_data.insert(new_chunk.ukey, split.clone());
+  compilation.chunk_by_ukey.add(new_chunk);
+  true
+}
+
+#[async_trait]
+impl Plugin for AggressiveSplittingPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.AggressiveSplittingPlugin"
+  }
+
+  async fn optimize_chunks(
+    &self,
+    _ctx: PluginContext,
+    args: OptimizeChunksArgs<'_>,
+  ) -> PluginOptimizeChunksOutput {
+    let compilation = args.compilation;
+    let logger = compilation.get_logger(self.name());
+    let chunk_size_options = ChunkSizeOptions {
+      chunk_overhead: Some(self.options.chunk_overhead),
+      entry_chunk_multiplicator: Some(self.options.entry_chunk_multiplicator),
+    };
+
+    let mut state = SplittingState::default();
+    let context = compilation.options.context.as_str().to_string();
+    for module in compilation.module_graph.modules().keys() {
+      let name = get_module_record_key(&context, module);
+      state.name_to_module.insert(name.clone(), *module);
+      state.module_to_name.insert(*module, name);
+    }
+
+    let used_splits = compilation
+      .input_records
+      .as_ref()
+      .map(|records| records.aggressive_splits.clone())
+      .unwrap_or_default();
+
+    let mut new_splits_count = 0;
+    loop {
+      let mut changed = false;
+
+      // 1. try to restore the splits from records
+      for split in &used_splits {
+        if apply_split(compilation, &mut state, split) {
+          changed = true;
+        }
+      }
+
+      // 2. for any other chunk which is bigger than max_size, select modules to split out
+      let chunk_graph = &compilation.chunk_graph;
+      let module_graph = &compilation.module_graph;
+      let mut sorted_chunks = compilation
+        .chunk_by_ukey
+        .keys()
+        .copied()
+        .collect::<Vec<_>>();
+      sorted_chunks.sort_by(|a, b| {
+        let a_size = chunk_graph.get_chunk_modules_size(a, module_graph);
+        let b_size = chunk_graph.get_chunk_modules_size(b, module_graph);
+        b_size
+          .total_cmp(&a_size)
+          .then_with(|| {
+            chunk_graph
+              .get_number_of_chunk_modules(a)
+              .cmp(&chunk_graph.get_number_of_chunk_modules(b))
+          })
+          .then_with(|| compare_chunks_with_graph(chunk_graph, module_graph, a, b))
+      });
+
+      for chunk in sorted_chunks {
+        if state.from_aggressive_splitting.contains(&chunk) {
+          continue;
+        }
+        let chunk_graph = &compilation.chunk_graph;
+        let module_graph = &compilation.module_graph;
+        let size = chunk_graph.get_chunk_size(
+          &chunk,
+          &chunk_size_options,
+          &compilation.chunk_by_ukey,
+          &compilation.chunk_group_by_ukey,
+          module_graph,
+        );
+        if size <= self.options.max_size || chunk_graph.get_number_of_chunk_modules(&chunk) <= 1 {
+          continue;
+        }
+
+        let entry_modules = chunk_graph.get_chunk_entry_modules_with_chunk_group_iterable(&chunk);
+        let mut selected_modules = vec![];
+        let mut selected_modules_size = 0.0;
+        for module in chunk_graph.get_ordered_chunk_modules(&chunk, module_graph) {
+          if entry_modules.contains_key(&module.identifier()) {
+            continue;
+          }
+          let new_size = selected_modules_size + get_module_size(module);
+          if new_size > self.options.max_size && selected_modules_size >= self.options.min_size {
+            break;
+          }
+          selected_modules_size = new_size;
+          selected_modules.push(module.identifier());
+        }
+        if selected_modules.is_empty() {
+          continue;
+        }
+
+        let mut modules = selected_modules
+          .iter()
+          .map(|module| state.module_to_name[module].clone())
+          .collect::<Vec<_>>();
+        modules.sort();
+        let split = AggressiveSplitRecord {
+          modules,
+          size: selected_modules_size,
+        };
+        if apply_split(compilation, &mut state, &split) {
+          new_splits_count += 1;
+          changed = true;
+        }
+      }
+
+      if !changed {
+        break;
+      }
+    }
+
+    // 3. save the splits to records, the applied ones first, then the unused ones from history
+    let mut split_chunks = state
+      .chunk_split_data
+      .keys()
+      .filter(|chunk| compilation.chunk_by_ukey.contains(chunk))
+      .copied()
+      .collect::<Vec<_>>();
+    split_chunks.sort_by(|a, b| {
+      compare_chunks_with_graph(&compilation.chunk_graph, &compilation.module_graph, a, b)
+    });
+    let mut all_splits: Vec<AggressiveSplitRecord> = vec![];
+    for split in split_chunks
+      .iter()
+      .map(|chunk| &state.chunk_split_data[chunk])
+      .chain(used_splits.iter())
+    {
+      if !all_splits.contains(split) {
+        all_splits.push(split.clone());
+      }
+    }
+    logger.log(format!(
+      "{} chunks from aggressive splitting, {new_splits_count} new splits",
+      split_chunks.len()
+    ));
+    compilation.aggressive_splits = all_splits;
+
+    Ok(())
+  }
+}
@@ -44,6 +44,19 @@ pub(crate) static CSS_MODULE_SOURCE_TYPE_LIST: &[SourceType; 2] =
 pub(crate) static CSS_MODULE_EXPORTS_ONLY_SOURCE_TYPE_LIST: &[SourceType; 1] =
   &[SourceType::JavaScript];
 
+/// CSS modules accept their own updates, which swap the stylesheets, unless the exports have
+/// changed, then the update is passed to the importers that depend on the class names.
+const CSS_MODULES_HMR_CODE: &str = r#"var __rspack_css_locals__ = JSON.stringify(module.exports);
+module.hot.dispose(function(data) {
+  data.locals = __rspack_css_locals__;
+});
+if (module.hot.data && module.hot.data.locals && module.hot.data.locals !== __rspack_css_locals__) {
+  module.hot.invalidate();
+} else {
+  module.hot.accept();
+}
+"#;
+
 type CssExportsType = Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>>>;
 
 #[derive(Debug)]
@@ -268,12 +281,16 @@ impl ParserAndGenerator for CssParserAndGenerator {
       }
       SourceType::JavaScript => {
         let locals = if let Some(exports) = &self.exports {
-          css_modules_exports_to_string(
+          let mut locals = css_modules_exports_to_string(
             exports,
             module,
             generate_context.compilation,
             generate_context.runtime_requirements,
-          )?
+          )?;
+          if generate_context.compilation.options.dev_server.hot {
+            locals.push_str(CSS_MODULES_HMR_CODE);
+          }
+          locals
         } else if generate_context.compilation.options.dev_server.hot {
           "module.hot.accept();".to_string()
         } else {
@@ -290,6 +290,9 @@ impl Plugin for CssPlugin {
       runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_CSS_FILENAME);
       runtime_requirements_mut.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
       runtime_requirements_mut.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
+      if compilation.options.output.chunk_load_retry.is_enabled() {
+        runtime_requirements_mut.insert(RuntimeGlobals::CHUNK_LOAD_RETRY);
+      }
       compilation
         .add_runtime_module(chunk, Box::<CssLoadingRuntimeModule>::default())
         .await?;
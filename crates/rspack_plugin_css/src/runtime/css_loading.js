@@ -1,7 +1,7 @@
 var uniqueName = "webpack";
 // loadCssChunkData is unnecessary
 var loadingAttribute = "data-webpack-loading";
-var loadStylesheet = function(chunkId, url, done, hmr) {
+var loadStylesheet = function(chunkId, url, done, hmr$ATTEMPT_ARGUMENT$) {
 	var link,
 		needAttach,
 		key = "chunk-" + chunkId;
@@ -42,13 +42,13 @@ var loadStylesheet = function(chunkId, url, done, hmr) {
 		link.removeAttribute(loadingAttribute);
 		clearTimeout(timeout);
 		if (event && event.type != "load") link.parentNode.removeChild(link);
-		done(event);
+$RETRY$		done(event);
 		if (prev) return prev(event);
 	};
 	if (link.getAttribute(loadingAttribute)) {
 		var timeout = setTimeout(
 			onLinkComplete.bind(null, undefined, { type: "timeout", target: link }),
-			120000
+			$CHUNK_LOAD_TIMEOUT$
 		);
 		link.onerror = onLinkComplete.bind(null, link.onerror);
 		link.onload = onLinkComplete.bind(null, link.onload);
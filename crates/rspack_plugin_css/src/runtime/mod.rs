@@ -85,10 +85,39 @@ impl RuntimeModule for CssLoadingRuntimeModule {
         &stringify_chunks(&initial_chunk_ids_without_css, 0)
       )));
 
-      source.add(RawSource::from(include_str!("./css_loading.js").replace(
-        "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-        &compilation.options.output.cross_origin_loading.to_string(),
-      )));
+      let output = &compilation.options.output;
+      let (attempt_argument, retry) = if output.chunk_load_retry.is_enabled() {
+        (
+          ", attempt",
+          format!(
+            r#"		if (event && event.type != "load" && !hmr) {{
+			return {}.retry(url, attempt || 0, "css", function (nextUrl, nextAttempt) {{
+				loadStylesheet(chunkId, nextUrl, done, hmr, nextAttempt);
+			}}, function () {{
+				done(event);
+				if (prev) return prev(event);
+			}});
+		}}
+"#,
+            RuntimeGlobals::CHUNK_LOAD_RETRY
+          ),
+        )
+      } else {
+        ("", String::new())
+      };
+      source.add(RawSource::from(
+        include_str!("./css_loading.js")
+          .replace(
+            "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+            &output.cross_origin_loading.to_string(),
+          )
+          .replace("$ATTEMPT_ARGUMENT$", attempt_argument)
+          .replace(
+            "$CHUNK_LOAD_TIMEOUT$",
+            &output.chunk_load_timeout.to_string(),
+          )
+          .replace("$RETRY$", &retry),
+      ));
 
       if with_loading {
         let chunk_loading_global_expr = format!(
@@ -2,7 +2,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{BoxDependency, ModuleDependency, SpanExt};
 use rspack_error::miette::{diagnostic, Diagnostic, Severity};
-use rspack_error::DiagnosticExt;
+use rspack_error::{codes, DiagnosticExt};
 use swc_core::common::Span;
 use swc_core::css::ast::{
   AtRule, AtRuleName, Function, ImportHref, ImportPrelude, Stylesheet, Token, TokenAndSpan, Url,
@@ -52,6 +52,7 @@ fn replace_module_request_prefix(
   if IS_MODULE_REQUEST.is_match(&specifier) {
     diagnostics.push(
       diagnostic!(
+        code = codes::CSS_TILDE_DEPRECATION_WARNING,
         severity = Severity::Warning,
         "css: Deprecated '~'\n'@import' or 'url()' with a request starts with '~' is deprecated.",
       )
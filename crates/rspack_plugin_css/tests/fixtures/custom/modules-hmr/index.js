@@ -0,0 +1,3 @@
+import classes from './style.module.css'
+
+console.log(classes)
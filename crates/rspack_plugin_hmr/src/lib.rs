@@ -5,7 +5,7 @@ use std::{hash::Hash, ops::Sub};
 use async_trait::async_trait;
 use hot_module_replacement::HotModuleReplacementRuntimeModule;
 use rspack_core::{
-  collect_changed_modules,
+  collect_runtime_modules, get_module_hashes, get_module_record_key, get_runtime_key,
   rspack_sources::{RawSource, SourceExt},
   AdditionalChunkRuntimeRequirementsArgs, ApplyContext, AssetInfo, Chunk, ChunkKind,
   CompilationArgs, CompilationAsset, CompilationParams, CompilationRecords, CompilerOptions,
@@ -16,7 +16,7 @@ use rspack_core::{
 };
 use rspack_error::Result;
 use rspack_hash::RspackHash;
-use rspack_identifier::IdentifierSet;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 #[derive(Debug)]
@@ -92,7 +92,7 @@ impl Plugin for HotModuleReplacementPlugin {
     let Some(CompilationRecords {
       old_chunks,
       all_old_runtime,
-      old_all_modules,
+      old_records,
       old_runtime_modules,
       old_hash,
     }) = compilation.records.take()
@@ -115,34 +115,61 @@ impl Plugin for HotModuleReplacementPlugin {
       return Ok(());
     }
 
-    let (now_all_modules, now_runtime_modules) = collect_changed_modules(compilation);
+    let now_runtime_modules = collect_runtime_modules(compilation);
+    // The modules are compared with the records by their record keys and code generation hashes
+    let context = compilation.options.context.as_str();
+    let now_module_keys = compilation
+      .module_graph
+      .modules()
+      .keys()
+      .filter(|identifier| {
+        compilation
+          .chunk_graph
+          .get_module_id(**identifier)
+          .is_some()
+      })
+      .map(|identifier| {
+        (
+          *identifier,
+          (
+            get_module_record_key(context, identifier),
+            get_module_hashes(compilation, identifier),
+          ),
+        )
+      })
+      .collect::<IdentifierMap<_>>();
 
-    let mut updated_modules: IdentifierSet = Default::default();
     let mut updated_runtime_modules: IdentifierSet = Default::default();
     let mut completely_removed_modules: HashSet<String> = Default::default();
 
-    for (old_uri, (old_hash, old_module_id)) in &old_all_modules {
-      if let Some((now_hash, _)) = now_all_modules.get(old_uri) {
-        // updated
-        if now_hash != old_hash {
-          updated_modules.insert(*old_uri);
-        }
-      } else {
+    let now_keys = now_module_keys
+      .values()
+      .map(|(key, _)| key)
+      .collect::<HashSet<_>>();
+    for (old_key, old_module_id) in &old_records.modules.by_identifier {
+      if !now_keys.contains(old_key) {
         // deleted
         completely_removed_modules.insert(old_module_id.to_string());
       }
     }
-    for identifier in now_all_modules.keys() {
-      if !old_all_modules.contains_key(identifier) {
-        // added
-        updated_modules.insert(*identifier);
-      }
-    }
 
-    // println!(
-    //   "updated_modules: {:?}\n, remove modules {:?}",
-    //   updated_modules, completely_removed_modules
-    // );
+    // The module is compared in the runtime of the chunk, a module shared by several runtimes
+    // may be generated differently for each of them.
+    let is_module_updated = |module: &ModuleIdentifier, runtime: &RuntimeSpec| {
+      let Some((key, now_hashes)) = now_module_keys.get(module) else {
+        return false;
+      };
+      let runtime_key = get_runtime_key(runtime.clone());
+      let Some(now_hash) = now_hashes.get(&runtime_key) else {
+        return false;
+      };
+      // added to the runtime or updated
+      old_records
+        .module_hashes
+        .get(key)
+        .and_then(|old_hashes| old_hashes.get(&runtime_key))
+        != Some(now_hash)
+    };
 
     for (identifier, old_runtime_module_content) in &old_runtime_modules {
       if let Some(new_runtime_module_content) = now_runtime_modules.get(identifier) {
@@ -193,7 +220,8 @@ impl Plugin for HotModuleReplacementPlugin {
           .get_chunk_graph_chunk(&current_chunk.ukey)
           .modules
           .iter()
-          .filter_map(|module| updated_modules.contains(module).then_some(*module))
+          .filter(|module| is_module_updated(module, &current_chunk.runtime))
+          .copied()
           .collect::<Vec<_>>();
 
         new_runtime_modules = compilation
@@ -222,7 +250,10 @@ impl Plugin for HotModuleReplacementPlugin {
       if !new_modules.is_empty() || !new_runtime_modules.is_empty() {
         let mut hot_update_chunk = Chunk::new(None, ChunkKind::HotUpdate);
         hot_update_chunk.id = Some(chunk_id.to_string());
-        hot_update_chunk.runtime = new_runtime.clone();
+        // the modules are rendered with the code generated for the runtime of the chunk
+        hot_update_chunk.runtime = current_chunk
+          .map(|current_chunk| current_chunk.runtime.clone())
+          .unwrap_or_else(|| new_runtime.clone());
         let mut chunk_hash = RspackHash::from(&compilation.options.output);
         let ukey = hot_update_chunk.ukey;
         if let Some(current_chunk) = current_chunk {
@@ -62,6 +62,66 @@ impl FromStr for HtmlScriptLoading {
   }
 }
 
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum HtmlResourceHint {
+  Preload,
+  Prefetch,
+}
+
+impl FromStr for HtmlResourceHint {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.eq("preload") {
+      Ok(HtmlResourceHint::Preload)
+    } else if s.eq("prefetch") {
+      Ok(HtmlResourceHint::Prefetch)
+    } else {
+      Err(anyhow::Error::msg(
+        "resourceHint in html config only support 'preload' or 'prefetch'",
+      ))
+    }
+  }
+}
+
+impl HtmlResourceHint {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      HtmlResourceHint::Preload => "preload",
+      HtmlResourceHint::Prefetch => "prefetch",
+    }
+  }
+}
+
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HtmlSriMode {
+  /// only the tags injected into the html get an integrity attribute
+  #[default]
+  Initial,
+  /// chunks loaded at runtime are verified as well
+  All,
+}
+
+impl FromStr for HtmlSriMode {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.eq("initial") {
+      Ok(HtmlSriMode::Initial)
+    } else if s.eq("all") {
+      Ok(HtmlSriMode::All)
+    } else {
+      Err(anyhow::Error::msg(
+        "sriMode in html config only support 'initial' or 'all'",
+      ))
+    }
+  }
+}
+
 #[cfg_attr(feature = "testing", derive(JsonSchema))]
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -89,6 +149,12 @@ pub struct HtmlRspackPluginOptions {
   /// hash func that used in subsource integrity
   /// sha384, sha256 or sha512
   pub sri: Option<HtmlSriHashFunction>,
+  /// `initial` or `all`, `all` embeds the integrity of every async chunk
+  /// in the runtime and verifies the chunk when it is loaded
+  #[serde(default)]
+  pub sri_mode: HtmlSriMode,
+  /// `preload` or `prefetch` links injected for the async chunks of the entries
+  pub resource_hint: Option<HtmlResourceHint>,
   #[serde(default)]
   pub minify: bool,
   pub title: Option<String>,
@@ -121,6 +187,8 @@ impl Default for HtmlRspackPluginOptions {
       chunks: None,
       excluded_chunks: None,
       sri: None,
+      sri_mode: Default::default(),
+      resource_hint: None,
       minify: false,
       title: None,
       favicon: None,
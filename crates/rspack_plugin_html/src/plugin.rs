@@ -5,24 +5,33 @@ use std::{
   path::{Path, PathBuf},
 };
 
-use anyhow::Context;
+use anyhow::Context as AnyhowContext;
 use async_trait::async_trait;
 use dojang::dojang::Dojang;
+use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
   parse_to_url,
   rspack_sources::{RawSource, SourceExt},
-  CompilationAsset, Filename, PathData, Plugin,
+  BoxDependency, ChunkByUkey, ChunkGroup, CodeGenerationDataFilename, Compilation, CompilationArgs,
+  CompilationAsset, CompilationParams, Context, DependencyType, EntryDependency, EntryOptions,
+  Filename, MakeParam, PathData, Plugin, PluginCompilationHookOutput, PluginContext,
+  PluginMakeHookOutput, PluginRuntimeRequirementsInTreeOutput, RuntimeGlobals, RuntimeModuleExt,
+  RuntimeRequirementsInTreeArgs,
 };
-use rspack_error::AnyhowError;
+use rspack_error::{AnyhowError, Result};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use serde::Deserialize;
-use swc_html::visit::VisitMutWith;
+use swc_html::{ast::Document, visit::VisitMutWith};
 
 use crate::{
-  config::{HtmlInject, HtmlRspackPluginOptions},
+  config::{HtmlInject, HtmlRspackPluginOptions, HtmlSriMode},
   parser::HtmlCompiler,
-  sri::{add_sri, create_digest_from_asset},
-  visitors::asset::{AssetWriter, HTMLPluginTag},
+  sri::{add_sri, create_digest_from_asset, replace_sri_hash_placeholders, SriHashesRuntimeModule},
+  visitors::{
+    asset::{AssetWriter, HTMLPluginTag},
+    reference::{HtmlReference, HtmlReferenceKind, ReferenceCollector, ReferenceWriter},
+  },
 };
 
 #[derive(Deserialize, Debug, Default)]
@@ -34,6 +43,114 @@ impl HtmlRspackPlugin {
   pub fn new(config: HtmlRspackPluginOptions) -> HtmlRspackPlugin {
     HtmlRspackPlugin { config }
   }
+
+  /// Reads the template and renders the template parameters, returns the
+  /// content, the url of the template, the name used to compute the output
+  /// path and the context references in the template are resolved from.
+  fn read_template(
+    &self,
+    compilation: &mut Compilation,
+  ) -> Result<(String, String, String, Context)> {
+    let config = &self.config;
+    let (content, url, normalized_template_name, context) =
+      if let Some(content) = &config.template_content {
+        (
+          content.clone(),
+          parse_to_url("template_content.html").path().to_string(),
+          "template_content.html".to_string(),
+          compilation.options.context.clone(),
+        )
+      } else if let Some(template) = &config.template {
+        // TODO: support loader query form
+        let resolved_template = path_clean::clean(
+          AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
+        );
+
+        let content = fs::read_to_string(&resolved_template)
+          .context(format!(
+            "failed to read `{}` from `{}`",
+            resolved_template.display(),
+            &compilation.options.context
+          ))
+          .map_err(AnyhowError::from)?;
+
+        let url = resolved_template.to_string_lossy().to_string();
+        let context = resolved_template
+          .parent()
+          .map(|dir| Context::from(dir.to_string_lossy().to_string()))
+          .unwrap_or_else(|| compilation.options.context.clone());
+        compilation.file_dependencies.insert(resolved_template);
+
+        (content, url, template.clone(), context)
+      } else {
+        (
+          default_template().to_owned(),
+          parse_to_url("default.html").path().to_string(),
+          "default.html".to_string(),
+          compilation.options.context.clone(),
+        )
+      };
+
+    // process with template parameters
+    let template_result = if let Some(template_parameters) = &config.template_parameters {
+      let mut dj = Dojang::new();
+      dj.add(url.clone(), content)
+        .expect("failed to add template");
+      dj.render(&url, serde_json::json!(template_parameters))
+        .expect("failed to render template")
+    } else {
+      content
+    };
+
+    Ok((template_result, url, normalized_template_name, context))
+  }
+
+  /// All assets referenced from the template are bundled into this entry, its
+  /// javascript output is removed again before the html is emitted.
+  fn assets_entry_name(&self) -> String {
+    format!("{}~assets", self.config.filename)
+  }
+
+  /// The entries of the references are prefixed with the filename of the html,
+  /// so they never collide with the entries of the user or other html plugins.
+  fn collect_references(&self, document: &Document) -> Vec<HtmlReference> {
+    ReferenceCollector::collect(document, &self.config.filename, &[self.assets_entry_name()])
+  }
+
+  /// On incremental rebuilds the entries of the last compilation are kept, the ones of
+  /// references removed from the template since then are removed here.
+  fn remove_unreferenced_entries(
+    &self,
+    compilation: &mut Compilation,
+    entry_requests: &[(String, Option<String>)],
+  ) {
+    let entry_prefix = format!("{}~", self.config.filename);
+    let mut requests_by_entry: HashMap<&str, HashSet<&str>> = HashMap::default();
+    for (request, name) in entry_requests {
+      if let Some(name) = name {
+        requests_by_entry
+          .entry(name.as_str())
+          .or_default()
+          .insert(request.as_str());
+      }
+    }
+    let module_graph = &compilation.module_graph;
+    compilation.entries.retain(|name, entry| {
+      if !name.starts_with(&entry_prefix) {
+        return true;
+      }
+      let Some(requests) = requests_by_entry.get(name.as_str()) else {
+        return false;
+      };
+      entry.dependencies.retain(|dependency_id| {
+        module_graph
+          .dependency_by_id(dependency_id)
+          .and_then(|dependency| dependency.as_module_dependency())
+          .is_some_and(|dependency| requests.contains(dependency.request()))
+      });
+      !entry.dependencies.is_empty()
+    });
+  }
 }
 
 fn default_template() -> &'static str {
@@ -48,63 +165,151 @@ fn default_template() -> &'static str {
 </html>"#
 }
 
+/// Whether the entry already has a dependency of the request, which happens on
+/// incremental rebuilds, where the entries of the last compilation are kept.
+fn has_entry_dependency(compilation: &Compilation, entry_name: &str, request: &str) -> bool {
+  compilation.entries.get(entry_name).is_some_and(|entry| {
+    entry.dependencies.iter().any(|dependency_id| {
+      compilation
+        .module_graph
+        .dependency_by_id(dependency_id)
+        .and_then(|dependency| dependency.as_module_dependency())
+        .is_some_and(|dependency| dependency.request() == request)
+    })
+  })
+}
+
+/// Files of the entrypoint in the order they need to be loaded, the runtime
+/// chunk first, then the split chunks and the entry chunk itself last.
+fn get_entrypoint_files(entrypoint: &ChunkGroup, chunk_by_ukey: &ChunkByUkey) -> Vec<String> {
+  let runtime_chunk = entrypoint.get_runtime_chunk();
+  let entry_point_chunk = entrypoint.get_entry_point_chunk();
+  entrypoint
+    .chunks
+    .iter()
+    .sorted_by_key(|&&chunk| {
+      if chunk == entry_point_chunk {
+        2
+      } else if chunk == runtime_chunk {
+        0
+      } else {
+        1
+      }
+    })
+    .flat_map(|chunk| chunk_by_ukey.expect_get(chunk).files.iter().sorted())
+    .cloned()
+    .collect()
+}
+
+fn asset_request(reference: &HtmlReference) -> String {
+  format!(
+    "{}.webpack[asset/resource]!=!{}",
+    reference.request, reference.request
+  )
+}
+
 #[async_trait]
 impl Plugin for HtmlRspackPlugin {
   fn name(&self) -> &'static str {
     "rspack.HtmlRspackPlugin"
   }
 
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args
+      .compilation
+      .set_dependency_factory(DependencyType::Entry, params.normal_module_factory.clone());
+    Ok(())
+  }
+
+  async fn make(
+    &self,
+    _ctx: PluginContext,
+    compilation: &mut Compilation,
+    params: &mut Vec<MakeParam>,
+  ) -> PluginMakeHookOutput {
+    // errors of reading and parsing the template are reported in `processAssets`
+    let Ok((content, url, _, context)) = self.read_template(compilation) else {
+      return Ok(());
+    };
+    let Ok(document) = HtmlCompiler::new(&self.config).parse_file(&url, content) else {
+      return Ok(());
+    };
+    let (document, _) = document.split_into_parts();
+
+    let entry_requests = self
+      .collect_references(&document)
+      .iter()
+      .map(|reference| match reference.kind {
+        HtmlReferenceKind::Script | HtmlReferenceKind::Style => {
+          (reference.request.clone(), reference.entry_name.clone())
+        }
+        HtmlReferenceKind::Asset => (asset_request(reference), Some(self.assets_entry_name())),
+      })
+      .collect::<Vec<_>>();
+    self.remove_unreferenced_entries(compilation, &entry_requests);
+
+    for (request, name) in entry_requests {
+      // references added by a previous compilation are rebuilt by the incremental make
+      if let Some(name) = &name
+        && has_entry_dependency(compilation, name, &request)
+      {
+        continue;
+      }
+      let dependency: BoxDependency = Box::new(EntryDependency::new(request, context.clone()));
+      let dependency_id = *dependency.id();
+      compilation.add_entry(
+        dependency,
+        EntryOptions {
+          name,
+          ..Default::default()
+        },
+      )?;
+      params.push(MakeParam::new_force_build_dep_param(dependency_id, None));
+    }
+    Ok(())
+  }
+
+  async fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    // every html plugin instance runs this hook, the one that comes first adds the
+    // runtime module and the others see `SRI_HASHES` already required
+    if self.config.sri.is_some()
+      && matches!(self.config.sri_mode, HtmlSriMode::All)
+      && args
+        .runtime_requirements
+        .contains(RuntimeGlobals::LOAD_SCRIPT)
+      && !args
+        .runtime_requirements_mut
+        .contains(RuntimeGlobals::SRI_HASHES)
+    {
+      args
+        .runtime_requirements_mut
+        .insert(RuntimeGlobals::SRI_HASHES);
+      args
+        .compilation
+        .add_runtime_module(args.chunk, SriHashesRuntimeModule::default().boxed())
+        .await?;
+    }
+    Ok(())
+  }
+
   async fn process_assets_stage_optimize_inline(
     &self,
-    _ctx: rspack_core::PluginContext,
+    _ctx: PluginContext,
     args: rspack_core::ProcessAssetsArgs<'_>,
   ) -> rspack_core::PluginProcessAssetsOutput {
     let config = &self.config;
     let compilation = args.compilation;
 
     let parser = HtmlCompiler::new(config);
-    let (content, url, normalized_template_name) = if let Some(content) = &config.template_content {
-      (
-        content.clone(),
-        parse_to_url("template_content.html").path().to_string(),
-        "template_content.html".to_string(),
-      )
-    } else if let Some(template) = &config.template {
-      // TODO: support loader query form
-      let resolved_template = path_clean::clean(
-        AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
-      );
-
-      let content = fs::read_to_string(&resolved_template)
-        .context(format!(
-          "failed to read `{}` from `{}`",
-          resolved_template.display(),
-          &compilation.options.context
-        ))
-        .map_err(AnyhowError::from)?;
-
-      let url = resolved_template.to_string_lossy().to_string();
-      compilation.file_dependencies.insert(resolved_template);
-
-      (content, url, template.clone())
-    } else {
-      (
-        default_template().to_owned(),
-        parse_to_url("default.html").path().to_string(),
-        "default.html".to_string(),
-      )
-    };
-
-    // process with template parameters
-    let template_result = if let Some(template_parameters) = &self.config.template_parameters {
-      let mut dj = Dojang::new();
-      dj.add(url.clone(), content)
-        .expect("failed to add template");
-      dj.render(&url, serde_json::json!(template_parameters))
-        .expect("failed to render template")
-    } else {
-      content
-    };
+    let (template_result, url, normalized_template_name, _) = self.read_template(compilation)?;
 
     let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
 
@@ -113,10 +318,111 @@ impl Plugin for HtmlRspackPlugin {
     if !diagnostic.is_empty() {
       compilation.push_batch_diagnostic(diagnostic);
     }
-    let included_assets = compilation
+
+    let references = self.collect_references(&current_ast);
+    let assets_entry_name = self.assets_entry_name();
+    let html_entries = references
+      .iter()
+      .filter_map(|reference| reference.entry_name.as_deref())
+      .chain([assets_entry_name.as_str()])
+      .collect::<HashSet<_>>();
+
+    // the javascript emitted for the assets entry is never loaded
+    if let Some(ukey) = compilation.entrypoints.get(&assets_entry_name) {
+      let entrypoint = compilation.chunk_group_by_ukey.expect_get(ukey);
+      let files = entrypoint
+        .chunks
+        .iter()
+        .map(|chunk| compilation.chunk_by_ukey.expect_get(chunk))
+        .filter(|chunk| chunk.groups.len() == 1)
+        .flat_map(|chunk| chunk.files.iter().cloned())
+        .collect::<Vec<_>>();
+      for file in files {
+        compilation.delete_asset(&file);
+      }
+    }
+
+    let public_path = config.get_public_path(compilation, &self.config.filename);
+    let mut reference_urls: HashMap<String, Vec<String>> = HashMap::default();
+    for reference in &references {
+      let urls = match reference.kind {
+        HtmlReferenceKind::Script | HtmlReferenceKind::Style => {
+          let Some(ukey) = reference
+            .entry_name
+            .as_ref()
+            .and_then(|name| compilation.entrypoints.get(name))
+          else {
+            continue;
+          };
+          let extensions: &[&str] = if matches!(reference.kind, HtmlReferenceKind::Script) {
+            &["js", "mjs"]
+          } else {
+            &["css"]
+          };
+          get_entrypoint_files(
+            compilation.chunk_group_by_ukey.expect_get(ukey),
+            &compilation.chunk_by_ukey,
+          )
+          .into_iter()
+          .filter(|file| {
+            Path::new(file)
+              .extension()
+              .is_some_and(|extension| extensions.iter().any(|e| extension.eq_ignore_ascii_case(e)))
+          })
+          .map(|file| format!("{public_path}{file}"))
+          .collect::<Vec<_>>()
+        }
+        HtmlReferenceKind::Asset => {
+          let request = asset_request(reference);
+          let Some(entry) = compilation.entries.get(&assets_entry_name) else {
+            continue;
+          };
+          let Some(module_identifier) = entry
+            .dependencies
+            .iter()
+            .find(|dependency_id| {
+              compilation
+                .module_graph
+                .dependency_by_id(dependency_id)
+                .and_then(|dependency| dependency.as_module_dependency())
+                .is_some_and(|dependency| dependency.request() == request)
+            })
+            .and_then(|dependency_id| {
+              compilation
+                .module_graph
+                .module_identifier_by_dependency_id(dependency_id)
+            })
+          else {
+            continue;
+          };
+          let Some(filename) = compilation
+            .code_generation_results
+            .get_one(module_identifier)
+            .and_then(|result| result.data.get::<CodeGenerationDataFilename>())
+          else {
+            continue;
+          };
+          vec![format!("{public_path}{}", filename.filename())]
+        }
+      };
+      if !urls.is_empty() {
+        reference_urls.insert(reference.value.clone(), urls);
+      }
+    }
+
+    if let Some(hash_func) = &config.sri
+      && matches!(config.sri_mode, HtmlSriMode::All)
+    {
+      replace_sri_hash_placeholders(compilation, hash_func)?;
+    }
+
+    let included_entrypoints = compilation
       .entrypoints
       .keys()
       .filter(|&entry_name| {
+        if html_entries.contains(entry_name.as_str()) {
+          return false;
+        }
         let mut included = true;
         if let Some(included_chunks) = &config.chunks {
           included = included_chunks.iter().any(|c| c.eq(entry_name));
@@ -127,6 +433,10 @@ impl Plugin for HtmlRspackPlugin {
         included
       })
       .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+      .collect::<Vec<_>>();
+
+    let included_assets = included_entrypoints
+      .iter()
       .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
       .map(|asset_name| {
         (
@@ -136,15 +446,16 @@ impl Plugin for HtmlRspackPlugin {
       })
       .collect::<Vec<_>>();
 
+    let initial_asset_names = included_assets
+      .iter()
+      .map(|(asset_name, _)| asset_name.clone())
+      .collect::<HashSet<_>>();
     let mut tags = vec![];
     // if inject is 'false', don't do anything
     if !matches!(config.inject, HtmlInject::False) {
       for (asset_name, asset) in included_assets {
         if let Some(extension) = Path::new(&asset_name).extension() {
-          let asset_uri = format!(
-            "{}{asset_name}",
-            config.get_public_path(compilation, &self.config.filename),
-          );
+          let asset_uri = format!("{public_path}{asset_name}");
           let mut tag: Option<HTMLPluginTag> = None;
           if extension.eq_ignore_ascii_case("css") {
             tag = Some(HTMLPluginTag::create_style(&asset_uri, config.inject));
@@ -161,6 +472,43 @@ impl Plugin for HtmlRspackPlugin {
           }
         }
       }
+
+      if let Some(resource_hint) = config.resource_hint {
+        let async_assets = included_entrypoints
+          .iter()
+          .flat_map(|entry| entry.chunks.iter())
+          .flat_map(|chunk| {
+            compilation
+              .chunk_by_ukey
+              .expect_get(chunk)
+              .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+          })
+          .flat_map(|chunk| compilation.chunk_by_ukey.expect_get(&chunk).files.iter())
+          .unique()
+          .filter(|asset_name| !initial_asset_names.contains(asset_name.as_str()))
+          .filter_map(|asset_name| Some((asset_name, compilation.assets().get(asset_name)?)))
+          .collect::<Vec<_>>();
+        for (asset_name, asset) in async_assets {
+          let Some(extension) = Path::new(asset_name).extension() else {
+            continue;
+          };
+          let as_type = if extension.eq_ignore_ascii_case("css") {
+            "style"
+          } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+            "script"
+          } else {
+            continue;
+          };
+          tags.push((
+            HTMLPluginTag::create_resource_hint(
+              &format!("{public_path}{asset_name}"),
+              resource_hint,
+              as_type,
+            ),
+            asset,
+          ));
+        }
+      }
     }
 
     // if some plugin changes assets in the same stage after this plugin
@@ -176,6 +524,7 @@ impl Plugin for HtmlRspackPlugin {
     }
 
     let tags = tags.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
+    current_ast.visit_mut_with(&mut ReferenceWriter::new(&reference_urls));
     let mut visitor = AssetWriter::new(config, &tags, compilation);
     current_ast.visit_mut_with(&mut visitor);
 
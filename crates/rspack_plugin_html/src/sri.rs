@@ -1,6 +1,15 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use rspack_core::rspack_sources::{BoxSource, Source};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, Source, SourceExt},
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_util::source_map::SourceMapKind;
 #[cfg(feature = "testing")]
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -85,3 +94,149 @@ pub fn add_sri(tag: &mut HTMLPluginTag, sri: &String) {
     });
   }
 }
+
+static SRI_HASH_PLACEHOLDER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\*-\*-\*-CHUNK-SRI-HASH-(.+?)-\*-\*-\*").expect("Invalid RegExp"));
+
+fn sri_hash_placeholder(chunk_id: &str) -> String {
+  format!("*-*-*-CHUNK-SRI-HASH-{chunk_id}-*-*-*")
+}
+
+/// Embeds a map from chunk id to integrity for every async chunk of the runtime
+/// chunk. The integrity is not known until the assets are emitted, so the map is
+/// filled with placeholders which are replaced by [replace_sri_hash_placeholders].
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct SriHashesRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+}
+
+impl Default for SriHashesRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/sri_hashes"),
+      chunk: None,
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for SriHashesRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation
+      .chunk_by_ukey
+      .expect_get(&self.chunk.expect("The chunk should be attached"));
+    let hashes = chunk
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .iter()
+      .filter_map(|chunk| compilation.chunk_by_ukey.expect_get(chunk).id.clone())
+      .map(|id| {
+        let placeholder = sri_hash_placeholder(&id);
+        (id, placeholder)
+      })
+      .collect::<BTreeMap<_, _>>();
+    RawSource::from(format!(
+      "{} = {};\n",
+      RuntimeGlobals::SRI_HASHES,
+      serde_json::to_string(&hashes).expect("should serialize sri hashes")
+    ))
+    .boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+}
+
+/// Replaces the placeholders emitted by [SriHashesRuntimeModule] with the
+/// integrity of the javascript file of each chunk. Files that contain
+/// placeholders themselves are only hashed after their placeholders are replaced,
+/// chunks that can't be hashed this way, e.g. in a cycle, are loaded without integrity.
+pub fn replace_sri_hash_placeholders(
+  compilation: &mut Compilation,
+  hash_func: &HtmlSriHashFunction,
+) -> rspack_error::Result<()> {
+  let mut chunk_files = compilation
+    .chunk_by_ukey
+    .values()
+    .filter_map(|chunk| {
+      let id = chunk.id.clone()?;
+      let file = chunk
+        .files
+        .iter()
+        .find(|file| file.ends_with(".js") || file.ends_with(".mjs"))?;
+      Some((id, file.clone()))
+    })
+    .collect::<BTreeMap<_, _>>();
+
+  let mut pending = compilation
+    .assets()
+    .iter()
+    .filter(|(_, asset)| {
+      asset
+        .get_source()
+        .is_some_and(|source| SRI_HASH_PLACEHOLDER_REGEX.is_match(&source.source()))
+    })
+    .map(|(filename, _)| filename.clone())
+    .collect::<Vec<_>>();
+  pending.sort();
+
+  let mut integrities: BTreeMap<String, String> = BTreeMap::default();
+  let mut force = false;
+  loop {
+    // hash every chunk whose file no longer contains placeholders
+    chunk_files.retain(|id, file| {
+      if pending.contains(file) {
+        return true;
+      }
+      if let Some(source) = compilation
+        .assets()
+        .get(file)
+        .and_then(|asset| asset.get_source())
+      {
+        integrities.insert(id.clone(), create_digest_from_asset(hash_func, source));
+      }
+      false
+    });
+
+    let mut progressed = false;
+    for filename in std::mem::take(&mut pending) {
+      let Some(source) = compilation
+        .assets()
+        .get(&filename)
+        .and_then(|asset| asset.get_source())
+      else {
+        continue;
+      };
+      let content = source.source();
+      let ready = force
+        || SRI_HASH_PLACEHOLDER_REGEX
+          .captures_iter(&content)
+          .all(|captures| !chunk_files.contains_key(&captures[1]));
+      if !ready {
+        pending.push(filename);
+        continue;
+      }
+      let replaced = SRI_HASH_PLACEHOLDER_REGEX
+        .replace_all(&content, |captures: &regex::Captures| {
+          integrities.get(&captures[1]).cloned().unwrap_or_default()
+        })
+        .to_string();
+      compilation.update_asset(&filename, |_, info| {
+        Ok((RawSource::from(replaced).boxed(), info))
+      })?;
+      progressed = true;
+    }
+    if pending.is_empty() {
+      break;
+    }
+    force = !progressed;
+  }
+  Ok(())
+}
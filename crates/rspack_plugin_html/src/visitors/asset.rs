@@ -9,7 +9,7 @@ use swc_html::ast::{Attribute, Child, Element, Namespace, Text};
 use swc_html::visit::{VisitMut, VisitMutWith};
 
 use super::utils::create_element;
-use crate::config::{HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading};
+use crate::config::{HtmlInject, HtmlResourceHint, HtmlRspackPluginOptions, HtmlScriptLoading};
 
 // the tag
 #[derive(Debug)]
@@ -40,6 +40,28 @@ impl HTMLPluginTag {
     }
   }
 
+  pub fn create_resource_hint(href: &str, rel: HtmlResourceHint, as_type: &str) -> HTMLPluginTag {
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      append_to: HtmlInject::Head,
+      attributes: vec![
+        HtmlPluginAttribute {
+          attr_name: "rel".to_string(),
+          attr_value: Some(rel.as_str().to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "href".to_string(),
+          attr_value: Some(href.to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "as".to_string(),
+          attr_value: Some(as_type.to_string()),
+        },
+      ],
+      void_tag: true,
+    }
+  }
+
   pub fn create_script(
     src: &str,
     append_to: HtmlInject,
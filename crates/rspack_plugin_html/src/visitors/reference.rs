@@ -0,0 +1,227 @@
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustc_hash::FxHashMap as HashMap;
+use swc_html::ast::{Child, Document, DocumentFragment, Element};
+use swc_html::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+static SCHEME_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z\d+\-.]*:").expect("Invalid RegExp"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlReferenceKind {
+  /// `<script src>`, bundled as an entry and replaced with its javascript files
+  Script,
+  /// `<link rel="stylesheet" href>`, bundled as an entry and replaced with its css files
+  Style,
+  /// any other `src`/`href`, bundled as an `asset/resource` module
+  Asset,
+}
+
+/// A local `src`/`href` reference found in the html template.
+#[derive(Debug, Clone)]
+pub struct HtmlReference {
+  pub kind: HtmlReferenceKind,
+  /// the attribute value as it is written in the template
+  pub value: String,
+  /// the request used to create the dependency
+  pub request: String,
+  /// name of the entry created for `Script` and `Style` references
+  pub entry_name: Option<String>,
+}
+
+fn get_attribute<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+  element
+    .attributes
+    .iter()
+    .find(|attr| attr.name.eq(name))
+    .and_then(|attr| attr.value.as_deref())
+}
+
+/// Returns the attributes of the element that may reference a file, along with
+/// how the referenced file should be bundled.
+fn get_reference_attributes(element: &Element) -> Vec<(&'static str, HtmlReferenceKind)> {
+  match &*element.tag_name {
+    "script" => vec![("src", HtmlReferenceKind::Script)],
+    "link" => {
+      let is_stylesheet = get_attribute(element, "rel").is_some_and(|rel| {
+        rel
+          .split_ascii_whitespace()
+          .any(|rel| rel.eq_ignore_ascii_case("stylesheet"))
+      });
+      if is_stylesheet {
+        vec![("href", HtmlReferenceKind::Style)]
+      } else {
+        vec![("href", HtmlReferenceKind::Asset)]
+      }
+    }
+    "img" | "source" | "audio" | "track" => vec![("src", HtmlReferenceKind::Asset)],
+    "video" => vec![
+      ("src", HtmlReferenceKind::Asset),
+      ("poster", HtmlReferenceKind::Asset),
+    ],
+    _ => vec![],
+  }
+}
+
+/// Only relative urls are bundled, absolute urls, root relative urls, urls with
+/// a scheme and fragments are kept as they are.
+fn is_local_reference(value: &str) -> bool {
+  !value.is_empty()
+    && !value.starts_with('/')
+    && !value.starts_with('#')
+    && !SCHEME_REGEX.is_match(value)
+}
+
+fn to_request(value: &str) -> String {
+  if value.starts_with("./") || value.starts_with("../") {
+    value.to_string()
+  } else {
+    format!("./{value}")
+  }
+}
+
+#[derive(Debug)]
+pub struct ReferenceCollector<'a> {
+  references: Vec<HtmlReference>,
+  /// prefix of the entry names, so they never collide with the entries of the user
+  entry_prefix: &'a str,
+  /// entry names already taken by the plugin itself
+  reserved_entry_names: &'a [String],
+}
+
+impl<'a> ReferenceCollector<'a> {
+  /// Collects the local references of the template in document order. The
+  /// result only depends on the document, so the entry names are stable
+  /// between `make` and `processAssets`.
+  pub fn collect(
+    document: &Document,
+    entry_prefix: &'a str,
+    reserved_entry_names: &'a [String],
+  ) -> Vec<HtmlReference> {
+    let mut collector = Self {
+      references: Vec::new(),
+      entry_prefix,
+      reserved_entry_names,
+    };
+    document.visit_with(&mut collector);
+    collector.references
+  }
+
+  fn entry_name(&self, request: &str) -> String {
+    let stem = Path::new(request)
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().to_string())
+      .unwrap_or_else(|| "html".to_string());
+    let prefixed = format!("{}~{stem}", self.entry_prefix);
+    let mut name = prefixed.clone();
+    let mut index = 1;
+    while self.reserved_entry_names.contains(&name)
+      || self.references.iter().any(|reference| {
+        reference.entry_name.as_deref() == Some(name.as_str()) && reference.request != request
+      })
+    {
+      name = format!("{prefixed}~{index}");
+      index += 1;
+    }
+    name
+  }
+}
+
+impl Visit for ReferenceCollector<'_> {
+  fn visit_element(&mut self, n: &Element) {
+    for (attr_name, kind) in get_reference_attributes(n) {
+      let Some(value) = get_attribute(n, attr_name) else {
+        continue;
+      };
+      if !is_local_reference(value) || self.references.iter().any(|r| r.value == value) {
+        continue;
+      }
+      let request = to_request(value);
+      let entry_name = match kind {
+        HtmlReferenceKind::Asset => None,
+        HtmlReferenceKind::Script | HtmlReferenceKind::Style => Some(self.entry_name(&request)),
+      };
+      self.references.push(HtmlReference {
+        kind,
+        value: value.to_string(),
+        request,
+        entry_name,
+      });
+    }
+    n.visit_children_with(self);
+  }
+}
+
+/// Replaces the references collected by [ReferenceCollector] with the urls of
+/// the emitted files. A reference that resolves to several files, e.g. an entry
+/// split into several chunks, is expanded into one element per file.
+#[derive(Debug)]
+pub struct ReferenceWriter<'a> {
+  urls: &'a HashMap<String, Vec<String>>,
+}
+
+impl<'a> ReferenceWriter<'a> {
+  pub fn new(urls: &'a HashMap<String, Vec<String>>) -> Self {
+    Self { urls }
+  }
+
+  fn rewrite_children(&self, children: &mut Vec<Child>) {
+    let old_children = std::mem::take(children);
+    for child in old_children {
+      let Child::Element(element) = child else {
+        children.push(child);
+        continue;
+      };
+      let mut expanded = None;
+      let mut element = element;
+      for (attr_name, _) in get_reference_attributes(&element) {
+        let Some(attr) = element
+          .attributes
+          .iter_mut()
+          .find(|attr| attr.name.eq(attr_name))
+        else {
+          continue;
+        };
+        let Some(urls) = attr.value.as_deref().and_then(|value| self.urls.get(value)) else {
+          continue;
+        };
+        let Some((last, rest)) = urls.split_last() else {
+          continue;
+        };
+        attr.value = Some(last.as_str().into());
+        attr.raw_value = None;
+        if !rest.is_empty() {
+          expanded = Some((attr_name, rest));
+        }
+      }
+      if let Some((attr_name, rest)) = expanded {
+        for url in rest {
+          let mut cloned = element.clone();
+          if let Some(attr) = cloned
+            .attributes
+            .iter_mut()
+            .find(|attr| attr.name.eq(attr_name))
+          {
+            attr.value = Some(url.as_str().into());
+          }
+          children.push(Child::Element(cloned));
+        }
+      }
+      children.push(Child::Element(element));
+    }
+  }
+}
+
+impl VisitMut for ReferenceWriter<'_> {
+  fn visit_mut_element(&mut self, n: &mut Element) {
+    n.visit_mut_children_with(self);
+    self.rewrite_children(&mut n.children);
+  }
+
+  fn visit_mut_document_fragment(&mut self, n: &mut DocumentFragment) {
+    n.visit_mut_children_with(self);
+    self.rewrite_children(&mut n.children);
+  }
+}
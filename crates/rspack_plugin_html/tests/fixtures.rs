@@ -1,8 +1,17 @@
 use std::path::PathBuf;
 
-use rspack_testing::{fixture, test_fixture_html};
+use rspack_testing::{fixture, test_fixture_html, test_fixture_insta};
 
 #[fixture("tests/fixtures/*")]
 fn html(fixture_path: PathBuf) {
   test_fixture_html(&fixture_path);
 }
+
+#[fixture("tests/runtime/*")]
+fn html_runtime(fixture_path: PathBuf) {
+  test_fixture_insta(
+    &fixture_path,
+    &|s| s.ends_with(".html") || s.ends_with("runtime.js"),
+    Box::new(|_, _| {}),
+  );
+}
@@ -1,5 +1,7 @@
+mod original_positions;
 mod parse;
 mod stringify;
 
+pub use original_positions::OriginalPositions;
 pub use parse::{parse, parse_js};
 pub use stringify::{print, stringify, CodegenOptions, SourceMapConfig};
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use rspack_core::{RealDependencyLocation, SourcePosition};
+use swc_core::common::{BytePos, LineCol, SourceFile, SourceMap, Span};
+
+/// Resolves the spans of the AST parsed from the code printed by [super::stringify] to the
+/// lines and columns of the source it was printed from, the source of the module.
+///
+/// The printed code differs from the source, e.g. empty lines are dropped.
+pub struct OriginalPositions {
+  /// The source map of the AST before it is printed
+  source_map: Arc<SourceMap>,
+  /// The printed code
+  generated: Arc<SourceFile>,
+  /// Positions in the source by their line and UTF-16 column in the printed code
+  mappings: BTreeMap<(u32, u32), BytePos>,
+}
+
+impl OriginalPositions {
+  pub fn new(
+    source_map: Arc<SourceMap>,
+    generated: Arc<SourceFile>,
+    mappings: &[(BytePos, LineCol)],
+  ) -> Self {
+    let mut by_line_col = BTreeMap::new();
+    for (pos, line_col) in mappings {
+      if pos.is_dummy() || pos.0 == u32::MAX {
+        continue;
+      }
+      by_line_col
+        .entry((line_col.line, line_col.col))
+        .or_insert(*pos);
+    }
+    Self {
+      source_map,
+      generated,
+      mappings: by_line_col,
+    }
+  }
+
+  pub fn resolve(&self, span: Span) -> RealDependencyLocation {
+    RealDependencyLocation {
+      start: self.position(span.lo),
+      end: self.position(span.hi),
+    }
+  }
+
+  fn position(&self, pos: BytePos) -> SourcePosition {
+    let generated = &self.generated;
+    let Some(line) = generated.lookup_line(pos) else {
+      return SourcePosition { line: 1, column: 0 };
+    };
+    let line_start = (generated.lines[line] - generated.start_pos).0 as usize;
+    let offset = (pos - generated.start_pos).0 as usize;
+    let column = generated
+      .src
+      .get(line_start..offset)
+      .map_or(0, |s| s.encode_utf16().count());
+    // Same as looking up a source map, the closest mapped position before it on the line
+    let mapped = self
+      .mappings
+      .range(..=(line as u32, column as u32))
+      .next_back()
+      .filter(|((mapped_line, _), _)| *mapped_line == line as u32);
+    match mapped {
+      Some(((_, mapped_column), original)) => {
+        let loc = self.source_map.lookup_char_pos(*original);
+        SourcePosition {
+          line: loc.line,
+          column: loc.col.0 + column - *mapped_column as usize,
+        }
+      }
+      // Nothing before it on the line is mapped, the position in the printed code is the best guess
+      None => SourcePosition {
+        line: line + 1,
+        column,
+      },
+    }
+  }
+}
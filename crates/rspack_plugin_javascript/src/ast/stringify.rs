@@ -40,6 +40,7 @@ impl CodegenOptions {
         inline_sources_content: true,
         emit_columns: matches!(source_map_kind, SourceMapKind::SourceMap),
         names: Default::default(),
+        keep_mappings: false,
       },
       keep_comments,
       inline_script: Some(false),
@@ -92,7 +93,7 @@ pub fn print(
         source_map.clone(),
         "\n",
         &mut buf,
-        if source_map_config.enable {
+        if source_map_config.enable || source_map_config.keep_mappings {
           Some(&mut src_map_buf)
         } else {
           None
@@ -132,7 +133,11 @@ pub fn print(
   } else {
     None
   };
-  Ok(TransformOutput { code: src, map })
+  Ok(TransformOutput {
+    code: src,
+    map,
+    mappings: src_map_buf,
+  })
 }
 
 #[derive(Default, Clone)]
@@ -141,6 +146,8 @@ pub struct SourceMapConfig {
   pub inline_sources_content: bool,
   pub emit_columns: bool,
   pub names: AHashMap<BytePos, Atom>,
+  /// Return the mappings in [TransformOutput::mappings] even if no source map is generated
+  pub keep_mappings: bool,
 }
 
 impl SourceMapGenConfig for SourceMapConfig {
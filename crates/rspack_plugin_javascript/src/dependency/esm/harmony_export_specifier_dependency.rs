@@ -1,7 +1,8 @@
 use rspack_core::{
   AsContextDependency, AsModuleDependency, Dependency, DependencyCategory, DependencyId,
-  DependencyTemplate, DependencyType, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec,
-  HarmonyExportInitFragment, ModuleGraph, TemplateContext, TemplateReplaceSource, UsedName,
+  DependencyTemplate, DependencyType, ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec,
+  ExportsSpec, HarmonyExportInitFragment, InlinableValue, ModuleGraph, TemplateContext,
+  TemplateReplaceSource, UsedName,
 };
 use swc_core::ecma::atoms::Atom;
 
@@ -9,8 +10,9 @@ use swc_core::ecma::atoms::Atom;
 #[derive(Debug, Clone)]
 pub struct HarmonyExportSpecifierDependency {
   id: DependencyId,
-  name: Atom,
+  pub(crate) name: Atom,
   value: Atom, // id
+  inlinable: Option<InlinableValue>,
 }
 
 impl HarmonyExportSpecifierDependency {
@@ -19,8 +21,14 @@ impl HarmonyExportSpecifierDependency {
       id: DependencyId::new(),
       name,
       value,
+      inlinable: None,
     }
   }
+
+  pub fn with_inlinable(mut self, inlinable: Option<InlinableValue>) -> Self {
+    self.inlinable = inlinable;
+    self
+  }
 }
 
 impl Dependency for HarmonyExportSpecifierDependency {
@@ -41,8 +49,17 @@ impl Dependency for HarmonyExportSpecifierDependency {
   }
 
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
+    let export = if let Some(inlinable) = &self.inlinable {
+      ExportNameOrSpec::ExportSpec(ExportSpec {
+        name: self.name.clone(),
+        inlinable: Some(inlinable.clone()),
+        ..Default::default()
+      })
+    } else {
+      ExportNameOrSpec::String(self.name.clone())
+    };
     Some(ExportsSpec {
-      exports: ExportsOfExportsSpec::Array(vec![ExportNameOrSpec::String(self.name.clone())]),
+      exports: ExportsOfExportsSpec::Array(vec![export]),
       priority: Some(1),
       can_mangle: None,
       terminal_binding: Some(true),
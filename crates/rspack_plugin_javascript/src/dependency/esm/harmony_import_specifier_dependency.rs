@@ -1,18 +1,26 @@
+use std::sync::Arc;
+
 use rspack_core::{
   create_exports_object_referenced, export_from_import, get_dependency_used_by_exports_condition,
-  get_exports_type, tree_shaking::symbol::DEFAULT_JS_WORD, AsContextDependency, Compilation,
+  get_exports_type, get_inlinable_value, property_access, to_normal_comment,
+  tree_shaking::symbol::DEFAULT_JS_WORD, AsContextDependency, BuildMetaExportsType, Compilation,
   ConnectionState, Dependency, DependencyCategory, DependencyCondition, DependencyId,
   DependencyTemplate, DependencyType, ExportsType, ExtendedReferencedExport, ModuleDependency,
   ModuleGraph, ModuleGraphModule, ModuleIdentifier, ReferencedExport, RuntimeSpec, TemplateContext,
   TemplateReplaceSource, UsedByExports,
 };
-use rspack_core::{get_import_var, ModuleReferenceOptions};
+use rspack_core::{get_import_var, ExportInfoProvided, ExportsInfoId, ModuleReferenceOptions};
+use rspack_error::{codes, Diagnostic};
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::{common::Span, ecma::atoms::Atom};
 
 use super::{
   create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply, Specifier,
 };
+use crate::dependency::{
+  HarmonyExportExpressionDependency, HarmonyExportImportedSpecifierDependency,
+  HarmonyExportSpecifierDependency,
+};
 
 #[derive(Debug, Clone)]
 pub struct HarmonyImportSpecifierDependency {
@@ -24,6 +32,8 @@ pub struct HarmonyImportSpecifierDependency {
   end: u32,
   ids: Vec<Atom>,
   pub(crate) call: bool,
+  /// Read the export from the constant it's bound to when possible, `experiments.inlineConst`
+  pub(crate) inline_const: bool,
   direct_import: bool,
   specifier: Specifier,
   used_by_exports: Option<UsedByExports>,
@@ -58,6 +68,7 @@ impl HarmonyImportSpecifierDependency {
       end,
       ids,
       call,
+      inline_const: false,
       direct_import,
       specifier,
       used_by_exports: None,
@@ -127,6 +138,108 @@ impl HarmonyImportSpecifierDependency {
       create_exports_object_referenced()
     }
   }
+
+  /// Warn about an import of a name which the imported module is known not to export,
+  /// the linking errors of webpack's `HarmonyImportDependency`.
+  ///
+  /// The provided exports are flagged by `FlagDependencyExportsPlugin` with the new tree
+  /// shaking, otherwise only the names a module exports itself are known, see [get_static_exports].
+  pub fn get_export_not_found_warning(
+    &self,
+    module_graph: &ModuleGraph,
+    parent_module: &ModuleIdentifier,
+    exports_flagged: bool,
+  ) -> Option<Diagnostic> {
+    let imported_module = module_graph.module_identifier_by_dependency_id(&self.id)?;
+    let ids = self.get_ids(module_graph);
+    if ids.is_empty() {
+      return None;
+    }
+    match get_exports_type(module_graph, &self.id, parent_module) {
+      ExportsType::Namespace => {}
+      ExportsType::DefaultWithNamed if ids[0] != *DEFAULT_JS_WORD => {}
+      _ => return None,
+    }
+    let not_provided = if exports_flagged {
+      let exports_info = module_graph.get_exports_info(imported_module).id;
+      matches!(
+        is_export_provided(exports_info, &ids, module_graph),
+        Some(ExportInfoProvided::False)
+      )
+    } else {
+      get_static_exports(module_graph, imported_module)
+        .is_some_and(|exports| !exports.contains(&ids[0]))
+    };
+    if !not_provided {
+      return None;
+    }
+    let local = match &self.specifier {
+      Specifier::Namespace(local) | Specifier::Default(local) | Specifier::Named(local, _) => local,
+    };
+    Some(
+      Diagnostic::warn(
+        codes::EXPORT_NOT_FOUND.into(),
+        format!(
+          "export '{}' (imported as '{local}') was not found in '{}'",
+          ids
+            .iter()
+            .map(|id| id.as_ref())
+            .collect::<Vec<_>>()
+            .join("."),
+          self.request,
+        ),
+      )
+      .with_module_identifier(Some(*parent_module)),
+    )
+  }
+}
+
+/// Names exported by an ES module, known from its own export dependencies. `None` if they are
+/// unknown until the exports are flagged, e.g. with `export * from`.
+pub fn get_static_exports(
+  module_graph: &ModuleGraph,
+  module_identifier: &ModuleIdentifier,
+) -> Option<HashSet<Atom>> {
+  let module = module_graph.module_by_identifier(module_identifier)?;
+  if !matches!(
+    module.build_meta()?.exports_type,
+    BuildMetaExportsType::Namespace
+  ) {
+    return None;
+  }
+  let mut exports = HashSet::default();
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+  {
+    if let Some(dep) = dep.downcast_ref::<HarmonyExportSpecifierDependency>() {
+      exports.insert(dep.name.clone());
+    } else if dep.is::<HarmonyExportExpressionDependency>() {
+      exports.insert(DEFAULT_JS_WORD.clone());
+    } else if let Some(dep) = dep.downcast_ref::<HarmonyExportImportedSpecifierDependency>() {
+      exports.insert(dep.name.clone()?);
+    }
+  }
+  Some(exports)
+}
+
+/// `Some(ExportInfoProvided::False)` if the nested export `ids` is known to be not provided.
+fn is_export_provided(
+  exports_info: ExportsInfoId,
+  ids: &[Atom],
+  module_graph: &ModuleGraph,
+) -> Option<ExportInfoProvided> {
+  let export_info = exports_info.get_read_only_export_info(&ids[0], module_graph);
+  if ids.len() > 1
+    && let Some(nested) = export_info.exports_info
+  {
+    return is_export_provided(nested, &ids[1..], module_graph);
+  }
+  match export_info.provided {
+    Some(ExportInfoProvided::True) if ids.len() > 1 => None,
+    provided => provided,
+  }
 }
 
 impl DependencyTemplate for HarmonyImportSpecifierDependency {
@@ -148,6 +261,23 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
       .module_graph_module_by_dependency_id(&self.id);
 
     let is_new_treeshaking = compilation.options.is_new_tree_shaking();
+    if self.inline_const
+      && let Some(value) = get_inlinable_value(&compilation.module_graph, &self.id)
+    {
+      let ids = self.get_ids(&compilation.module_graph);
+      let value = format!(
+        "({}{}){}",
+        to_normal_comment(&format!("inlined export {}", ids[0])),
+        value.render(),
+        property_access(&ids, 1)
+      );
+      if self.shorthand {
+        source.insert(self.end, &format!(": {value}"), None);
+      } else {
+        source.replace(self.start, self.end, &value, None)
+      }
+      return;
+    }
     if is_new_treeshaking {
       let connection = compilation.module_graph.connection_by_dependency(&self.id);
       let is_target_active = if let Some(con) = connection {
@@ -303,7 +433,25 @@ impl ModuleDependency for HarmonyImportSpecifierDependency {
   }
 
   fn get_condition(&self) -> Option<DependencyCondition> {
-    get_dependency_used_by_exports_condition(self.id, self.used_by_exports.as_ref())
+    let condition =
+      get_dependency_used_by_exports_condition(self.id, self.used_by_exports.as_ref());
+    if !self.inline_const {
+      return condition;
+    }
+    let id = self.id;
+    Some(DependencyCondition::Fn(Arc::new(
+      move |connection, runtime, module_graph: &ModuleGraph| {
+        // the inlined export is no longer read from the imported module
+        if get_inlinable_value(module_graph, &id).is_some() {
+          return ConnectionState::Bool(false);
+        }
+        match &condition {
+          Some(DependencyCondition::Fn(f)) => f(connection, runtime, module_graph),
+          Some(DependencyCondition::False) => ConnectionState::Bool(false),
+          None => ConnectionState::Bool(true),
+        }
+      },
+    )))
   }
 
   fn get_referenced_exports(
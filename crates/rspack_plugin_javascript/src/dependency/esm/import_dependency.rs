@@ -1,5 +1,5 @@
 use rspack_core::{module_namespace_promise, DependencyType, ErrorSpan, ImportDependencyTrait};
-use rspack_core::{AsContextDependency, Dependency};
+use rspack_core::{AffectType, AsContextDependency, Dependency};
 use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
 use rspack_core::{ModuleDependency, TemplateContext, TemplateReplaceSource};
 use swc_core::ecma::atoms::Atom;
@@ -53,6 +53,10 @@ impl Dependency for ImportDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "ImportDependency"
   }
+
+  fn could_affect_referencing_module(&self) -> AffectType {
+    AffectType::True
+  }
 }
 
 impl ModuleDependency for ImportDependency {
@@ -1,7 +1,7 @@
 use rspack_core::{
-  filter_runtime, AsContextDependency, AsModuleDependency, ConnectionState, Dependency,
-  DependencyId, DependencyTemplate, ModuleGraph, ModuleIdentifier, TemplateContext,
-  TemplateReplaceSource, UsageState, UsedByExports, UsedName,
+  filter_runtime, is_no_side_effects_call, AsContextDependency, AsModuleDependency,
+  ConnectionState, Dependency, DependencyId, DependencyTemplate, ModuleGraph, ModuleIdentifier,
+  TemplateContext, TemplateReplaceSource, UsageState, UsedByExports, UsedName,
 };
 use rustc_hash::FxHashSet as HashSet;
 #[derive(Debug, Clone)]
@@ -54,7 +54,15 @@ impl DependencyTemplate for PureExpressionDependency {
         unreachable!()
       }
       Some(UsedByExports::Bool(false)) => {}
-      Some(UsedByExports::Set(ref set)) => {
+      Some(UsedByExports::Set(ref set)) | Some(UsedByExports::SetOrCalls(ref set, _)) => {
+        // the expression isn't pure when any of the imported functions it calls has side effects
+        if let Some(UsedByExports::SetOrCalls(_, ref calls)) = self.used_by_exports
+          && !calls
+            .iter()
+            .all(|call| is_no_side_effects_call(&ctx.compilation.module_graph, call))
+        {
+          return;
+        }
         let exports_info = ctx
           .compilation
           .module_graph
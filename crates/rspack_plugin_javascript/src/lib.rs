@@ -20,6 +20,8 @@ pub use crate::plugin::*;
 pub struct TransformOutput {
   pub code: String,
   pub map: Option<String>,
+  /// Positions in the printed AST and the lines and columns they are printed at
+  pub mappings: Vec<(swc_core::common::BytePos, swc_core::common::LineCol)>,
 }
 
 #[derive(Debug)]
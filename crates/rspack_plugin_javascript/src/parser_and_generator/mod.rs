@@ -19,11 +19,11 @@ use rspack_util::source_map::SourceMapKind;
 use swc_core::common::SyntaxContext;
 use swc_core::ecma::parser::{EsConfig, Syntax};
 
-use crate::ast::CodegenOptions;
+use crate::ast::{CodegenOptions, OriginalPositions};
 use crate::inner_graph_plugin::InnerGraphPlugin;
 use crate::visitors::ScanDependenciesResult;
 use crate::visitors::{run_before_pass, scan_dependencies, swc_visitor::resolver};
-use crate::{SideEffectsFlagPluginVisitor, SyntaxContextInfo};
+use crate::{NoSideEffectsFunctions, SideEffectsFlagPluginVisitor, SyntaxContextInfo};
 
 #[derive(Debug)]
 pub struct JavaScriptParserAndGenerator;
@@ -146,12 +146,12 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
 
     run_before_pass(&mut ast, compiler_options)?;
 
-    let output: crate::TransformOutput = crate::ast::stringify(
-      &ast,
-      additional_data
-        .remove::<CodegenOptions>()
-        .unwrap_or_else(|| CodegenOptions::new(&module_source_map_kind, Some(true))),
-    )?;
+    let mut codegen_options = additional_data
+      .remove::<CodegenOptions>()
+      .unwrap_or_else(|| CodegenOptions::new(&module_source_map_kind, Some(true)));
+    codegen_options.source_map_config.keep_mappings = true;
+    let output: crate::TransformOutput = crate::ast::stringify(&ast, codegen_options)?;
+    let source_map = ast.get_context().source_map.clone();
 
     let parse_result = match crate::ast::parse(
       output.code.clone(),
@@ -167,6 +167,8 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     };
 
     ast = parse_result.0;
+    let original_positions =
+      OriginalPositions::new(source_map, parse_result.1.clone(), &output.mappings);
 
     ast.transform(|program, context| {
       program.visit_mut_with(&mut resolver(
@@ -185,10 +187,12 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       mut rewrite_usage_span,
       import_map,
       mut warning_diagnostics,
-    } = match ast.visit(|program, _| {
+    } = match ast.visit(|program, context| {
       scan_dependencies(
         parse_result.1,
+        &original_positions,
         program,
+        context.unresolved_mark,
         &mut worker_syntax_list,
         resource_data,
         compiler_options,
@@ -223,6 +227,21 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       OptimizeAnalyzeResult::default()
     };
 
+    let no_side_effects = (compiler_options.is_new_tree_shaking()
+      && (compiler_options.optimization.side_effects.is_true()
+        || compiler_options.optimization.inner_graph))
+      .then(|| {
+        ast.visit(|program, _| {
+          NoSideEffectsFunctions::new(
+            program.get_inner_program(),
+            &import_map,
+            program.comments.as_ref(),
+          )
+        })
+      })
+      .unwrap_or_default();
+    build_info.no_side_effects_functions = no_side_effects.exports.clone();
+
     if compiler_options.is_new_tree_shaking()
       && compiler_options.optimization.side_effects.is_true()
     {
@@ -231,9 +250,26 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         let mut visitor = SideEffectsFlagPluginVisitor::new(
           SyntaxContextInfo::new(unresolved_ctxt),
           program.comments.as_ref(),
+          &no_side_effects,
         );
         program.visit_with(&mut visitor);
-        build_meta.side_effect_free = Some(visitor.side_effects_span.is_none());
+        let side_effect_free = visitor.side_effects_span.is_none();
+        build_info.no_side_effects_calls = if side_effect_free {
+          dependencies
+            .iter()
+            .filter(|dep| {
+              visitor
+                .no_side_effects_calls
+                .iter()
+                .any(|span| dep.is_span_equal(span))
+            })
+            .map(|dep| *dep.id())
+            .collect()
+        } else {
+          vec![]
+        };
+        build_meta.side_effect_free =
+          Some(side_effect_free && build_info.no_side_effects_calls.is_empty());
       });
     }
 
@@ -250,6 +286,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
             &import_map,
             module_identifier,
             program.comments.take(),
+            &no_side_effects,
           );
           plugin.enable();
           program.visit_with(&mut plugin);
@@ -1,26 +1,35 @@
 use std::collections::hash_map::Entry;
 use std::collections::VecDeque;
+use std::sync::Arc;
 
 use rspack_core::{
-  BuildMetaExportsType, Compilation, DependenciesBlock, DependencyId, ExportInfoProvided,
-  ExportNameOrSpec, ExportsInfoId, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
-  ModuleGraphConnection, ModuleIdentifier, Plugin,
+  cache::ModuleMemCache, BuildMetaExportsType, Compilation, DependenciesBlock, DependencyId,
+  ExportInfoProvided, ExportNameOrSpec, ExportsInfoId, ExportsOfExportsSpec, ExportsSpec,
+  ModuleGraph, ModuleGraphConnection, ModuleIdentifier, Plugin, RestoreProvidedData,
 };
 use rspack_error::Result;
+use rspack_identifier::IdentifierMap;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
+const MEM_CACHE_KEY: &str = "FlagDependencyExportsPlugin";
+
 struct FlagDependencyExportsProxy<'a> {
   mg: &'a mut ModuleGraph,
+  module_mem_caches: Option<&'a IdentifierMap<Arc<ModuleMemCache>>>,
   changed: bool,
   current_module_id: ModuleIdentifier,
   dependencies: HashMap<ModuleIdentifier, HashSet<ModuleIdentifier>>,
 }
 
 impl<'a> FlagDependencyExportsProxy<'a> {
-  pub fn new(mg: &'a mut ModuleGraph) -> Self {
+  pub fn new(
+    mg: &'a mut ModuleGraph,
+    module_mem_caches: Option<&'a IdentifierMap<Arc<ModuleMemCache>>>,
+  ) -> Self {
     Self {
       mg,
+      module_mem_caches,
       changed: false,
       current_module_id: ModuleIdentifier::default(),
       dependencies: HashMap::default(),
@@ -29,6 +38,7 @@ impl<'a> FlagDependencyExportsProxy<'a> {
 
   pub fn apply(&mut self) {
     let mut q = VecDeque::new();
+    let mut modules_to_store = vec![];
 
     // take the ownership of module_identifier_to_module_graph_module to avoid borrow ref and
     // mut ref of `ModuleGraph` at the same time
@@ -68,9 +78,18 @@ impl<'a> FlagDependencyExportsProxy<'a> {
         continue;
       }
 
+      if let Some(data) = self
+        .module_mem_caches
+        .and_then(|caches| caches.get(&mgm.module_identifier))
+        .and_then(|mem_cache| mem_cache.get::<RestoreProvidedData>(MEM_CACHE_KEY))
+      {
+        exports_id.restore_provided(self.mg, &data);
+        continue;
+      }
+
       exports_id.set_has_provide_info(self.mg);
       q.push_back(mgm.module_identifier);
-      // TODO: mem cache
+      modules_to_store.push(mgm.module_identifier);
     }
     self.mg.module_identifier_to_module_graph_module = module_graph_modules;
     while let Some(module_id) = q.pop_back() {
@@ -87,6 +106,24 @@ impl<'a> FlagDependencyExportsProxy<'a> {
         self.notify_dependencies(&mut q);
       }
     }
+
+    if let Some(module_mem_caches) = self.module_mem_caches {
+      for module_id in modules_to_store {
+        let Some(mem_cache) = module_mem_caches.get(&module_id) else {
+          continue;
+        };
+        let exports_info_id = self.mg.get_exports_info(&module_id).id;
+        // the restored data doesn't carry the targets of the reexports, so keep processing the
+        // modules that reexport
+        if has_export_targets(exports_info_id, self.mg) {
+          continue;
+        }
+        mem_cache.set(
+          MEM_CACHE_KEY,
+          exports_info_id.get_restore_provided_data(self.mg),
+        );
+      }
+    }
   }
 
   pub fn notify_dependencies(&mut self, q: &mut VecDeque<ModuleIdentifier>) {
@@ -208,41 +245,52 @@ impl<'a> FlagDependencyExportsProxy<'a> {
   ) {
     for export_name_or_spec in exports {
       // dbg!(&export_name_or_spec);
-      let (name, can_mangle, terminal_binding, exports, from, from_export, priority, hidden) =
-        match export_name_or_spec {
-          ExportNameOrSpec::String(name) => (
-            name.clone(),
-            global_export_info.can_mangle,
-            global_export_info.terminal_binding,
-            None::<&Vec<ExportNameOrSpec>>,
-            global_export_info.from.cloned(),
-            None::<&rspack_core::Nullable<Vec<Atom>>>,
-            global_export_info.priority,
-            false,
-          ),
-          ExportNameOrSpec::ExportSpec(spec) => (
-            spec.name.clone(),
-            match spec.can_mangle {
-              Some(v) => Some(v),
-              None => global_export_info.can_mangle,
-            },
-            spec
-              .terminal_binding
-              .unwrap_or(global_export_info.terminal_binding),
-            spec.exports.as_ref(),
-            if spec.from.is_some() {
-              spec.from
-            } else {
-              global_export_info.from.cloned()
-            },
-            spec.export.as_ref(),
-            match spec.priority {
-              Some(v) => Some(v),
-              None => global_export_info.priority,
-            },
-            spec.hidden.unwrap_or(false),
-          ),
-        };
+      let (
+        name,
+        can_mangle,
+        terminal_binding,
+        exports,
+        from,
+        from_export,
+        priority,
+        hidden,
+        inlinable,
+      ) = match export_name_or_spec {
+        ExportNameOrSpec::String(name) => (
+          name.clone(),
+          global_export_info.can_mangle,
+          global_export_info.terminal_binding,
+          None::<&Vec<ExportNameOrSpec>>,
+          global_export_info.from.cloned(),
+          None::<&rspack_core::Nullable<Vec<Atom>>>,
+          global_export_info.priority,
+          false,
+          None,
+        ),
+        ExportNameOrSpec::ExportSpec(spec) => (
+          spec.name.clone(),
+          match spec.can_mangle {
+            Some(v) => Some(v),
+            None => global_export_info.can_mangle,
+          },
+          spec
+            .terminal_binding
+            .unwrap_or(global_export_info.terminal_binding),
+          spec.exports.as_ref(),
+          if spec.from.is_some() {
+            spec.from
+          } else {
+            global_export_info.from.cloned()
+          },
+          spec.export.as_ref(),
+          match spec.priority {
+            Some(v) => Some(v),
+            None => global_export_info.priority,
+          },
+          spec.hidden.unwrap_or(false),
+          spec.inlinable.as_ref(),
+        ),
+      };
       let export_info_id = exports_info.get_export_info(&name, self.mg);
 
       let export_info_mut = export_info_id.get_export_info_mut(self.mg);
@@ -266,6 +314,13 @@ impl<'a> FlagDependencyExportsProxy<'a> {
         self.changed = true;
       }
 
+      if let Some(inlinable) = inlinable
+        && export_info_mut.inlinable.as_ref() != Some(inlinable)
+      {
+        export_info_mut.inlinable = Some(inlinable.clone());
+        self.changed = true;
+      }
+
       if let Some(exports) = exports {
         let nested_exports_info = export_info_id.create_nested_exports_info(self.mg);
         self.merge_exports(
@@ -345,8 +400,27 @@ pub struct FlagDependencyExportsPlugin;
 #[async_trait::async_trait]
 impl Plugin for FlagDependencyExportsPlugin {
   async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
-    let mut proxy = FlagDependencyExportsProxy::new(&mut compilation.module_graph);
+    let mut proxy = FlagDependencyExportsProxy::new(
+      &mut compilation.module_graph,
+      compilation.module_mem_caches.as_ref(),
+    );
     proxy.apply();
     Ok(())
   }
 }
+
+fn has_export_targets(exports_info_id: ExportsInfoId, mg: &ModuleGraph) -> bool {
+  let exports_info = exports_info_id.get_exports_info(mg);
+  exports_info
+    .exports
+    .values()
+    .chain([&exports_info.other_exports_info])
+    .any(|export_info_id| {
+      let export_info = export_info_id.get_export_info(mg);
+      !export_info.target.is_empty()
+        || (export_info.exports_info_owned
+          && export_info
+            .exports_info
+            .is_some_and(|exports_info| has_export_targets(exports_info, mg)))
+    })
+}
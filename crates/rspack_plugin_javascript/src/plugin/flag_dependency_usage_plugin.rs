@@ -1,10 +1,11 @@
-use std::collections::hash_map::Entry;
+use std::collections::hash_map::{DefaultHasher, Entry};
 use std::collections::VecDeque;
+use std::hash::Hasher;
 
 use rspack_core::{
-  is_exports_object_referenced, is_no_exports_referenced, merge_runtime, AsyncDependenciesBlockId,
-  BuildMetaExportsType, Compilation, ConnectionState, DependenciesBlock, DependencyId,
-  ExportsInfoId, ExtendedReferencedExport, GroupOptions, ModuleIdentifier, Plugin,
+  get_runtime_key, is_exports_object_referenced, is_no_exports_referenced, merge_runtime,
+  AsyncDependenciesBlockId, BuildMetaExportsType, Compilation, ConnectionState, DependenciesBlock,
+  DependencyId, ExportsInfoId, ExtendedReferencedExport, GroupOptions, ModuleIdentifier, Plugin,
   ReferencedExport, RuntimeSpec, UsageState,
 };
 use rspack_error::Result;
@@ -12,6 +13,10 @@ use rspack_identifier::IdentifierMap;
 use rspack_util::swc::join_atom;
 use rustc_hash::FxHashMap as HashMap;
 
+const MEM_CACHE_KEY: &str = "FlagDependencyUsagePlugin";
+
+type ReferencedModules = Vec<(ModuleIdentifier, Vec<ExtendedReferencedExport>)>;
+
 #[derive(Debug)]
 enum ModuleOrAsyncDependenciesBlock {
   Module(ModuleIdentifier),
@@ -107,6 +112,47 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
       ExtendRef(Vec<ExtendedReferencedExport>),
     }
 
+    // The exports a module references depend on the module, the modules it references and its
+    // own usage, so they can be reused from the memory cache when its usage is unchanged.
+    let mem_cache = match block_id {
+      ModuleOrAsyncDependenciesBlock::Module(module) => self
+        .compilation
+        .module_mem_caches
+        .as_ref()
+        .and_then(|caches| caches.get(&module))
+        .cloned()
+        .map(|mem_cache| {
+          let mg = &self.compilation.module_graph;
+          let mut hasher = DefaultHasher::new();
+          mg.get_exports_info(&module)
+            .id
+            .update_hash(mg, &mut hasher, runtime.as_ref());
+          let key = (
+            MEM_CACHE_KEY,
+            runtime.clone().map(get_runtime_key),
+            hasher.finish(),
+          );
+          (mem_cache, key)
+        }),
+      ModuleOrAsyncDependenciesBlock::AsyncDependenciesBlock(_) => None,
+    };
+    if let Some((mem_cache, key)) = &mem_cache
+      && let Some(referenced_modules) = mem_cache.get::<ReferencedModules>(key)
+    {
+      for (module_id, referenced_exports) in referenced_modules.iter() {
+        self.process_referenced_module(
+          *module_id,
+          referenced_exports.clone(),
+          runtime.clone(),
+          force_side_effects,
+          q,
+        );
+      }
+      return;
+    }
+    // only the modules without nested processing are cached
+    let mut cacheable = mem_cache.is_some();
+
     let mut map: IdentifierMap<ProcessModuleReferencedExports> = IdentifierMap::default();
     let mut queue = VecDeque::new();
     queue.push_back(block_id);
@@ -144,6 +190,7 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
             .runtime
             .as_ref()
             .map(|runtime| RuntimeSpec::from_iter([runtime.as_str().into()]));
+          cacheable = false;
           self.process_module(
             ModuleOrAsyncDependenciesBlock::AsyncDependenciesBlock(block_id),
             runtime,
@@ -182,6 +229,7 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
             continue;
           }
           ConnectionState::TransitiveOnly => {
+            cacheable = false;
             self.process_module(
               ModuleOrAsyncDependenciesBlock::Module(connection.module_identifier),
               runtime.clone(),
@@ -284,12 +332,16 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
       }
     }
 
+    let mut referenced_modules = cacheable.then(ReferencedModules::default);
     for (module_id, referenced_exports) in map {
       // dbg!(&module_id, &referenced_exports);
       let normalized_refs = match referenced_exports {
         ProcessModuleReferencedExports::Map(map) => map.into_values().collect::<Vec<_>>(),
         ProcessModuleReferencedExports::ExtendRef(extend_ref) => extend_ref,
       };
+      if let Some(referenced_modules) = &mut referenced_modules {
+        referenced_modules.push((module_id, normalized_refs.clone()));
+      }
       self.process_referenced_module(
         module_id,
         normalized_refs,
@@ -298,6 +350,9 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
         q,
       );
     }
+    if let (Some((mem_cache, key)), Some(referenced_modules)) = (mem_cache, referenced_modules) {
+      mem_cache.set(key, referenced_modules);
+    }
   }
 
   fn process_entry_dependency(
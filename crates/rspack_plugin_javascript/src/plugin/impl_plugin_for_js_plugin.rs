@@ -5,15 +5,17 @@ use async_trait::async_trait;
 use rspack_core::rspack_sources::BoxSource;
 use rspack_core::{
   get_js_chunk_filename_template, AdditionalChunkRuntimeRequirementsArgs, ChunkGraph,
-  ChunkHashArgs, ChunkKind, ChunkUkey, CompilationArgs, CompilationParams, CompilerOptions,
-  DependencyType, ErrorSpan, IgnoreErrorModuleFactory, ModuleGraph, ModuleType, ParserAndGenerator,
-  PathData, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginChunkHashHookOutput,
-  PluginCompilationHookOutput, PluginContext, PluginRenderManifestHookOutput, RenderManifestEntry,
-  RuntimeGlobals, SelfModuleFactory, SourceType,
+  ChunkHashArgs, ChunkKind, ChunkUkey, Compilation, CompilationArgs, CompilationParams,
+  CompilerOptions, DependencyType, ErrorSpan, IgnoreErrorModuleFactory, ModuleGraph, ModuleType,
+  ParserAndGenerator, PathData, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  PluginChunkHashHookOutput, PluginCompilationHookOutput, PluginContext,
+  PluginRenderManifestHookOutput, RenderManifestEntry, RuntimeGlobals, SelfModuleFactory,
+  SourceType,
 };
 use rspack_error::{IntoTWithDiagnosticArray, Result};
 use rspack_hash::RspackHash;
 
+use crate::dependency::HarmonyImportSpecifierDependency;
 use crate::parser_and_generator::JavaScriptParserAndGenerator;
 use crate::JsPlugin;
 
@@ -146,6 +148,29 @@ impl Plugin for JsPlugin {
     Ok(())
   }
 
+  // Warn about the imports which are not exported, webpack reports them in `finishModules`,
+  // which runs twice with the new tree shaking
+  async fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<Option<()>> {
+    let exports_flagged = compilation.options.is_new_tree_shaking()
+      && compilation.options.optimization.provided_exports;
+    let module_graph = &compilation.module_graph;
+    let mut diagnostics = vec![];
+    for (module_identifier, module) in module_graph.modules() {
+      diagnostics.extend(
+        module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter_map(|dep| dep.downcast_ref::<HarmonyImportSpecifierDependency>())
+          .filter_map(|dep| {
+            dep.get_export_not_found_warning(module_graph, module_identifier, exports_flagged)
+          }),
+      );
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(None)
+  }
+
   async fn chunk_hash(
     &self,
     _ctx: PluginContext,
@@ -1,6 +1,6 @@
 use std::{collections::hash_map::Entry, hash::Hash};
 
-use rspack_core::{Dependency, ModuleIdentifier, SpanExt, UsedByExports};
+use rspack_core::{Dependency, DependencyId, ModuleIdentifier, SpanExt, UsedByExports};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::{
   common::{Span, Spanned, SyntaxContext},
@@ -20,6 +20,7 @@ use crate::{
   dependency::{PureExpressionDependency, DEFAULT_EXPORT},
   is_pure_class, is_pure_class_member,
   plugin::side_effects_flag_plugin::is_pure_expression,
+  plugin::side_effects_flag_plugin::NoSideEffectsFunctions,
   visitors::{harmony_import_dependency_scanner::ImportMap, ExtraSpanInfo},
   ClassExt,
 };
@@ -28,6 +29,8 @@ use crate::{
 pub enum InnerGraphMapSetValue {
   TopLevel(Atom),
   Str(Atom),
+  /// A call to an imported function that is assumed to be a `#__NO_SIDE_EFFECTS__` function.
+  Call(DependencyId),
 }
 
 /// You need to make sure that InnerGraphMapUsage is not a  [InnerGraphMapUsage::True] variant
@@ -36,20 +39,12 @@ impl From<InnerGraphMapUsage> for InnerGraphMapSetValue {
     match value {
       InnerGraphMapUsage::TopLevel(str) => Self::TopLevel(str),
       InnerGraphMapUsage::Value(str) => Self::Str(str),
+      InnerGraphMapUsage::Call(dep_id) => Self::Call(dep_id),
       InnerGraphMapUsage::True => unreachable!(""),
     }
   }
 }
 
-impl InnerGraphMapSetValue {
-  fn to_atom(&self) -> &Atom {
-    match self {
-      InnerGraphMapSetValue::TopLevel(v) => v,
-      InnerGraphMapSetValue::Str(v) => v,
-    }
-  }
-}
-
 #[derive(Default, Clone, PartialEq, Eq, Debug)]
 pub enum InnerGraphMapValue {
   Set(HashSet<InnerGraphMapSetValue>),
@@ -62,6 +57,7 @@ pub enum InnerGraphMapValue {
 pub enum InnerGraphMapUsage {
   TopLevel(Atom),
   Value(Atom),
+  Call(DependencyId),
   True,
 }
 
@@ -86,6 +82,7 @@ pub struct InnerGraphPlugin<'a> {
   in_named: bool,
   top_level_ctxt_set: HashSet<SyntaxContext>,
   pub comments: Option<SwcComments>,
+  no_side_effects: &'a NoSideEffectsFunctions,
 }
 
 impl<'a> Visit for InnerGraphPlugin<'a> {
@@ -155,8 +152,17 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
       key.visit_with(self);
     };
     let is_static = node.is_static();
-    if !is_static || is_pure_class_member(node, self.unresolved_ctxt, self.comments.as_ref()) {
+    let is_pure = !is_static
+      || is_pure_class_member(
+        node,
+        self.unresolved_ctxt,
+        self.comments.as_ref(),
+        self.no_side_effects,
+      );
+    let calls = self.no_side_effects.take_calls();
+    if is_pure {
       self.set_top_level_symbol(previous_top_level_symbol.clone());
+      self.add_no_side_effects_calls_usage(calls);
       if is_static && !matches!(node, ClassMember::Method(_) | ClassMember::PrivateMethod(_)) {
         let span = match node {
           ClassMember::Constructor(_) => unreachable!(),
@@ -261,11 +267,18 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
     if !self.is_enabled() {
       return;
     }
-    let is_pure_class = is_pure_class(&node.class, self.unresolved_ctxt, self.comments.as_ref());
-    if is_pure_class {
+    let is_pure_class = is_pure_class(
+      &node.class,
+      self.unresolved_ctxt,
+      self.comments.as_ref(),
+      self.no_side_effects,
+    );
+    let calls = self.no_side_effects.take_calls();
+    let is_toplevel = self.is_toplevel();
+    if is_pure_class && is_toplevel {
       self.set_symbol_if_is_top_level(node.ident.sym.clone());
+      self.add_no_side_effects_calls_usage(calls);
     }
-    let is_toplevel = self.is_toplevel();
     let scope_level = self.scope_level;
     self.scope_level += 1;
     if is_toplevel {
@@ -349,17 +362,31 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
           self.clear_symbol_if_is_top_level();
         }
         Expr::Class(class) => {
-          let is_pure = is_pure_class(&class.class, self.unresolved_ctxt, self.comments.as_ref());
+          let is_pure = is_pure_class(
+            &class.class,
+            self.unresolved_ctxt,
+            self.comments.as_ref(),
+            self.no_side_effects,
+          );
+          let calls = self.no_side_effects.take_calls();
           if is_pure {
             self.set_symbol_if_is_top_level(symbol);
+            self.add_no_side_effects_calls_usage(calls);
           }
           class.visit_with(self);
           self.clear_symbol_if_is_top_level();
         }
         _ => {
-          init.visit_children_with(self);
-          if is_pure_expression(init, self.unresolved_ctxt, self.comments.as_ref()) {
+          let is_pure = is_pure_expression(
+            init,
+            self.unresolved_ctxt,
+            self.comments.as_ref(),
+            self.no_side_effects,
+          );
+          let calls = self.no_side_effects.take_calls();
+          if is_pure {
             self.set_symbol_if_is_top_level(symbol);
+            self.add_no_side_effects_calls_usage(calls);
             let start = init.span().real_lo();
             let end = init.span().real_hi();
             let module_identifier = self.state.module_identifier;
@@ -373,7 +400,10 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
                 }
               },
             ));
+            init.visit_children_with(self);
             self.clear_symbol_if_is_top_level();
+          } else {
+            init.visit_children_with(self);
           }
         }
       }
@@ -452,16 +482,31 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
         self.clear_symbol_if_is_top_level();
       }
       Expr::Class(ref class) => {
-        let is_pure = is_pure_class(&class.class, self.unresolved_ctxt, self.comments.as_ref());
+        let is_pure = is_pure_class(
+          &class.class,
+          self.unresolved_ctxt,
+          self.comments.as_ref(),
+          self.no_side_effects,
+        );
+        let calls = self.no_side_effects.take_calls();
         if is_pure {
           self.set_symbol_if_is_top_level(DEFAULT_EXPORT.into());
+          self.add_no_side_effects_calls_usage(calls);
         }
         class.visit_with(self);
         self.clear_symbol_if_is_top_level();
       }
       _ => {
-        if is_pure_expression(expr, self.unresolved_ctxt, self.comments.as_ref()) {
+        let is_pure = is_pure_expression(
+          expr,
+          self.unresolved_ctxt,
+          self.comments.as_ref(),
+          self.no_side_effects,
+        );
+        let calls = self.no_side_effects.take_calls();
+        if is_pure {
           self.set_symbol_if_is_top_level(DEFAULT_EXPORT.into());
+          self.add_no_side_effects_calls_usage(calls);
           let start = expr.span().real_lo();
           let end = expr.span().real_hi();
           let module_identifier = self.state.module_identifier;
@@ -508,8 +553,16 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
     self.set_symbol_if_is_top_level(ident);
     match &node.decl {
       DefaultDecl::Class(class) => {
-        let is_pure = is_pure_class(&class.class, self.unresolved_ctxt, self.comments.as_ref());
-        if !is_pure {
+        let is_pure = is_pure_class(
+          &class.class,
+          self.unresolved_ctxt,
+          self.comments.as_ref(),
+          self.no_side_effects,
+        );
+        let calls = self.no_side_effects.take_calls();
+        if is_pure {
+          self.add_no_side_effects_calls_usage(calls);
+        } else {
           self.set_top_level_symbol(None);
         }
         class.visit_with(self);
@@ -525,6 +578,7 @@ impl<'a> Visit for InnerGraphPlugin<'a> {
 }
 
 impl<'a> InnerGraphPlugin<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     dependencies: &'a mut Vec<Box<dyn Dependency>>,
     unresolved_ctxt: SyntaxContext,
@@ -533,6 +587,7 @@ impl<'a> InnerGraphPlugin<'a> {
     import_map: &'a ImportMap,
     module_identifier: ModuleIdentifier,
     comments: Option<SwcComments>,
+    no_side_effects: &'a NoSideEffectsFunctions,
   ) -> Self {
     Self {
       dependencies,
@@ -547,6 +602,7 @@ impl<'a> InnerGraphPlugin<'a> {
       comments,
       in_named: false,
       top_level_ctxt_set: HashSet::from_iter([top_level_ctxt]),
+      no_side_effects,
     }
   }
 
@@ -580,7 +636,9 @@ impl<'a> InnerGraphPlugin<'a> {
           .inner_graph
           .insert(symbol, InnerGraphMapValue::True);
       }
-      InnerGraphMapUsage::Value(_) | InnerGraphMapUsage::TopLevel(_) => {
+      InnerGraphMapUsage::Value(_)
+      | InnerGraphMapUsage::TopLevel(_)
+      | InnerGraphMapUsage::Call(_) => {
         // SAFETY: we can make sure that the usage is not a `InnerGraphMapSetValue::True` variant.
         let set_value: InnerGraphMapSetValue = usage.into();
         match self.state.inner_graph.entry(symbol) {
@@ -627,10 +685,36 @@ impl<'a> InnerGraphPlugin<'a> {
     }
   }
 
+  /// The current top level symbol is only pure when the imported functions it calls are
+  /// `#__NO_SIDE_EFFECTS__` functions, so it's also used when any of them isn't.
+  fn add_no_side_effects_calls_usage(&mut self, calls: Vec<Span>) {
+    let Some(symbol) = self.get_top_level_symbol() else {
+      return;
+    };
+    for span in calls {
+      let usage = match self
+        .dependencies
+        .iter()
+        .find(|dep| dep.is_span_equal(&span))
+      {
+        Some(dep) => InnerGraphMapUsage::Call(*dep.id()),
+        None => InnerGraphMapUsage::True,
+      };
+      self.add_usage(symbol.clone(), usage);
+    }
+  }
+
   pub fn visit_class_custom(&mut self, class: &Class) {
     if let Some(super_class) = &class.super_class
-      && is_pure_expression(super_class, self.unresolved_ctxt, self.comments.as_ref())
+      && is_pure_expression(
+        super_class,
+        self.unresolved_ctxt,
+        self.comments.as_ref(),
+        self.no_side_effects,
+      )
     {
+      let calls = self.no_side_effects.take_calls();
+      self.add_no_side_effects_calls_usage(calls);
       let start = super_class.span().real_lo();
       let end = super_class.span().real_hi();
       let module_identifier = self.state.module_identifier;
@@ -645,6 +729,8 @@ impl<'a> InnerGraphPlugin<'a> {
         },
       ));
     }
+    // drop the calls of the super class that has side effects
+    self.no_side_effects.take_calls();
     class.visit_children_with(self);
   }
 
@@ -698,8 +784,8 @@ impl<'a> InnerGraphPlugin<'a> {
           }
           for name in names {
             match name {
-              InnerGraphMapSetValue::Str(v) => {
-                new_set.insert(InnerGraphMapSetValue::Str(v.clone()));
+              InnerGraphMapSetValue::Str(_) | InnerGraphMapSetValue::Call(_) => {
+                new_set.insert(name.clone());
               }
               InnerGraphMapSetValue::TopLevel(v) => {
                 let item_value = state.inner_graph.get(v);
@@ -785,8 +871,22 @@ impl<'a> InnerGraphPlugin<'a> {
         let used_by_exports = if let Some(usage) = usage {
           match usage {
             InnerGraphMapValue::Set(set) => {
-              let finalized_set = HashSet::from_iter(set.iter().map(|item| item.to_atom().clone()));
-              UsedByExports::Set(finalized_set)
+              let mut finalized_set = HashSet::default();
+              let mut calls = vec![];
+              for item in set {
+                match item {
+                  InnerGraphMapSetValue::TopLevel(v) | InnerGraphMapSetValue::Str(v) => {
+                    finalized_set.insert(v.clone());
+                  }
+                  InnerGraphMapSetValue::Call(dep_id) => calls.push(*dep_id),
+                }
+              }
+              if calls.is_empty() {
+                UsedByExports::Set(finalized_set)
+              } else {
+                calls.sort_unstable();
+                UsedByExports::SetOrCalls(finalized_set, calls)
+              }
             }
             InnerGraphMapValue::True => UsedByExports::Bool(true),
             InnerGraphMapValue::Nil => UsedByExports::Bool(false),
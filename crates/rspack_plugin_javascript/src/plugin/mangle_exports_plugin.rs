@@ -1,3 +1,6 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -8,6 +11,7 @@ use rspack_core::{
 use rspack_error::Result;
 use rspack_ids::id_helpers::assign_deterministic_ids;
 use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::ecma::atoms::Atom;
 
 use crate::utils::mangle_exports::{
   number_to_identifier, NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS, NUMBER_OF_IDENTIFIER_START_CHARS,
@@ -47,8 +51,10 @@ impl MangleExportsPlugin {
 #[async_trait]
 impl Plugin for MangleExportsPlugin {
   async fn optimize_code_generation(&self, compilation: &mut Compilation) -> Result<Option<()>> {
-    // TODO: should bailout if compilation.moduleMemCache is enable, https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/MangleExportsPlugin.js#L160-L164
-    // We don't do that cause we don't have this option
+    // webpack bails out with moduleMemCache, https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/MangleExportsPlugin.js#L160-L164
+    // We don't need to, the code generation memory cache is keyed by the mangled names of the
+    // referenced modules, so the mangled names are cached per module here.
+    let module_mem_caches = compilation.module_mem_caches.as_ref();
     let mg = &mut compilation.module_graph;
     let module_id_list = mg
       .module_identifier_to_module
@@ -68,12 +74,100 @@ impl Plugin for MangleExportsPlugin {
         .map(|meta| matches!(meta.exports_type, BuildMetaExportsType::Namespace))
         .unwrap_or_default();
       let exports_info_id = mgm.exports;
+      let Some(mem_cache) = module_mem_caches.and_then(|caches| caches.get(&identifier)) else {
+        mangle_exports_info(mg, self.deterministic, exports_info_id, is_namespace);
+        continue;
+      };
+      let mut hasher = DefaultHasher::new();
+      hash_mangle_info(mg, exports_info_id, &mut hasher);
+      let key = (
+        MEM_CACHE_KEY,
+        self.deterministic,
+        is_namespace,
+        hasher.finish(),
+      );
+      if let Some(used_names) = mem_cache.get::<Vec<(Vec<Atom>, Atom)>>(key) {
+        restore_used_names(mg, exports_info_id, &used_names);
+        continue;
+      }
       mangle_exports_info(mg, self.deterministic, exports_info_id, is_namespace);
+      let mut used_names = vec![];
+      collect_used_names(mg, exports_info_id, &mut vec![], &mut used_names);
+      mem_cache.set(key, used_names);
     }
     Ok(None)
   }
 }
 
+const MEM_CACHE_KEY: &str = "MangleExportsPlugin";
+
+/// Hash everything `mangle_exports_info` reads from the exports info.
+fn hash_mangle_info(mg: &ModuleGraph, exports_info_id: ExportsInfoId, hasher: &mut DefaultHasher) {
+  let exports_info = exports_info_id.get_exports_info(mg);
+  exports_info
+    .other_exports_info
+    .get_used(mg, None)
+    .hash(hasher);
+  for export_info_id in exports_info.exports.values() {
+    let export_info = export_info_id.get_export_info(mg);
+    export_info.name.hash(hasher);
+    export_info.has_used_name().hash(hasher);
+    export_info.can_mangle_provide.hash(hasher);
+    export_info.can_mangle_use.hash(hasher);
+    export_info.provided.hash(hasher);
+    export_info.get_used(None).hash(hasher);
+    export_info.exports_info_owned.hash(hasher);
+    if export_info.exports_info_owned
+      && let Some(nested_exports_info) = export_info.exports_info
+    {
+      hash_mangle_info(mg, nested_exports_info, hasher);
+    }
+  }
+}
+
+fn collect_used_names(
+  mg: &ModuleGraph,
+  exports_info_id: ExportsInfoId,
+  path: &mut Vec<Atom>,
+  used_names: &mut Vec<(Vec<Atom>, Atom)>,
+) {
+  for (name, export_info_id) in &exports_info_id.get_exports_info(mg).exports {
+    let export_info = export_info_id.get_export_info(mg);
+    path.push(name.clone());
+    if let Some(used_name) = export_info.used_name() {
+      used_names.push((path.clone(), used_name.clone()));
+    }
+    if export_info.exports_info_owned
+      && let Some(nested_exports_info) = export_info.exports_info
+    {
+      collect_used_names(mg, nested_exports_info, path, used_names);
+    }
+    path.pop();
+  }
+}
+
+fn restore_used_names(
+  mg: &mut ModuleGraph,
+  exports_info_id: ExportsInfoId,
+  used_names: &[(Vec<Atom>, Atom)],
+) {
+  for (path, used_name) in used_names {
+    let mut export_info_id = None;
+    let mut exports_info_id = Some(exports_info_id);
+    for name in path {
+      export_info_id = exports_info_id
+        .and_then(|exports_info_id| exports_info_id.get_exports_info(mg).exports.get(name))
+        .copied();
+      exports_info_id = export_info_id.and_then(|id| id.get_export_info(mg).exports_info);
+    }
+    if let Some(export_info_id) = export_info_id
+      && !export_info_id.get_export_info(mg).has_used_name()
+    {
+      export_info_id.set_used_name(mg, used_name.clone());
+    }
+  }
+}
+
 /// Compare function for sorting exports by name.
 fn compare_strings_numeric(a: &ExportInfo, b: &ExportInfo) -> std::cmp::Ordering {
   a.name.cmp(&b.name)
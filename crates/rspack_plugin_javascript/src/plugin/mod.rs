@@ -214,14 +214,19 @@ impl JsPlugin {
 
           if !chunk_ids.is_empty() {
             startup.push(format!(
-              "{}{}(undefined, {} , function() {{ return __webpack_require__({module_id_expr}) }});",
+              "{}{}(undefined, {} , {});",
               if i + 1 == entries.len() {
                 "var __webpack_exports__ = "
               } else {
                 ""
               },
               RuntimeGlobals::ON_CHUNKS_LOADED,
-              stringify_array(&chunk_ids)
+              stringify_array(&chunk_ids),
+              compilation
+                .options
+                .output
+                .environment
+                .returning_function(&format!("__webpack_require__({module_id_expr})"), "")
             ));
           }
           /* if use_require */
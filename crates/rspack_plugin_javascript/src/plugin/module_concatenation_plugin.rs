@@ -7,19 +7,61 @@ use rspack_core::concatenated_module::{
   is_harmony_dep_like, ConcatenatedInnerModule, ConcatenatedModule, RootModuleContext,
 };
 use rspack_core::{
-  filter_runtime, merge_runtime, runtime_to_string, Compilation, CompilerContext,
-  ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module, ModuleExt,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, OptimizeChunksArgs, Plugin, ProvidedExports,
-  RuntimeCondition, RuntimeSpec, WrappedModuleIdentifier,
+  filter_runtime, merge_runtime, runtime_to_string, BuildMetaExportsType, Compilation,
+  CompilerContext, Context, ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger,
+  Module, ModuleExt, ModuleGraph, ModuleGraphModule, ModuleIdentifier, OptimizeChunksArgs, Plugin,
+  ProvidedExports, RuntimeCondition, RuntimeSpec, WrappedModuleIdentifier,
 };
 use rspack_error::Result;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-#[allow(unused)]
 fn format_bailout_reason(msg: &str) -> String {
   format!("ModuleConcatenation bailout: {}", msg)
 }
 
+fn format_bailout_warning(
+  module_graph: &ModuleGraph,
+  context: &Context,
+  module_id: &ModuleIdentifier,
+  problem: &Warning,
+) -> String {
+  let readable_identifier = |id: &ModuleIdentifier| {
+    module_graph
+      .module_by_identifier(id)
+      .expect("should have module")
+      .readable_identifier(context)
+      .to_string()
+  };
+  match problem {
+    Warning::Problem(problem) => format_bailout_reason(&format!(
+      "Cannot concat with {}: {}",
+      readable_identifier(module_id),
+      problem
+    )),
+    Warning::Id(problem_module) => {
+      let reason_with_prefix = module_graph
+        .get_optimization_bailout(module_id)
+        .last()
+        .map(|reason| format!(": {reason}"))
+        .unwrap_or_default();
+      if problem_module == module_id {
+        format_bailout_reason(&format!(
+          "Cannot concat with {}{}",
+          readable_identifier(module_id),
+          reason_with_prefix
+        ))
+      } else {
+        format_bailout_reason(&format!(
+          "Cannot concat with {} because of {}{}",
+          readable_identifier(module_id),
+          readable_identifier(problem_module),
+          reason_with_prefix
+        ))
+      }
+    }
+  }
+}
+
 #[derive(Clone)]
 enum Warning {
   Id(ModuleIdentifier),
@@ -72,10 +114,10 @@ impl ConcatConfiguration {
     self.warnings.insert(module, problem);
   }
 
-  fn get_warnings_sorted(&self) -> HashMap<ModuleIdentifier, Warning> {
+  fn get_warnings_sorted(&self) -> Vec<(ModuleIdentifier, Warning)> {
     let mut sorted_warnings: Vec<_> = self.warnings.clone().into_iter().collect();
     sorted_warnings.sort_by(|a, b| a.0.cmp(&b.0));
-    sorted_warnings.into_iter().collect()
+    sorted_warnings
   }
 
   fn get_modules(&self) -> &HashSet<ModuleIdentifier> {
@@ -103,6 +145,34 @@ impl ConcatConfiguration {
 pub struct ModuleConcatenationPlugin;
 
 impl ModuleConcatenationPlugin {
+  fn set_bailout_reason(
+    compilation: &mut Compilation,
+    logger: &impl Logger,
+    module_id: &ModuleIdentifier,
+    reason: String,
+  ) {
+    Self::set_inner_bailout_reason(&mut compilation.module_graph, module_id, &reason);
+    let module = compilation
+      .module_graph
+      .module_by_identifier(module_id)
+      .expect("should have module");
+    logger.debug(format!(
+      "{}: {}",
+      module.readable_identifier(&compilation.options.context),
+      reason
+    ));
+  }
+
+  fn set_inner_bailout_reason(
+    module_graph: &mut ModuleGraph,
+    module_id: &ModuleIdentifier,
+    reason: &str,
+  ) {
+    module_graph
+      .get_optimization_bailout_mut(module_id)
+      .push(format_bailout_reason(reason));
+  }
+
   pub fn get_imports(
     mg: &ModuleGraph,
     mi: WrappedModuleIdentifier,
@@ -466,11 +536,11 @@ impl ModuleConcatenationPlugin {
     //
     incoming_modules.sort();
     //
-    for _origin_module in &incoming_modules {
+    for origin_module in &incoming_modules {
       if let Some(problem) = Self::try_to_add(
         compilation,
         config,
-        module_id,
+        origin_module,
         runtime,
         active_runtime,
         possible_modules,
@@ -521,11 +591,33 @@ impl Plugin for ModuleConcatenationPlugin {
         .is_async(&module_id)
         .expect("should have async result")
       {
-        // TODO: bailout
+        Self::set_bailout_reason(
+          compilation,
+          &logger,
+          &module_id,
+          "Module is async".to_string(),
+        );
+        continue;
+      }
+      if !matches!(
+        m.build_meta().map(|meta| meta.exports_type),
+        Some(BuildMetaExportsType::Namespace)
+      ) {
+        Self::set_bailout_reason(
+          compilation,
+          &logger,
+          &module_id,
+          "Module is not an ECMAScript module".to_string(),
+        );
         continue;
       }
       if !m.build_info().expect("should have build info").strict {
-        // TODO: bailout
+        Self::set_bailout_reason(
+          compilation,
+          &logger,
+          &module_id,
+          "Module is not in strict mode".to_string(),
+        );
         continue;
       }
       if compilation
@@ -533,7 +625,12 @@ impl Plugin for ModuleConcatenationPlugin {
         .get_number_of_module_chunks(*module_id)
         == 0
       {
-        // TODO: bailout
+        Self::set_bailout_reason(
+          compilation,
+          &logger,
+          &module_id,
+          "Module is not in any chunk".to_string(),
+        );
         continue;
       }
       let exports_info = compilation.module_graph.get_exports_info(&module_id);
@@ -553,7 +650,26 @@ impl Plugin for ModuleConcatenationPlugin {
         .copied()
         .collect::<Vec<_>>();
       if !unknown_exports.is_empty() {
-        // TODO: bailout
+        let reason = format!(
+          "Reexports in this module do not have a static target ({})",
+          unknown_exports
+            .iter()
+            .map(|id| {
+              let export_info = id.get_export_info(&compilation.module_graph);
+              format!(
+                "{}: {}",
+                export_info
+                  .name
+                  .as_ref()
+                  .map(|name| name.to_string())
+                  .unwrap_or_else(|| "other exports".to_string()),
+                export_info.get_used_info()
+              )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+        );
+        Self::set_bailout_reason(compilation, &logger, &module_id, reason);
         continue;
       }
       let unknown_provided_exports = relevnat_epxorts
@@ -566,12 +682,36 @@ impl Plugin for ModuleConcatenationPlugin {
         .collect::<Vec<_>>();
 
       if !unknown_provided_exports.is_empty() {
-        // TODO: bailout
+        let reason = format!(
+          "List of module exports is dynamic ({})",
+          unknown_provided_exports
+            .iter()
+            .map(|id| {
+              let export_info = id.get_export_info(&compilation.module_graph);
+              format!(
+                "{}: {} and {}",
+                export_info
+                  .name
+                  .as_ref()
+                  .map(|name| name.to_string())
+                  .unwrap_or_else(|| "other exports".to_string()),
+                export_info.get_provided_info(),
+                export_info.get_used_info()
+              )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+        );
+        Self::set_inner_bailout_reason(&mut compilation.module_graph, &module_id, &reason);
         can_be_root = false;
       }
 
       if compilation.chunk_graph.is_entry_module(&module_id) {
-        // TODO: bailout
+        Self::set_inner_bailout_reason(
+          &mut compilation.module_graph,
+          &module_id,
+          "Module is an entry point",
+        );
         can_be_inner = false;
       }
       if can_be_root {
@@ -688,7 +828,22 @@ impl Plugin for ModuleConcatenationPlugin {
         concat_configurations.push(current_configuration);
       } else {
         stats_empty_configurations += 1;
-        // TODO: bailout
+        let reasons = current_configuration
+          .get_warnings_sorted()
+          .into_iter()
+          .map(|(module_id, problem)| {
+            format_bailout_warning(
+              &compilation.module_graph,
+              &compilation.options.context,
+              &module_id,
+              &problem,
+            )
+          })
+          .collect::<Vec<_>>();
+        compilation
+          .module_graph
+          .get_optimization_bailout_mut(current_root)
+          .extend(reasons);
       }
     }
     logger.time_end(start);
@@ -805,6 +960,8 @@ impl Plugin for ModuleConcatenationPlugin {
               process_dependencies_queue: compilation.process_dependencies_queue.clone(),
               build_time_execution_queue: compilation.build_time_execution_queue.clone(),
               plugin_driver: compilation.plugin_driver.clone(),
+              logging: compilation.get_logging().clone(),
+              emitted_assets: Default::default(),
             },
             plugin_driver: compilation.plugin_driver.clone(),
             compiler_options: &compilation.options,
@@ -825,6 +982,22 @@ impl Plugin for ModuleConcatenationPlugin {
       compilation
         .module_graph
         .clone_module_attributes(&root_module_id, &new_module.id());
+      let reasons = config
+        .get_warnings_sorted()
+        .into_iter()
+        .map(|(module_id, problem)| {
+          format_bailout_warning(
+            &compilation.module_graph,
+            &compilation.options.context,
+            &module_id,
+            &problem,
+          )
+        })
+        .collect::<Vec<_>>();
+      compilation
+        .module_graph
+        .get_optimization_bailout_mut(&new_module.id())
+        .extend(reasons);
       // integrate
       for m in modules_set {
         if m == &root_module_id {
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -12,8 +13,9 @@ use rspack_identifier::IdentifierSet;
 use rustc_hash::FxHashSet as HashSet;
 // use rspack_core::Plugin;
 // use rspack_error::Result;
-use swc_core::common::{comments, Span, Spanned, SyntaxContext, GLOBALS};
+use swc_core::common::{comments, BytePos, Span, Spanned, SyntaxContext, GLOBALS};
 use swc_core::ecma::ast::*;
+use swc_core::ecma::atoms::Atom;
 use swc_core::ecma::utils::{ExprCtx, ExprExt};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 use swc_node_comments::SwcComments;
@@ -21,12 +23,16 @@ use swc_node_comments::SwcComments;
 use crate::dependency::{
   HarmonyExportImportedSpecifierDependency, HarmonyImportSpecifierDependency,
 };
+use crate::visitors::harmony_import_dependency_scanner::ImportMap;
 
 pub struct SideEffectsFlagPluginVisitor<'a> {
   unresolved_ctxt: SyntaxContext,
   pub side_effects_span: Option<Span>,
   is_top_level: bool,
   comments: Option<&'a SwcComments>,
+  no_side_effects: &'a NoSideEffectsFunctions,
+  /// Callees of the calls to imported functions the module is only side effect free with.
+  pub no_side_effects_calls: Vec<Span>,
 }
 
 impl<'a> Debug for SideEffectsFlagPluginVisitor<'a> {
@@ -35,6 +41,7 @@ impl<'a> Debug for SideEffectsFlagPluginVisitor<'a> {
       .field("unresolved_ctxt", &self.unresolved_ctxt)
       .field("side_effects_span", &self.side_effects_span)
       .field("is_top_level", &self.is_top_level)
+      .field("no_side_effects_calls", &self.no_side_effects_calls)
       .finish()
   }
 }
@@ -51,12 +58,25 @@ impl SyntaxContextInfo {
 }
 
 impl<'a> SideEffectsFlagPluginVisitor<'a> {
-  pub fn new(mark_info: SyntaxContextInfo, comments: Option<&'a SwcComments>) -> Self {
+  pub fn new(
+    mark_info: SyntaxContextInfo,
+    comments: Option<&'a SwcComments>,
+    no_side_effects: &'a NoSideEffectsFunctions,
+  ) -> Self {
     Self {
       unresolved_ctxt: mark_info.unresolved_ctxt,
       side_effects_span: None,
       is_top_level: true,
       comments,
+      no_side_effects,
+      no_side_effects_calls: vec![],
+    }
+  }
+
+  fn collect_no_side_effects_calls(&mut self) {
+    let calls = self.no_side_effects.take_calls();
+    if self.side_effects_span.is_none() {
+      self.no_side_effects_calls.extend(calls);
     }
   }
 }
@@ -81,7 +101,12 @@ impl<'a> Visit for SideEffectsFlagPluginVisitor<'a> {
             decl.visit_with(self);
           }
           ModuleDecl::ExportDefaultExpr(expr) => {
-            if !is_pure_expression(&expr.expr, self.unresolved_ctxt, self.comments) {
+            if !is_pure_expression(
+              &expr.expr,
+              self.unresolved_ctxt,
+              self.comments,
+              self.no_side_effects,
+            ) {
               self.side_effects_span = Some(node.span);
             }
           }
@@ -93,12 +118,14 @@ impl<'a> Visit for SideEffectsFlagPluginVisitor<'a> {
         },
         ModuleItem::Stmt(stmt) => stmt.visit_with(self),
       }
+      self.collect_no_side_effects_calls();
     }
   }
 
   fn visit_script(&mut self, node: &Script) {
     for stmt in &node.body {
       stmt.visit_with(self);
+      self.collect_no_side_effects_calls();
     }
   }
 
@@ -111,7 +138,12 @@ impl<'a> Visit for SideEffectsFlagPluginVisitor<'a> {
   }
 
   fn visit_export_decl(&mut self, node: &ExportDecl) {
-    if !is_pure_decl(&node.decl, self.unresolved_ctxt, self.comments) {
+    if !is_pure_decl(
+      &node.decl,
+      self.unresolved_ctxt,
+      self.comments,
+      self.no_side_effects,
+    ) {
       self.side_effects_span = Some(node.decl.span());
     }
     node.visit_children_with(self);
@@ -160,29 +192,50 @@ impl<'a> SideEffectsFlagPluginVisitor<'a> {
     }
     match ele {
       Stmt::If(stmt) => {
-        if !is_pure_expression(&stmt.test, self.unresolved_ctxt, self.comments) {
+        if !is_pure_expression(
+          &stmt.test,
+          self.unresolved_ctxt,
+          self.comments,
+          self.no_side_effects,
+        ) {
           self.side_effects_span = Some(stmt.span);
         }
       }
       Stmt::While(stmt) => {
-        if !is_pure_expression(&stmt.test, self.unresolved_ctxt, self.comments) {
+        if !is_pure_expression(
+          &stmt.test,
+          self.unresolved_ctxt,
+          self.comments,
+          self.no_side_effects,
+        ) {
           self.side_effects_span = Some(stmt.span);
         }
       }
       Stmt::DoWhile(stmt) => {
-        if !is_pure_expression(&stmt.test, self.unresolved_ctxt, self.comments) {
+        if !is_pure_expression(
+          &stmt.test,
+          self.unresolved_ctxt,
+          self.comments,
+          self.no_side_effects,
+        ) {
           self.side_effects_span = Some(stmt.span);
         }
       }
       Stmt::For(stmt) => {
         let pure_init = match stmt.init {
           Some(ref init) => match init {
-            VarDeclOrExpr::VarDecl(decl) => {
-              is_pure_var_decl(decl, self.unresolved_ctxt, self.comments)
-            }
-            VarDeclOrExpr::Expr(expr) => {
-              is_pure_expression(expr, self.unresolved_ctxt, self.comments)
-            }
+            VarDeclOrExpr::VarDecl(decl) => is_pure_var_decl(
+              decl,
+              self.unresolved_ctxt,
+              self.comments,
+              self.no_side_effects,
+            ),
+            VarDeclOrExpr::Expr(expr) => is_pure_expression(
+              expr,
+              self.unresolved_ctxt,
+              self.comments,
+              self.no_side_effects,
+            ),
           },
           None => true,
         };
@@ -193,7 +246,12 @@ impl<'a> SideEffectsFlagPluginVisitor<'a> {
         }
 
         let pure_test = match stmt.test {
-          Some(box ref test) => is_pure_expression(test, self.unresolved_ctxt, self.comments),
+          Some(box ref test) => is_pure_expression(
+            test,
+            self.unresolved_ctxt,
+            self.comments,
+            self.no_side_effects,
+          ),
           None => true,
         };
 
@@ -203,7 +261,12 @@ impl<'a> SideEffectsFlagPluginVisitor<'a> {
         }
 
         let pure_update = match stmt.update {
-          Some(ref expr) => is_pure_expression(expr, self.unresolved_ctxt, self.comments),
+          Some(ref expr) => is_pure_expression(
+            expr,
+            self.unresolved_ctxt,
+            self.comments,
+            self.no_side_effects,
+          ),
           None => true,
         };
 
@@ -212,17 +275,32 @@ impl<'a> SideEffectsFlagPluginVisitor<'a> {
         }
       }
       Stmt::Expr(stmt) => {
-        if !is_pure_expression(&stmt.expr, self.unresolved_ctxt, self.comments) {
+        if !is_pure_expression(
+          &stmt.expr,
+          self.unresolved_ctxt,
+          self.comments,
+          self.no_side_effects,
+        ) {
           self.side_effects_span = Some(stmt.span);
         }
       }
       Stmt::Switch(stmt) => {
-        if !is_pure_expression(&stmt.discriminant, self.unresolved_ctxt, self.comments) {
+        if !is_pure_expression(
+          &stmt.discriminant,
+          self.unresolved_ctxt,
+          self.comments,
+          self.no_side_effects,
+        ) {
           self.side_effects_span = Some(stmt.span);
         }
       }
       Stmt::Decl(stmt) => {
-        if !is_pure_decl(stmt, self.unresolved_ctxt, self.comments) {
+        if !is_pure_decl(
+          stmt,
+          self.unresolved_ctxt,
+          self.comments,
+          self.no_side_effects,
+        ) {
           self.side_effects_span = Some(stmt.span());
         }
       }
@@ -236,10 +314,158 @@ impl<'a> SideEffectsFlagPluginVisitor<'a> {
 
 static PURE_COMMENTS: Lazy<regex::Regex> =
   Lazy::new(|| regex::Regex::new("^\\s*(#|@)__PURE__\\s*$").expect("Should create the regex"));
+static NO_SIDE_EFFECTS_COMMENTS: Lazy<regex::Regex> = Lazy::new(|| {
+  regex::Regex::new("^\\s*(#|@)__NO_SIDE_EFFECTS__\\s*$").expect("Should create the regex")
+});
+
+/// Functions annotated with `#__NO_SIDE_EFFECTS__`, calls to them are pure.
+///
+/// Whether an imported function is annotated is only known after the module graph is built, so
+/// calls to imported functions are assumed to be pure, and the callees are collected to be checked
+/// with [rspack_core::is_no_side_effects_call] later.
+#[derive(Debug, Default)]
+pub struct NoSideEffectsFunctions {
+  local: HashSet<Id>,
+  imported: HashSet<Id>,
+  /// Names of the exports that are annotated functions.
+  pub exports: HashSet<Atom>,
+  calls: RefCell<Vec<Span>>,
+}
+
+impl NoSideEffectsFunctions {
+  pub fn new(program: &Program, import_map: &ImportMap, comments: Option<&SwcComments>) -> Self {
+    let mut functions = Self {
+      imported: import_map.keys().cloned().collect(),
+      ..Default::default()
+    };
+    let Program::Module(module) = program else {
+      return functions;
+    };
+    let is_annotated = |pos: BytePos| {
+      comments
+        .and_then(|comments| comments.leading.get(&pos))
+        .is_some_and(|comment_list| {
+          comment_list.iter().any(|comment| {
+            comment.kind == comments::CommentKind::Block
+              && NO_SIDE_EFFECTS_COMMENTS.is_match(&comment.text)
+          })
+        })
+    };
+    let is_annotated_function = |expr: &Expr| {
+      matches!(expr.unwrap_parens(), Expr::Fn(_) | Expr::Arrow(_)) && is_annotated(expr.span_lo())
+    };
+    let annotated_decl = |decl: &Decl, annotated: bool| -> Vec<Id> {
+      match decl {
+        Decl::Fn(f) if annotated || is_annotated(f.function.span_lo()) => vec![f.ident.to_id()],
+        Decl::Var(var) => {
+          // the annotation before `const` only applies to a single declarator
+          let annotated = (annotated || is_annotated(var.span_lo())) && var.decls.len() == 1;
+          var
+            .decls
+            .iter()
+            .filter_map(|decl| {
+              let (Pat::Ident(ident), Some(init)) = (&decl.name, &decl.init) else {
+                return None;
+              };
+              let is_function = matches!(init.unwrap_parens(), Expr::Fn(_) | Expr::Arrow(_));
+              ((is_function && annotated) || is_annotated_function(init)).then(|| ident.to_id())
+            })
+            .collect()
+        }
+        _ => vec![],
+      }
+    };
+
+    for item in &module.body {
+      match item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          functions.local.extend(annotated_decl(decl, false));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+          for id in annotated_decl(&export.decl, is_annotated(export.span_lo())) {
+            functions.exports.insert(id.0.clone());
+            functions.local.insert(id);
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+          if let DefaultDecl::Fn(f) = &export.decl
+            && (is_annotated(export.span_lo()) || is_annotated(f.function.span_lo()))
+          {
+            functions.exports.insert("default".into());
+            if let Some(ident) = &f.ident {
+              functions.local.insert(ident.to_id());
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+    // the local annotated functions exported afterwards
+    for item in &module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) if export.src.is_none() => {
+          for specifier in &export.specifiers {
+            if let ExportSpecifier::Named(named) = specifier
+              && let ModuleExportName::Ident(orig) = &named.orig
+              && functions.local.contains(&orig.to_id())
+            {
+              let exported = match &named.exported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                Some(ModuleExportName::Str(str)) => str.value.clone(),
+                None => orig.sym.clone(),
+              };
+              functions.exports.insert(exported);
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+          let is_annotated_local = matches!(
+            export.expr.unwrap_parens(),
+            Expr::Ident(ident) if functions.local.contains(&ident.to_id())
+          );
+          if is_annotated_local || is_annotated_function(&export.expr) {
+            functions.exports.insert("default".into());
+          }
+        }
+        _ => {}
+      }
+    }
+    functions
+  }
+
+  fn is_no_side_effects_callee(&self, callee: &Callee) -> bool {
+    let Callee::Expr(callee) = callee else {
+      return false;
+    };
+    let imported = match &**callee {
+      Expr::Ident(ident) if self.local.contains(&ident.to_id()) => return true,
+      Expr::Ident(ident) => self.imported.contains(&ident.to_id()),
+      // `ns.fn()` with `import * as ns from '...'`
+      Expr::Member(MemberExpr {
+        obj: box Expr::Ident(obj),
+        prop: MemberProp::Ident(_),
+        ..
+      }) => self.imported.contains(&obj.to_id()),
+      _ => false,
+    };
+    if imported {
+      self.calls.borrow_mut().push(callee.span());
+    }
+    imported
+  }
+
+  /// Takes the callees of the calls to imported functions collected since the last time, which
+  /// are only meaningful when the expressions checked are pure.
+  pub fn take_calls(&self) -> Vec<Span> {
+    std::mem::take(&mut self.calls.borrow_mut())
+  }
+}
+
 fn is_pure_call_expr(
   call_expr: &CallExpr,
   unresolved_ctxt: SyntaxContext,
   comments: Option<&SwcComments>,
+  no_side_effects: &NoSideEffectsFunctions,
 ) -> bool {
   let callee = &call_expr.callee;
   let pure_flag = comments
@@ -253,7 +479,7 @@ fn is_pure_call_expr(
       }
     })
     .unwrap_or(false);
-  if !pure_flag {
+  if !pure_flag && !no_side_effects.is_no_side_effects_callee(callee) {
     let expr = Expr::Call(call_expr.clone());
     !expr.may_have_side_effects(&ExprCtx {
       unresolved_ctxt,
@@ -264,7 +490,7 @@ fn is_pure_call_expr(
       if arg.spread.is_some() {
         false
       } else {
-        is_pure_expression(&arg.expr, unresolved_ctxt, comments)
+        is_pure_expression(&arg.expr, unresolved_ctxt, comments, no_side_effects)
       }
     })
   }
@@ -274,9 +500,10 @@ pub fn is_pure_expression<'a>(
   expr: &'a Expr,
   unresolved_ctxt: SyntaxContext,
   comments: Option<&'a SwcComments>,
+  no_side_effects: &NoSideEffectsFunctions,
 ) -> bool {
   match expr {
-    Expr::Call(call) => is_pure_call_expr(call, unresolved_ctxt, comments),
+    Expr::Call(call) => is_pure_call_expr(call, unresolved_ctxt, comments, no_side_effects),
     _ => !expr.may_have_side_effects(&ExprCtx {
       unresolved_ctxt,
       is_unresolved_ref_safe: true,
@@ -288,13 +515,14 @@ pub fn is_pure_class_member<'a>(
   member: &'a ClassMember,
   unresolved_ctxt: SyntaxContext,
   comments: Option<&'a SwcComments>,
+  no_side_effects: &NoSideEffectsFunctions,
 ) -> bool {
   let is_key_pure = match member.class_key() {
     Some(PropName::Ident(_ident)) => true,
     Some(PropName::Str(_)) => true,
     Some(PropName::Num(_)) => true,
     Some(PropName::Computed(computed)) => {
-      is_pure_expression(&computed.expr, unresolved_ctxt, comments)
+      is_pure_expression(&computed.expr, unresolved_ctxt, comments, no_side_effects)
     }
     Some(PropName::BigInt(_)) => true,
     None => true,
@@ -309,14 +537,14 @@ pub fn is_pure_class_member<'a>(
     ClassMember::PrivateMethod(_) => true,
     ClassMember::ClassProp(prop) => {
       if let Some(ref value) = prop.value {
-        is_pure_expression(value, unresolved_ctxt, comments)
+        is_pure_expression(value, unresolved_ctxt, comments, no_side_effects)
       } else {
         true
       }
     }
     ClassMember::PrivateProp(ref prop) => {
       if let Some(ref value) = prop.value {
-        is_pure_expression(value, unresolved_ctxt, comments)
+        is_pure_expression(value, unresolved_ctxt, comments, no_side_effects)
       } else {
         true
       }
@@ -336,11 +564,12 @@ pub fn is_pure_decl(
   stmt: &Decl,
   unresolved_ctxt: SyntaxContext,
   comments: Option<&SwcComments>,
+  no_side_effects: &NoSideEffectsFunctions,
 ) -> bool {
   match stmt {
-    Decl::Class(class) => is_pure_class(&class.class, unresolved_ctxt, comments),
+    Decl::Class(class) => is_pure_class(&class.class, unresolved_ctxt, comments, no_side_effects),
     Decl::Fn(_) => true,
-    Decl::Var(var) => is_pure_var_decl(var, unresolved_ctxt, comments),
+    Decl::Var(var) => is_pure_var_decl(var, unresolved_ctxt, comments, no_side_effects),
     Decl::Using(_) => false,
     Decl::TsInterface(_) => unreachable!(),
     Decl::TsTypeAlias(_) => unreachable!(),
@@ -354,9 +583,10 @@ pub fn is_pure_class(
   class: &Class,
   unresolved_ctxt: SyntaxContext,
   comments: Option<&SwcComments>,
+  no_side_effects: &NoSideEffectsFunctions,
 ) -> bool {
   if let Some(ref super_class) = class.super_class {
-    if !is_pure_expression(super_class, unresolved_ctxt, comments) {
+    if !is_pure_expression(super_class, unresolved_ctxt, comments, no_side_effects) {
       return false;
     }
   }
@@ -364,7 +594,7 @@ pub fn is_pure_class(
     match key {
       PropName::BigInt(_) | PropName::Ident(_) | PropName::Str(_) | PropName::Num(_) => true,
       PropName::Computed(ref computed) => {
-        is_pure_expression(&computed.expr, unresolved_ctxt, comments)
+        is_pure_expression(&computed.expr, unresolved_ctxt, comments, no_side_effects)
       }
     }
   };
@@ -377,12 +607,13 @@ pub fn is_pure_class(
         &Expr::PrivateName(method.key.clone()),
         unresolved_ctxt,
         comments,
+        no_side_effects,
       ),
       ClassMember::ClassProp(prop) => {
         is_pure_key(&prop.key)
           && (!prop.is_static
             || if let Some(ref value) = prop.value {
-              is_pure_expression(value, unresolved_ctxt, comments)
+              is_pure_expression(value, unresolved_ctxt, comments, no_side_effects)
             } else {
               true
             })
@@ -392,9 +623,10 @@ pub fn is_pure_class(
           &Expr::PrivateName(prop.key.clone()),
           unresolved_ctxt,
           comments,
+          no_side_effects,
         ) && (!prop.is_static
           || if let Some(ref value) = prop.value {
-            is_pure_expression(value, unresolved_ctxt, comments)
+            is_pure_expression(value, unresolved_ctxt, comments, no_side_effects)
           } else {
             true
           })
@@ -411,10 +643,11 @@ fn is_pure_var_decl<'a>(
   var: &'a VarDecl,
   unresolved_ctxt: SyntaxContext,
   comments: Option<&'a SwcComments>,
+  no_side_effects: &NoSideEffectsFunctions,
 ) -> bool {
   var.decls.iter().all(|decl| {
     if let Some(ref init) = decl.init {
-      is_pure_expression(init, unresolved_ctxt, comments)
+      is_pure_expression(init, unresolved_ctxt, comments, no_side_effects)
     } else {
       true
     }
@@ -1,15 +1,17 @@
 use rspack_core::{
   tree_shaking::symbol::DEFAULT_JS_WORD, BoxDependency, BoxDependencyTemplate, BuildInfo,
-  ConstDependency, DependencyLocation, SpanExt,
+  ConstDependency, DependencyLocation, InlinableValue, SpanExt,
 };
 use rustc_hash::{FxHashMap as HashMap, FxHashSet};
 use swc_core::{
-  common::{Span, Spanned},
+  common::{Span, Spanned, SyntaxContext},
   ecma::{
     ast::{
       ClassDecl, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
-      ExportSpecifier, FnDecl, Ident, ModuleExportName, NamedExport, Program,
+      ExportSpecifier, Expr, FnDecl, Ident, Lit, ModuleDecl, ModuleExportName, ModuleItem,
+      NamedExport, Pat, Program, Stmt, UnaryOp, VarDecl, VarDeclKind,
     },
+    atoms::Atom,
     utils::{find_pat_ids, ExprFactory},
     visit::{noop_visit_type, Visit, VisitWith},
   },
@@ -34,9 +36,14 @@ pub struct HarmonyExportDependencyScanner<'a, 'b> {
   pub rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
   pub comments: Option<&'b SwcComments>,
   pub ignored: &'a mut FxHashSet<DependencyLocation>,
+  inline_const: bool,
+  unresolved_ctxt: SyntaxContext,
+  /// The top level constants bound to primitives, which `experiments.inlineConst` inlines
+  inlinable_consts: HashMap<Atom, InlinableValue>,
 }
 
 impl<'a, 'b> HarmonyExportDependencyScanner<'a, 'b> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
@@ -45,6 +52,8 @@ impl<'a, 'b> HarmonyExportDependencyScanner<'a, 'b> {
     rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
     comments: Option<&'b SwcComments>,
     ignored: &'a mut FxHashSet<DependencyLocation>,
+    inline_const: bool,
+    unresolved_ctxt: SyntaxContext,
   ) -> Self {
     Self {
       dependencies,
@@ -54,8 +63,68 @@ impl<'a, 'b> HarmonyExportDependencyScanner<'a, 'b> {
       rewrite_usage_span,
       comments,
       ignored,
+      inline_const,
+      unresolved_ctxt,
+      inlinable_consts: Default::default(),
     }
   }
+
+  fn collect_inlinable_consts(&mut self, var_decl: &VarDecl) {
+    if var_decl.kind != VarDeclKind::Const {
+      return;
+    }
+    for decl in &var_decl.decls {
+      if let Pat::Ident(ident) = &decl.name
+        && let Some(value) = decl
+          .init
+          .as_deref()
+          .and_then(|init| get_inlinable_value(init, self.unresolved_ctxt))
+      {
+        self.inlinable_consts.insert(ident.sym.clone(), value);
+      }
+    }
+  }
+}
+
+/// Strings are inlined into every module reading them, so only the short ones are worth it.
+const MAX_INLINABLE_STRING_LENGTH: usize = 32;
+
+/// `unresolved_ctxt` is the context of the global `Infinity`, the expression simplifier replaces
+/// the literals too large for a f64 with it.
+fn get_inlinable_value(expr: &Expr, unresolved_ctxt: SyntaxContext) -> Option<InlinableValue> {
+  let is_infinity = |expr: &Expr| matches!(expr, Expr::Ident(ident) if &*ident.sym == "Infinity" && ident.span.ctxt == unresolved_ctxt);
+  match expr {
+    Expr::Paren(paren) => get_inlinable_value(&paren.expr, unresolved_ctxt),
+    expr if is_infinity(expr) => Some(InlinableValue::Number("Infinity".into())),
+    Expr::Lit(Lit::Null(_)) => Some(InlinableValue::Null),
+    Expr::Lit(Lit::Bool(value)) => Some(InlinableValue::Boolean(value.value)),
+    Expr::Lit(Lit::Num(num)) => Some(InlinableValue::Number(render_number(num.value))),
+    Expr::Lit(Lit::Str(str)) if str.value.len() <= MAX_INLINABLE_STRING_LENGTH => {
+      Some(InlinableValue::String(str.value.clone()))
+    }
+    Expr::Unary(unary) if unary.op == UnaryOp::Minus => match unary.arg.as_ref() {
+      Expr::Lit(Lit::Num(num)) => Some(InlinableValue::Number(render_number(-num.value))),
+      expr if is_infinity(expr) => Some(InlinableValue::Number("-Infinity".into())),
+      _ => None,
+    },
+    Expr::Unary(unary) if unary.op == UnaryOp::Void && unary.arg.is_lit() => {
+      Some(InlinableValue::Undefined)
+    }
+    _ => None,
+  }
+}
+
+/// Literals too large for a f64, e.g. `1e400`, are parsed as infinity, which Rust prints as `inf`.
+fn render_number(value: f64) -> Atom {
+  if value.is_infinite() {
+    if value.is_sign_positive() {
+      "Infinity".into()
+    } else {
+      "-Infinity".into()
+    }
+  } else {
+    value.to_string().into()
+  }
 }
 
 impl<'a, 'b> Visit for HarmonyExportDependencyScanner<'a, 'b> {
@@ -63,6 +132,20 @@ impl<'a, 'b> Visit for HarmonyExportDependencyScanner<'a, 'b> {
   no_visit_ignored_stmt!();
 
   fn visit_program(&mut self, program: &'_ Program) {
+    if self.inline_const
+      && let Program::Module(module) = program
+    {
+      for item in &module.body {
+        match item {
+          ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+          | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Var(var_decl),
+            ..
+          })) => self.collect_inlinable_consts(var_decl),
+          _ => {}
+        }
+      }
+    }
     program.visit_children_with(self);
   }
 
@@ -90,12 +173,10 @@ impl<'a, 'b> Visit for HarmonyExportDependencyScanner<'a, 'b> {
         find_pat_ids::<_, Ident>(&v.decls)
           .into_iter()
           .for_each(|ident| {
-            self
-              .dependencies
-              .push(Box::new(HarmonyExportSpecifierDependency::new(
-                ident.sym.clone(),
-                ident.sym.clone(),
-              )));
+            self.dependencies.push(Box::new(
+              HarmonyExportSpecifierDependency::new(ident.sym.clone(), ident.sym.clone())
+                .with_inlinable(self.inlinable_consts.get(&ident.sym).cloned()),
+            ));
 
             usages.push((ident.sym.clone(), ident.sym.clone()));
             self.build_info.harmony_named_exports.insert(ident.sym);
@@ -153,12 +234,10 @@ impl<'a, 'b> Visit for HarmonyExportDependencyScanner<'a, 'b> {
                     None,
                   )));
               } else {
-                self
-                  .dependencies
-                  .push(Box::new(HarmonyExportSpecifierDependency::new(
-                    export.clone(),
-                    orig.sym.clone(),
-                  )));
+                self.dependencies.push(Box::new(
+                  HarmonyExportSpecifierDependency::new(export.clone(), orig.sym.clone())
+                    .with_inlinable(self.inlinable_consts.get(&orig.sym).cloned()),
+                ));
 
                 self.build_info.harmony_named_exports.insert(export.clone());
               }
@@ -605,10 +605,14 @@ mod test {
       missing_dependencies: Default::default(),
       build_dependencies: Default::default(),
       asset_filenames: Default::default(),
+      assets: Default::default(),
       harmony_named_exports: Default::default(),
       all_star_exports: Default::default(),
       need_create_require: false,
       json_data: None,
+      js_incompatible_exports: None,
+      no_side_effects_functions: Default::default(),
+      no_side_effects_calls: Default::default(),
     };
     let mut import_map = Default::default();
     let mut deps = vec![];
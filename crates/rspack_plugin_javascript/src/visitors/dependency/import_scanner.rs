@@ -17,6 +17,7 @@ use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
 use super::context_helper::scanner_context_module;
 use super::{is_import_meta_context_call, parse_order_string};
+use crate::ast::OriginalPositions;
 use crate::dependency::{ImportContextDependency, ImportDependency};
 use crate::dependency::{ImportEagerDependency, ImportMetaContextDependency};
 use crate::no_visit_ignored_stmt;
@@ -25,6 +26,7 @@ use crate::webpack_comment::try_extract_webpack_magic_comment;
 
 pub struct ImportScanner<'a> {
   pub source_file: Arc<SourceFile>,
+  pub original_positions: &'a OriginalPositions,
   pub module_identifier: ModuleIdentifier,
   pub dependencies: &'a mut Vec<BoxDependency>,
   pub blocks: &'a mut Vec<AsyncDependenciesBlock>,
@@ -116,6 +118,7 @@ impl<'a> ImportScanner<'a> {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
     source_file: Arc<SourceFile>,
+    original_positions: &'a OriginalPositions,
     module_identifier: ModuleIdentifier,
     dependencies: &'a mut Vec<BoxDependency>,
     blocks: &'a mut Vec<AsyncDependenciesBlock>,
@@ -127,6 +130,7 @@ impl<'a> ImportScanner<'a> {
   ) -> Self {
     Self {
       source_file,
+      original_positions,
       module_identifier,
       dependencies,
       blocks,
@@ -226,7 +230,7 @@ impl Visit for ImportScanner<'_> {
         ));
         let mut block = AsyncDependenciesBlock::new(
           self.module_identifier,
-          Some(DependencyLocation::new(span.start, span.end)),
+          Some(self.original_positions.resolve(node.span)),
         );
         block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
           chunk_name,
@@ -271,7 +275,7 @@ impl Visit for ImportScanner<'_> {
         ));
         let mut block = AsyncDependenciesBlock::new(
           self.module_identifier,
-          Some(DependencyLocation::new(span.start, span.end)),
+          Some(self.original_positions.resolve(node.span)),
         );
         block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
           chunk_name,
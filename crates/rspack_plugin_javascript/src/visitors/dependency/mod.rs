@@ -20,7 +20,7 @@ use rspack_core::{BuildMeta, CompilerOptions, ModuleIdentifier, ModuleType, Reso
 use rspack_error::miette::Diagnostic;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet};
 use swc_core::common::comments::Comments;
-use swc_core::common::{SourceFile, Span};
+use swc_core::common::{Mark, SourceFile, Span, SyntaxContext};
 use swc_core::ecma::atoms::Atom;
 
 use self::harmony_import_dependency_scanner::ImportMap;
@@ -34,6 +34,8 @@ use self::{
   import_meta_scanner::ImportMetaScanner, import_scanner::ImportScanner,
   worker_scanner::WorkerScanner,
 };
+use crate::ast::OriginalPositions;
+use crate::dependency::HarmonyImportSpecifierDependency;
 
 pub struct ScanDependenciesResult {
   pub dependencies: Vec<BoxDependency>,
@@ -57,7 +59,9 @@ pub enum ExtraSpanInfo {
 #[allow(clippy::too_many_arguments)]
 pub fn scan_dependencies(
   source_file: Arc<SourceFile>,
+  original_positions: &OriginalPositions,
   program: &Program,
+  unresolved_mark: Mark,
   worker_syntax_list: &mut WorkerSyntaxList,
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
@@ -114,13 +118,23 @@ pub fn scan_dependencies(
       &mut rewrite_usage_span,
       comments,
       &mut ignored,
+      compiler_options.experiments.inline_const && compiler_options.is_new_tree_shaking(),
+      SyntaxContext::empty().apply_mark(unresolved_mark),
     ));
+    if compiler_options.experiments.inline_const && compiler_options.is_new_tree_shaking() {
+      for dependency in dependencies.iter_mut() {
+        if let Some(dependency) = dependency.downcast_mut::<HarmonyImportSpecifierDependency>() {
+          dependency.inline_const = true;
+        }
+      }
+    }
 
     let mut worker_scanner = WorkerScanner::new(
       &module_identifier,
       &compiler_options.output,
       worker_syntax_list,
       &mut ignored,
+      original_positions,
     );
     program.visit_with(&mut worker_scanner);
     blocks.append(&mut worker_scanner.blocks);
@@ -139,6 +153,7 @@ pub fn scan_dependencies(
 
   program.visit_with(&mut ImportScanner::new(
     source_file.clone(),
+    original_positions,
     module_identifier,
     &mut dependencies,
     &mut blocks,
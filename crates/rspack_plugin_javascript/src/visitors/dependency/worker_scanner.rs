@@ -11,6 +11,7 @@ use swc_core::common::Spanned;
 use swc_core::ecma::ast::{Expr, ExprOrSpread, NewExpr};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
+use crate::ast::OriginalPositions;
 use crate::dependency::WorkerDependency;
 use crate::no_visit_ignored_stmt;
 use crate::utils::get_literal_str_by_obj_prop;
@@ -25,6 +26,7 @@ pub struct WorkerScanner<'a> {
   output_options: &'a OutputOptions,
   syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
   pub ignored: &'a mut FxHashSet<DependencyLocation>,
+  original_positions: &'a OriginalPositions,
 }
 
 // new Worker(new URL("./foo.worker.js", import.meta.url));
@@ -34,6 +36,7 @@ impl<'a> WorkerScanner<'a> {
     output_options: &'a OutputOptions,
     syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
     ignored: &'a mut FxHashSet<DependencyLocation>,
+    original_positions: &'a OriginalPositions,
   ) -> Self {
     Self {
       presentational_dependencies: Vec::new(),
@@ -44,6 +47,7 @@ impl<'a> WorkerScanner<'a> {
       output_options,
       syntax_list,
       ignored,
+      original_positions,
     }
   }
 
@@ -74,7 +78,7 @@ impl<'a> WorkerScanner<'a> {
     ));
     let mut block = AsyncDependenciesBlock::new(
       *self.module_identifier,
-      Some(DependencyLocation::new(span.start, span.end)),
+      Some(self.original_positions.resolve(new_expr.span)),
     );
     block.set_group_options(GroupOptions::Entrypoint(Box::new(EntryOptions {
       name,
@@ -1,6 +1,9 @@
 use once_cell::sync::Lazy;
 use regex::Captures;
-use rspack_error::miette::{Diagnostic, Severity};
+use rspack_error::{
+  codes,
+  miette::{Diagnostic, Severity},
+};
 use rustc_hash::FxHashMap;
 use swc_core::common::comments::{CommentKind, Comments};
 use swc_core::common::{SourceFile, Span};
@@ -69,6 +72,7 @@ fn add_magic_comment_warning(
       source_file,
       span.into(),
     )
+    .with_code(codes::COMMENT_COMPILATION_WARNING)
     .with_severity(Severity::Warning),
   ))
 }
@@ -124,10 +124,13 @@ impl Plugin for ArrayPushCallbackChunkFormatPlugin {
       let has_entry = chunk.has_entry_module(&args.compilation.chunk_graph);
       if has_entry || !runtime_modules.is_empty() {
         source.add(RawSource::from(","));
-        source.add(RawSource::from(format!(
-          "function({}) {{\n",
-          RuntimeGlobals::REQUIRE
-        )));
+        source.add(RawSource::from(
+          if args.compilation.options.output.environment.arrow_function {
+            format!("({}) => {{\n", RuntimeGlobals::REQUIRE)
+          } else {
+            format!("function({}) {{\n", RuntimeGlobals::REQUIRE)
+          },
+        ));
         if !runtime_modules.is_empty() {
           source.add(render_runtime_modules(args.compilation, args.chunk_ukey)?);
         }
@@ -173,10 +173,17 @@ pub fn generate_entry_startup(
     }
   }
 
+  let environment = &compilation.options.output.environment;
   let mut source = String::default();
   source.push_str(&format!(
-    "var __webpack_exec__ = function(moduleId) {{ return __webpack_require__({} = moduleId) }}\n",
-    RuntimeGlobals::ENTRY_MODULE_ID
+    "var __webpack_exec__ = {}\n",
+    environment.returning_function(
+      &format!(
+        "__webpack_require__({} = moduleId)",
+        RuntimeGlobals::ENTRY_MODULE_ID
+      ),
+      "moduleId"
+    )
   ));
 
   let module_ids_code = &module_id_exprs
@@ -193,16 +200,14 @@ pub fn generate_entry_startup(
       source.push_str("var __webpack_exports__ = ");
     }
     source.push_str(&format!(
-      "{}(0, {}, function() {{
-        return {};
-      }});\n",
+      "{}(0, {}, {});\n",
       if passive {
         RuntimeGlobals::ON_CHUNKS_LOADED
       } else {
         RuntimeGlobals::STARTUP_ENTRYPOINT
       },
       stringify_chunks_to_array(&chunks_ids),
-      module_ids_code
+      environment.basic_function("", &format!("\n        return {module_ids_code};\n      "))
     ));
     if passive {
       source.push_str(&format!(
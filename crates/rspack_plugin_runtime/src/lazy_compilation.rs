@@ -82,7 +82,11 @@ impl Module for LazyCompilationProxyModule {
         include_str!("runtime/lazy_compilation.js")
           // TODO
           .replace("$CHUNK_ID$", self.module_identifier.to_string().as_str())
-          .replace("$MODULE_ID$", self.module_identifier.to_string().as_str()),
+          .replace("$MODULE_ID$", self.module_identifier.to_string().as_str())
+          .replace(
+            "$EMPTY_FUNCTION$",
+            compilation.options.output.environment.empty_function(),
+          ),
       )
       .boxed(),
     );
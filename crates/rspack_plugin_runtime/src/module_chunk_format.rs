@@ -123,8 +123,14 @@ impl Plugin for ModuleChunkFormatPlugin {
       let mut startup_source = vec![];
 
       startup_source.push(format!(
-        "var __webpack_exec__ = function(moduleId) {{ return __webpack_require__({} = moduleId); }}",
-        RuntimeGlobals::ENTRY_MODULE_ID
+        "var __webpack_exec__ = {};",
+        compilation.options.output.environment.returning_function(
+          &format!(
+            "__webpack_require__({} = moduleId)",
+            RuntimeGlobals::ENTRY_MODULE_ID
+          ),
+          "moduleId"
+        )
       ));
 
       let mut loaded_chunks = HashSet::default();
@@ -2,7 +2,7 @@
 // var data = "$MODULE_ID$";
 module.exports = __webpack_require__.l(
 	"./lazy-compilation-web/$CHUNK_ID$",
-	function () {}
+	$EMPTY_FUNCTION$
 ); //.then(__webpack_require__.t.bind(__webpack_require__, "$MODULE_ID$", 23));
 if (module.hot) {
 	module.hot.accept();
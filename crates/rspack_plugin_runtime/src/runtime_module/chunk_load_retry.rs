@@ -0,0 +1,43 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_util::source_map::SourceMapKind;
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct ChunkLoadRetryRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for ChunkLoadRetryRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_load_retry"),
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkLoadRetryRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let retry = &compilation.options.output.chunk_load_retry;
+    RawSource::from(
+      include_str!("runtime/chunk_load_retry.js")
+        .replace("$MAX_RETRIES$", &retry.max_retries.to_string())
+        .replace("$RETRY_DELAY$", &retry.retry_delay.to_string())
+        .replace(
+          "$FALLBACK_PUBLIC_PATHS$",
+          &serde_json::to_string(&retry.fallback_public_paths).expect("should be valid json"),
+        ),
+    )
+    .boxed()
+  }
+}
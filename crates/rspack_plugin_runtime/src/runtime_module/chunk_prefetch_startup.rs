@@ -81,13 +81,15 @@ impl RuntimeModule for ChunkPrefetchStartupRuntimeModule {
 
           format!(
             r#"
-            {}(0, {}, function() {{
-              {}
-            }}, 5);
+            {}(0, {}, {}, 5);
             "#,
             RuntimeGlobals::ON_CHUNKS_LOADED,
             serde_json::to_string(&group_chunk_ids).expect("invalid json tostring"),
-            body
+            compilation
+              .options
+              .output
+              .environment
+              .basic_function("", &format!("\n              {body}\n            "))
           )
         })
         .join("\n"),
@@ -319,7 +319,7 @@ impl RuntimeModule for GetChunkFilenameRuntimeModule {
 
     RawSource::from(format!(
       "// This function allow to reference chunks
-        {} = function (chunkId) {{
+        {} = {} {{
           // return url for filenames not based on template
           {}
           // return url for filenames based on template
@@ -327,6 +327,11 @@ impl RuntimeModule for GetChunkFilenameRuntimeModule {
         }};
       ",
       self.global,
+      if compilation.options.output.environment.arrow_function {
+        "(chunkId) =>"
+      } else {
+        "function (chunkId)"
+      },
       static_urls
         .iter()
         .map(|(filename, chunk_ids)| stringify_static_chunk_map(filename, chunk_ids))
@@ -41,11 +41,16 @@ impl RuntimeModule for GetChunkUpdateFilenameRuntimeModule {
           .runtime(&chunk.runtime),
       );
       RawSource::from(format!(
-        "{} = function (chunkId) {{
+        "{} = {} {{
             return '{}';
          }};
         ",
         RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME,
+        if compilation.options.output.environment.arrow_function {
+          "(chunkId) =>"
+        } else {
+          "function (chunkId)"
+        },
         filename
       ))
       .boxed()
@@ -44,11 +44,17 @@ impl RuntimeModule for GetMainFilenameRuntimeModule {
           .runtime(&chunk.runtime),
       );
       RawSource::from(format!(
-        "{} = function () {{
+        "{} = {} {{
             return \"{}\";
          }};
         ",
-        self.global, filename
+        self.global,
+        if compilation.options.output.environment.arrow_function {
+          "() =>"
+        } else {
+          "function ()"
+        },
+        filename
       ))
       .boxed()
     } else {
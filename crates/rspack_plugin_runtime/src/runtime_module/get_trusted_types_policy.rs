@@ -43,10 +43,19 @@ impl RuntimeModule for GetTrustedTypesPolicyRuntimeModule {
     let create_script = runtime_requirements.contains(RuntimeGlobals::CREATE_SCRIPT);
     let create_script_url = runtime_requirements.contains(RuntimeGlobals::CREATE_SCRIPT_URL);
 
-    let mut result = include_str!("runtime/get_trusted_types_policy.js").replace(
-      "$policyName$",
-      &trusted_types.policy_name.clone().unwrap_or_default(),
-    );
+    let mut result = include_str!("runtime/get_trusted_types_policy.js")
+      .replace(
+        "$policyName$",
+        &trusted_types.policy_name.clone().unwrap_or_default(),
+      )
+      .replace(
+        "$LET$",
+        if compilation.options.output.environment.r#const {
+          "let"
+        } else {
+          "var"
+        },
+      );
     let mut policy_content: Vec<String> = Vec::new();
     if create_script {
       policy_content.push(
@@ -65,6 +65,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
     let with_preload = runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
     let cross_origin_loading = &compilation.options.output.cross_origin_loading;
     let script_type = &compilation.options.output.script_type;
+    let environment = &compilation.options.output.environment;
 
     let condition_map =
       compilation
@@ -102,6 +103,14 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       } else {
         include_str!("runtime/jsonp_chunk_loading.js")
           .replace("$JS_MATCHER$", has_js_matcher.to_string().as_str())
+          .replace(
+            "$CHUNK_KEY$",
+            if environment.template_literal {
+              "`chunk-${chunkId}`"
+            } else {
+              "\"chunk-\" + chunkId"
+            },
+          )
           .replace(
             "$MATCH_FALLBACK$",
             if matches!(has_js_matcher, BooleanMatcher::Condition(true)) {
@@ -114,11 +123,16 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
 
       source.add(RawSource::from(format!(
         r#"
-        {}.j = function (chunkId, promises) {{
+        {}.j = {} {{
           {body}
         }}
         "#,
-        RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+        RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+        if environment.arrow_function {
+          "(chunkId, promises) =>"
+        } else {
+          "function (chunkId, promises)"
+        }
       )));
     }
 
@@ -215,6 +229,23 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       source.add(RawSource::from(
         include_str!("runtime/jsonp_chunk_loading_with_callback.js")
           .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
+          .replace("$CONST$", environment.render_const())
+          .replace(
+            "$UNPACK_DATA$",
+            if environment.destructuring {
+              "var [chunkIds, moreModules, runtime] = data;"
+            } else {
+              "var chunkIds = data[0];\n\tvar moreModules = data[1];\n\tvar runtime = data[2];"
+            },
+          )
+          .replace(
+            "$FOR_CHUNK_IDS$",
+            if environment.for_of {
+              "for (chunkId of chunkIds) {"
+            } else {
+              "for (; i < chunkIds.length; i++) {\n\t\tchunkId = chunkIds[i];"
+            },
+          )
           .replace(
             "$WITH_ON_CHUNK_LOAD$",
             match with_on_chunk_load {
@@ -1,17 +1,20 @@
 use rspack_core::{
   impl_runtime_module,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
+  ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
 
+use crate::get_chunk_runtime_requirements;
+
 #[impl_runtime_module]
 #[derive(Debug, Eq)]
 pub struct LoadScriptRuntimeModule {
   id: Identifier,
   unique_name: String,
   with_create_script_url: bool,
+  chunk: Option<ChunkUkey>,
 }
 
 impl LoadScriptRuntimeModule {
@@ -20,6 +23,7 @@ impl LoadScriptRuntimeModule {
       id: Identifier::from("webpack/runtime/load_script"),
       unique_name,
       with_create_script_url,
+      chunk: None,
       source_map_kind: SourceMapKind::None,
       custom_source: None,
     }
@@ -37,6 +41,7 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     } else {
       "url".to_string()
     };
+    let environment = &compilation.options.output.environment;
     let cross_origin_loading = match &compilation.options.output.cross_origin_loading {
       CrossOriginLoading::Disable => "".to_string(),
       CrossOriginLoading::Enable(value) => format!(
@@ -48,6 +53,27 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       ),
     };
 
+    let with_sri_hashes = self.chunk.is_some_and(|chunk| {
+      get_chunk_runtime_requirements(compilation, &chunk).contains(RuntimeGlobals::SRI_HASHES)
+    });
+    let integrity = if with_sri_hashes {
+      let cross_origin = match &compilation.options.output.cross_origin_loading {
+        CrossOriginLoading::Enable(value) => value.as_str(),
+        CrossOriginLoading::Disable => "anonymous",
+      };
+      format!(
+        r#"
+        if (chunkId !== undefined && {sri_hashes}[chunkId]) {{
+          script.integrity = {sri_hashes}[chunkId];
+          script.crossOrigin = "{cross_origin}";
+        }}
+        "#,
+        sri_hashes = RuntimeGlobals::SRI_HASHES
+      )
+    } else {
+      String::new()
+    };
+
     let script_type = if compilation.options.output.script_type.eq("false") {
       String::new()
     } else {
@@ -57,11 +83,43 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       )
     };
 
+    let output = &compilation.options.output;
+    let charset = if output.charset {
+      "\t\tscript.charset = 'utf-8';\n"
+    } else {
+      ""
+    };
+    let (attempt_argument, retry) = if output.chunk_load_retry.is_enabled() {
+      (
+        ", attempt",
+        format!(
+          r#"		if (doneFns && event.type !== "load") {{
+			return {chunk_load_retry}.retry(url, attempt || 0, "script", function (nextUrl, nextAttempt) {{
+				doneFns.forEach(function (fn) {{
+					{load_script}(nextUrl, fn, key, chunkId, nextAttempt);
+				}});
+			}}, function () {{
+				doneFns.forEach(function (fn) {{
+					return fn(event);
+				}});
+				if (prev) return prev(event);
+			}});
+		}}
+"#,
+          chunk_load_retry = RuntimeGlobals::CHUNK_LOAD_RETRY,
+          load_script = RuntimeGlobals::LOAD_SCRIPT,
+        ),
+      )
+    } else {
+      ("", String::new())
+    };
+
     let unique_prefix = if self.unique_name.is_empty() {
       None
     } else {
       Some(format!(
-        r#"var dataWebpackPrefix = "{}:";"#,
+        r#"{} dataWebpackPrefix = "{}:";"#,
+        environment.render_const(),
         self.unique_name
       ))
     };
@@ -70,9 +128,29 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       include_str!("runtime/load_script.js")
         .replace(
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-          &cross_origin_loading,
+          &format!("{cross_origin_loading}{integrity}"),
+        )
+        .replace("$CONST$", environment.render_const())
+        .replace(
+          "$REMOVE_SCRIPT$",
+          if environment.optional_chaining {
+            "script.parentNode?.removeChild(script)"
+          } else {
+            "script.parentNode && script.parentNode.removeChild(script)"
+          },
         )
         .replace("$URL$", &url)
+        .replace("$ATTEMPT_ARGUMENT$", attempt_argument)
+        .replace("$CHARSET$", charset)
+        .replace(
+          "$CHUNK_LOAD_TIMEOUT_IN_SECONDS$",
+          &(output.chunk_load_timeout / 1000).to_string(),
+        )
+        .replace(
+          "$CHUNK_LOAD_TIMEOUT$",
+          &output.chunk_load_timeout.to_string(),
+        )
+        .replace("$RETRY$", &retry)
         .replace("$SCRIPT_TYPE$", &script_type)
         .replace(
           "$UNIQUE_GET_ATTRIBUTE$",
@@ -95,4 +173,8 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     )
     .boxed()
   }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
 }
@@ -1,6 +1,7 @@
 mod async_module;
 mod auto_public_path;
 mod base_uri;
+mod chunk_load_retry;
 mod chunk_name;
 mod chunk_prefetch_preload_function;
 mod chunk_prefetch_startup;
@@ -42,6 +43,7 @@ mod utils;
 pub use async_module::AsyncRuntimeModule;
 pub use auto_public_path::AutoPublicPathRuntimeModule;
 pub use base_uri::BaseUriRuntimeModule;
+pub use chunk_load_retry::ChunkLoadRetryRuntimeModule;
 pub use chunk_name::ChunkNameRuntimeModule;
 pub use chunk_prefetch_preload_function::ChunkPrefetchPreloadFunctionRuntimeModule;
 pub use chunk_prefetch_startup::ChunkPrefetchStartupRuntimeModule;
@@ -107,13 +107,27 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
 
     if with_loading || with_external_install_chunk {
       source.add(RawSource::from(
-        include_str!("runtime/module_chunk_loading.js").replace(
-          "$WITH_ON_CHUNK_LOAD$",
-          match with_on_chunk_load {
-            true => "__webpack_require__.O();",
-            false => "",
-          },
-        ),
+        include_str!("runtime/module_chunk_loading.js")
+          .replace(
+            "$UNPACK_DATA$",
+            if compilation
+              .options
+              .output
+              .environment
+              .destructuring
+            {
+              "var {ids, modules, runtime} = data;"
+            } else {
+              "var ids = data.ids;\n    var modules = data.modules;\n    var runtime = data.runtime;"
+            },
+          )
+          .replace(
+            "$WITH_ON_CHUNK_LOAD$",
+            match with_on_chunk_load {
+              true => "__webpack_require__.O();",
+              false => "",
+            },
+          ),
       ));
     }
 
@@ -140,11 +154,16 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
 
       source.add(RawSource::from(format!(
         r#"
-        {}.j = function (chunkId, promises) {{
+        {}.j = {} {{
           {body}
         }}
         "#,
-        RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+        RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+        if compilation.options.output.environment.arrow_function {
+          "(chunkId, promises) =>"
+        } else {
+          "function (chunkId, promises)"
+        }
       )));
     }
 
@@ -160,11 +179,13 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
     if with_on_chunk_load {
       source.add(RawSource::from(format!(
         r#"
-        {}.j = function(chunkId) {{
-            return installedChunks[chunkId] === 0;
-        }}
+        {}.j = {}
         "#,
-        RuntimeGlobals::ON_CHUNKS_LOADED
+        RuntimeGlobals::ON_CHUNKS_LOADED,
+        compilation.options.output.environment.basic_function(
+          "chunkId",
+          "\n            return installedChunks[chunkId] === 0;\n        "
+        )
       )));
     }
 
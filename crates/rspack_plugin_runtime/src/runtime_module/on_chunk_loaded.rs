@@ -27,7 +27,11 @@ impl RuntimeModule for OnChunkLoadedRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/on_chunk_loaded.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    RawSource::from(include_str!("runtime/on_chunk_loaded.js").replace(
+      "$CONST$",
+      compilation.options.output.environment.render_const(),
+    ))
+    .boxed()
   }
 }
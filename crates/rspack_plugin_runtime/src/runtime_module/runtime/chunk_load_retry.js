@@ -0,0 +1,53 @@
+__webpack_require__.lr = {
+	maxRetries: $MAX_RETRIES$,
+	retryDelay: $RETRY_DELAY$,
+	fallbackPublicPaths: $FALLBACK_PUBLIC_PATHS$,
+	// handlers called with { url, nextUrl, attempt, type } before a failed request is retried
+	onRetry: [],
+	retry: function (url, attempt, type, load, fail) {
+		var policy = __webpack_require__.lr;
+		if (attempt >= policy.maxRetries) return fail();
+		var publicPaths = [__webpack_require__.p].concat(policy.fallbackPublicPaths);
+		var nextUrl = url;
+		for (var i = publicPaths.length - 1; i >= 0; i--) {
+			if (url.indexOf(publicPaths[i]) === 0) {
+				nextUrl =
+					publicPaths[(i + 1) % publicPaths.length] +
+					url.slice(publicPaths[i].length);
+				break;
+			}
+		}
+		var info = { url: url, nextUrl: nextUrl, attempt: attempt + 1, type: type };
+		policy.onRetry.forEach(function (handler) {
+			handler(info);
+		});
+		setTimeout(function () {
+			load(nextUrl, attempt + 1);
+		}, policy.retryDelay * Math.pow(2, attempt));
+	},
+	fetch: function (url, type) {
+		var load = function (url, attempt) {
+			return fetch(url)
+				.then(function (res) {
+					if (!res.ok) throw new Error("Loading " + url + " failed with status " + res.status);
+					return res;
+				})
+				.catch(function (err) {
+					return new Promise(function (resolve, reject) {
+						__webpack_require__.lr.retry(
+							url,
+							attempt,
+							type,
+							function (nextUrl, nextAttempt) {
+								resolve(load(nextUrl, nextAttempt));
+							},
+							function () {
+								reject(err);
+							}
+						);
+					});
+				});
+		};
+		return load(url, 0);
+	}
+};
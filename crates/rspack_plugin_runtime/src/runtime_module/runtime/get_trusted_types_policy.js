@@ -1,4 +1,4 @@
-var policy;
+$LET$ policy;
 __webpack_require__.tt = function () {
     // Create Trusted Type policy if Trusted Types are available and the policy doesn't exist yet.
     if (policy === undefined) {
@@ -43,7 +43,7 @@ if (installedChunkData !== 0) {
 					}
 				}
 			};
-			__webpack_require__.l(url, loadingEnded, "chunk-" + chunkId, chunkId);
+			__webpack_require__.l(url, loadingEnded, $CHUNK_KEY$, chunkId);
 		} $MATCH_FALLBACK$
 	}
 }
@@ -1,8 +1,6 @@
 // install a JSONP callback for chunk loading
 var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
-	var chunkIds = data[0];
-	var moreModules = data[1];
-	var runtime = data[2];
+	$UNPACK_DATA$
 	// add "moreModules" to the modules object,
 	// then flag all "chunkIds" as loaded and fire callback
 	var moduleId,
@@ -17,8 +15,7 @@ var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
 		if (runtime) var result = runtime(__webpack_require__);
 	}
 	if (parentChunkLoadingFunction) parentChunkLoadingFunction(data);
-	for (; i < chunkIds.length; i++) {
-		chunkId = chunkIds[i];
+	$FOR_CHUNK_IDS$
 		if (
 			__webpack_require__.o(installedChunks, chunkId) &&
 			installedChunks[chunkId]
@@ -30,7 +27,7 @@ var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
 	$WITH_ON_CHUNK_LOAD$
 };
 
-var chunkLoadingGlobal = $CHUNK_LOADING_GLOBAL_EXPR$ = $CHUNK_LOADING_GLOBAL_EXPR$ || [];
+$CONST$ chunkLoadingGlobal = $CHUNK_LOADING_GLOBAL_EXPR$ = $CHUNK_LOADING_GLOBAL_EXPR$ || [];
 chunkLoadingGlobal.forEach(webpackJsonpCallback.bind(null, 0));
 chunkLoadingGlobal.push = webpackJsonpCallback.bind(
 	null,
@@ -1,8 +1,8 @@
-var inProgress = {};
+$CONST$ inProgress = {};
 
 $UNIQUE_PREFIX$
 // loadScript function to load a script via script tag
-__webpack_require__.l = function (url, done, key, chunkId) {
+__webpack_require__.l = function (url, done, key, chunkId$ATTEMPT_ARGUMENT$) {
 	if (inProgress[url]) {
 		inProgress[url].push(done);
 		return;
@@ -22,8 +22,7 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 		needAttach = true;
 		script = document.createElement('script');
 		$SCRIPT_TYPE$
-		script.charset = 'utf-8';
-		script.timeout = 120;
+$CHARSET$		script.timeout = $CHUNK_LOAD_TIMEOUT_IN_SECONDS$;
 		if (__webpack_require__.nc) {
 			script.setAttribute("nonce", __webpack_require__.nc);
 		}
@@ -38,8 +37,8 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 		clearTimeout(timeout);
 		var doneFns = inProgress[url];
 		delete inProgress[url];
-		script.parentNode && script.parentNode.removeChild(script);
-		doneFns &&
+		$REMOVE_SCRIPT$;
+$RETRY$		doneFns &&
 			doneFns.forEach(function (fn) {
 				return fn(event);
 			});
@@ -50,7 +49,7 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 			type: 'timeout',
 			target: script
 		}),
-		120000
+		$CHUNK_LOAD_TIMEOUT$
 	);
 	script.onerror = onScriptComplete.bind(null, script.onerror);
 	script.onload = onScriptComplete.bind(null, script.onload);
@@ -1,7 +1,5 @@
 var installChunk = function (data) {
-    var ids = data.ids;
-    var modules = data.modules;
-    var runtime = data.runtime;
+    $UNPACK_DATA$
     // add "modules" to the modules object,
     // then flag all "ids" as loaded and fire callback
     var moduleId, chunkId, i = 0;
@@ -1,4 +1,4 @@
-var deferred = [];
+$CONST$ deferred = [];
 __webpack_require__.O = function (result, chunkIds, fn, priority) {
 	if (chunkIds) {
 		priority = priority || 0;
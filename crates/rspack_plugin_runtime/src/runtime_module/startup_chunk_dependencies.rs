@@ -83,12 +83,14 @@ impl RuntimeModule for StartupChunkDependenciesRuntimeModule {
 
       RawSource::from(format!(
         r#"var next = {};
-        {} = function() {{
-          {}
-        }};"#,
+        {} = {};"#,
         RuntimeGlobals::STARTUP,
         RuntimeGlobals::STARTUP,
-        body
+        compilation
+          .options
+          .output
+          .environment
+          .basic_function("", &format!("\n          {body}\n        "))
       ))
       .boxed()
     } else {
@@ -13,15 +13,16 @@ use rspack_core::{
 
 use crate::runtime_module::{
   chunk_has_css, is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule,
-  BaseUriRuntimeModule, ChunkNameRuntimeModule, ChunkPrefetchPreloadFunctionRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule,
-  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NonceRuntimeModule,
-  NormalRuntimeModule, OnChunkLoadedRuntimeModule, PublicPathRuntimeModule,
-  RelativeUrlRuntimeModule, RuntimeIdRuntimeModule, SystemContextRuntimeModule,
+  BaseUriRuntimeModule, ChunkLoadRetryRuntimeModule, ChunkNameRuntimeModule,
+  ChunkPrefetchPreloadFunctionRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
+  NodeModuleDecoratorRuntimeModule, NonceRuntimeModule, NormalRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
+  RuntimeIdRuntimeModule, SystemContextRuntimeModule,
 };
 
 static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
@@ -49,13 +50,14 @@ static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
     RuntimeGlobals::SCRIPT_NONCE,
     // RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
-    // RuntimeGlobals::WASM_INSTANCES,
+    RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
     RuntimeGlobals::LOAD_SCRIPT,
     RuntimeGlobals::SYSTEM_CONTEXT,
     RuntimeGlobals::ON_CHUNKS_LOADED,
+    RuntimeGlobals::CHUNK_LOAD_RETRY,
   ]
 });
 
@@ -223,6 +225,16 @@ impl Plugin for RuntimePlugin {
       }
     }
 
+    if compilation.options.output.chunk_load_retry.is_enabled()
+      && runtime_requirements.contains(RuntimeGlobals::LOAD_SCRIPT)
+    {
+      runtime_requirements_mut.insert(RuntimeGlobals::CHUNK_LOAD_RETRY);
+    }
+
+    if runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY) {
+      runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    }
+
     if (runtime_requirements.contains(RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME)
       && compilation
         .options
@@ -426,6 +438,11 @@ impl Plugin for RuntimePlugin {
             )
             .await?;
         }
+        RuntimeGlobals::CHUNK_LOAD_RETRY => {
+          compilation
+            .add_runtime_module(chunk, ChunkLoadRetryRuntimeModule::default().boxed())
+            .await?;
+        }
         RuntimeGlobals::HAS_OWN_PROPERTY => {
           compilation
             .add_runtime_module(chunk, HasOwnPropertyRuntimeModule::default().boxed())
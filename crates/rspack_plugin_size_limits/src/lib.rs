@@ -0,0 +1,191 @@
+// https://github.com/webpack/webpack/blob/main/lib/performance/SizeLimitsPlugin.js
+
+use std::fmt::Write;
+
+use async_trait::async_trait;
+use rspack_core::{
+  Compilation, CompilationAsset, Logger, PerformanceHints, PerformanceOptions, Plugin,
+  PluginContext, PluginProcessAssetsOutput, ProcessAssetsArgs,
+};
+use rspack_error::{Diagnostic, Result};
+
+#[derive(Debug, Default)]
+pub struct SizeLimitsPlugin;
+
+impl SizeLimitsPlugin {
+  pub fn new() -> Self {
+    Self
+  }
+
+  async fn is_asset_included(
+    options: &PerformanceOptions,
+    name: &str,
+    asset: &CompilationAsset,
+  ) -> Result<bool> {
+    if asset.get_source().is_none() {
+      return Ok(false);
+    }
+    match &options.asset_filter {
+      Some(filter) => filter.try_match(name).await,
+      // Source maps and the assets only used in development are not served to the users
+      None => Ok(!name.ends_with(".map") && !asset.info.development),
+    }
+  }
+
+  fn create_diagnostic(hints: PerformanceHints, title: &str, message: String) -> Diagnostic {
+    if matches!(hints, PerformanceHints::Error) {
+      Diagnostic::error(title.to_string(), message)
+    } else {
+      Diagnostic::warn(title.to_string(), message)
+    }
+  }
+}
+
+#[async_trait]
+impl Plugin for SizeLimitsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SizeLimitsPlugin"
+  }
+
+  async fn after_process_assets(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let compilation = args.compilation;
+    let compiler_options = compilation.options.clone();
+    let options = &compiler_options.performance;
+    if !options.hints.is_enable() {
+      return Ok(());
+    }
+
+    let logger = compilation.get_logger(self.name());
+    let start = logger.time("check size limits");
+
+    let mut asset_names = compilation.assets().keys().collect::<Vec<_>>();
+    asset_names.sort_unstable();
+
+    let mut asset_sizes: Vec<(&String, f64)> = Vec::with_capacity(asset_names.len());
+    for name in asset_names {
+      let asset = &compilation.assets()[name];
+      if !Self::is_asset_included(options, name, asset).await? {
+        continue;
+      }
+      let size = asset.get_source().map_or(0f64, |s| s.size() as f64);
+      asset_sizes.push((name, size));
+    }
+
+    let assets_over_size_limit = asset_sizes
+      .iter()
+      .filter(|(_, size)| *size > options.max_asset_size)
+      .collect::<Vec<_>>();
+
+    let mut entrypoints_over_size_limit = vec![];
+    for (name, ukey) in &compilation.entrypoints {
+      let entrypoint = compilation.chunk_group_by_ukey.expect_get(ukey);
+      let files = entrypoint
+        .get_files(&compilation.chunk_by_ukey)
+        .into_iter()
+        .filter(|file| asset_sizes.iter().any(|(name, _)| *name == file))
+        .collect::<Vec<_>>();
+      let size: f64 = asset_sizes
+        .iter()
+        .filter(|(name, _)| files.contains(name))
+        .map(|(_, size)| size)
+        .sum();
+      if size > options.max_entrypoint_size {
+        entrypoints_over_size_limit.push((name, size, files));
+      }
+    }
+
+    let mut diagnostics = vec![];
+
+    if !assets_over_size_limit.is_empty() {
+      let mut message = format!(
+        "The following asset(s) exceed the recommended size limit ({}).\nThis can impact web performance.\nAssets:",
+        format_size(options.max_asset_size)
+      );
+      for (name, size) in assets_over_size_limit {
+        write!(message, "\n  {} ({})", name, format_size(*size)).expect("should write");
+      }
+      diagnostics.push(Self::create_diagnostic(
+        options.hints,
+        "asset size limit",
+        message,
+      ));
+    }
+
+    if !entrypoints_over_size_limit.is_empty() {
+      let mut message = format!(
+        "The following entrypoint(s) combined asset size exceeds the recommended limit ({}). This can impact web performance.\nEntrypoints:",
+        format_size(options.max_entrypoint_size)
+      );
+      for (name, size, files) in entrypoints_over_size_limit {
+        write!(message, "\n  {} ({})", name, format_size(size)).expect("should write");
+        for file in files {
+          write!(message, "\n      {file}").expect("should write");
+        }
+      }
+      diagnostics.push(Self::create_diagnostic(
+        options.hints,
+        "entrypoint size limit",
+        message,
+      ));
+    }
+
+    if !diagnostics.is_empty() && !has_async_chunks(compilation) {
+      diagnostics.push(Self::create_diagnostic(
+        options.hints,
+        "performance recommendations",
+        "You can limit the size of your bundles by using import() to lazy load some parts of your application.\nFor more info visit https://www.rspack.dev/guide/code-splitting.html".to_string(),
+      ));
+    }
+
+    compilation.push_batch_diagnostic(diagnostics);
+    logger.time_end(start);
+    Ok(())
+  }
+}
+
+fn has_async_chunks(compilation: &Compilation) -> bool {
+  compilation
+    .chunk_by_ukey
+    .values()
+    .any(|chunk| !chunk.can_be_initial(&compilation.chunk_group_by_ukey))
+}
+
+/// Formats a byte size the same way as webpack's `formatSize`, e.g. `244 KiB`.
+fn format_size(size: f64) -> String {
+  if size <= 0f64 {
+    return "0 bytes".to_string();
+  }
+  let abbreviations = ["bytes", "KiB", "MiB", "GiB"];
+  let index = ((size.ln() / 1024f64.ln()).floor() as usize).min(abbreviations.len() - 1);
+  let value = size / 1024f64.powi(index as i32);
+  // mimic `+value.toPrecision(3)`
+  let integer_digits = value.log10().floor() as i32 + 1;
+  let decimals = (3 - integer_digits).max(0) as usize;
+  let mut formatted = format!("{value:.decimals$}");
+  if formatted.contains('.') {
+    formatted = formatted
+      .trim_end_matches('0')
+      .trim_end_matches('.')
+      .to_string();
+  }
+  format!("{formatted} {}", abbreviations[index])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::format_size;
+
+  #[test]
+  fn format_size_like_webpack() {
+    assert_eq!(format_size(0f64), "0 bytes");
+    assert_eq!(format_size(100f64), "100 bytes");
+    assert_eq!(format_size(1024f64), "1 KiB");
+    assert_eq!(format_size(250000f64), "244 KiB");
+    assert_eq!(format_size(1536f64), "1.5 KiB");
+    assert_eq!(format_size(1024f64 * 1024f64 * 2.345), "2.35 MiB");
+  }
+}
@@ -88,30 +88,75 @@ impl SplitChunkSizes {
     })
   }
 
-  pub fn bigger_than(&self, other: &Self) -> bool {
+  /// Return `true` if any non-empty `SourceType` of `self` is bigger than the one in `max_size`.
+  /// `SourceType`s missing in `max_size` are not limited.
+  pub fn bigger_than(&self, max_size: &Self) -> bool {
     self.iter().any(|(ty, ty_size)| {
-      if *ty_size == 0.0 {
-        false
-      } else {
-        let other_size = other.get(ty).copied().unwrap_or(-f64::INFINITY);
-        *ty_size > other_size
-      }
+      *ty_size != 0.0
+        && max_size
+          .get(ty)
+          .map_or(false, |ty_max_size| ty_size > ty_max_size)
     })
   }
-  pub fn smaller_than(&self, other: &Self) -> bool {
+
+  /// Return `true` if any non-empty `SourceType` of `self` is smaller than the one in `min_size`.
+  /// `SourceType`s missing in `min_size` are not limited.
+  pub fn smaller_than(&self, min_size: &Self) -> bool {
     self.iter().any(|(ty, ty_size)| {
-      if *ty_size == 0.0 {
-        false
-      } else {
-        let other_size = other.get(ty).copied().unwrap_or(f64::INFINITY);
-        *ty_size < other_size
-      }
+      *ty_size != 0.0
+        && min_size
+          .get(ty)
+          .map_or(false, |ty_min_size| ty_size < ty_min_size)
     })
   }
 
   pub fn add_by(&mut self, other: &Self) {
     self.combine_with(other, &|a, b| a + b)
   }
+
+  pub fn subtract_by(&mut self, other: &Self) {
+    other.iter().for_each(|(ty, size)| {
+      *self.entry(*ty).or_default() -= size;
+    })
+  }
+
+  pub fn total_size_of(&self, source_types: &[SourceType]) -> f64 {
+    source_types.iter().filter_map(|ty| self.get(ty)).sum()
+  }
+
+  pub fn has_non_zero_sizes(&self) -> bool {
+    self.values().any(|size| *size > 0.0)
+  }
+
+  /// Port of `checkMinSize` in webpack's SplitChunksPlugin.
+  ///
+  /// Return `true` if every non-empty `SourceType` of `self` reaches the size in `min_size`.
+  pub fn fits_min_size(&self, min_size: &Self) -> bool {
+    self.violating_min_size_types(min_size).is_empty()
+  }
+
+  /// Port of `getViolatingMinSizes` in webpack's SplitChunksPlugin.
+  pub fn violating_min_size_types(&self, min_size: &Self) -> Vec<SourceType> {
+    min_size
+      .iter()
+      .filter(|(ty, min_size)| {
+        let size = self.get(ty).copied().unwrap_or_default();
+        size != 0.0 && size < **min_size
+      })
+      .map(|(ty, _)| *ty)
+      .collect()
+  }
+
+  /// Port of `checkMinSizeReduction` in webpack's SplitChunksPlugin.
+  ///
+  /// Moving modules of these sizes out of `chunk_count` chunks should reduce at least `min_size_reduction`
+  /// for every non-empty `SourceType`.
+  pub fn fits_min_size_reduction(&self, min_size_reduction: &Self, chunk_count: usize) -> bool {
+    min_size_reduction.iter().all(|(ty, min_reduction)| {
+      let size = self.get(ty).copied().unwrap_or_default();
+      size == 0.0 || size * chunk_count as f64 >= *min_reduction
+    })
+  }
 }
 
 impl Deref for SplitChunkSizes {
@@ -30,6 +30,12 @@ pub struct CacheGroup {
   pub name: ChunkNameGetter,
   pub priority: f64,
   pub min_size: SplitChunkSizes,
+  /// Minimum size reduction, per `SourceType`, of the chunks that a `ModuleGroup` is split from
+  pub min_size_reduction: SplitChunkSizes,
+  /// Minimum size of the chunk that is left over when a `ModuleGroup` is split from only one chunk
+  pub min_remaining_size: SplitChunkSizes,
+  /// `ModuleGroup`s reaching this size ignore `max_*_requests` and `min_remaining_size`
+  pub enforce_size_threshold: SplitChunkSizes,
   pub reuse_existing_chunk: bool,
   /// number of referenced chunks
   pub min_chunks: u32,
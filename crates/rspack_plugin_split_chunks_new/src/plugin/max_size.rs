@@ -4,11 +4,12 @@ use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  ChunkUkey, Compilation, CompilerOptions, Module, ModuleIdentifier, DEFAULT_DELIMITER,
+  ChunkUkey, Compilation, CompilerOptions, Logger, Module, ModuleIdentifier, Plugin, SourceType,
+  DEFAULT_DELIMITER,
 };
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_util::{ext::DynHash, identifier::make_paths_relative};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::MaxSizeSetting;
 use crate::{SplitChunkSizes, SplitChunksPlugin};
@@ -23,21 +24,167 @@ struct GroupItem {
 #[derive(Debug)]
 struct Group {
   nodes: Vec<GroupItem>,
+  /// `similarities[i]` is the similarity between the keys of `nodes[i]` and `nodes[i + 1]`
+  similarities: Vec<usize>,
   pub size: SplitChunkSizes,
   pub key: Option<String>,
 }
 
 impl Group {
-  fn new(items: Vec<GroupItem>, key: Option<String>) -> Self {
+  fn new(items: Vec<GroupItem>, similarities: Vec<usize>) -> Self {
     let mut summed_size = SplitChunkSizes::empty();
     items.iter().for_each(|item| summed_size.add_by(&item.size));
 
     Self {
       nodes: items,
+      similarities,
       size: summed_size,
-      key,
+      key: None,
     }
   }
+
+  /// Remove nodes matching `filter` from the group and return them.
+  /// Return `None` and keep the group untouched if all nodes match.
+  fn pop_nodes(&mut self, filter: impl Fn(&GroupItem) -> bool) -> Option<Vec<GroupItem>> {
+    if self.nodes.iter().all(&filter) {
+      return None;
+    }
+
+    let mut new_nodes: Vec<GroupItem> = Vec::with_capacity(self.nodes.len());
+    let mut new_similarities = Vec::with_capacity(self.similarities.len());
+    let mut result_nodes = vec![];
+    let mut last_kept_index = None;
+
+    for (index, node) in std::mem::take(&mut self.nodes).into_iter().enumerate() {
+      if filter(&node) {
+        result_nodes.push(node);
+        continue;
+      }
+      if let Some(last_kept_index) = last_kept_index {
+        new_similarities.push(if last_kept_index + 1 == index {
+          self.similarities[index - 1]
+        } else {
+          let last_node = new_nodes.last().expect("should have last node");
+          similarity(&last_node.key, &node.key)
+        });
+      }
+      last_kept_index = Some(index);
+      new_nodes.push(node);
+    }
+
+    *self = Self::new(new_nodes, new_similarities);
+    Some(result_nodes)
+  }
+}
+
+/// Similarity of two keys, the more characters they share at the same position, the bigger it is
+fn similarity(a: &str, b: &str) -> usize {
+  a.bytes()
+    .zip(b.bytes())
+    .map(|(ca, cb)| 10usize.saturating_sub(ca.abs_diff(cb) as usize))
+    .sum()
+}
+
+fn get_similarities(nodes: &[GroupItem]) -> Vec<usize> {
+  nodes
+    .windows(2)
+    .map(|pair| similarity(&pair[0].key, &pair[1].key))
+    .collect()
+}
+
+/// Find the shortest unused prefix of `a` that differs from `b`. Keys always contain a hash,
+/// so `a` itself is unique.
+fn get_name(a: &str, b: &str, used_names: &mut FxHashSet<String>) -> String {
+  let (a_bytes, b_bytes) = (a.as_bytes(), b.as_bytes());
+  let len = usize::min(a_bytes.len(), b_bytes.len());
+  let mut index = 0;
+  while index < len {
+    let is_different = a_bytes[index] != b_bytes[index];
+    index += 1;
+    if is_different {
+      break;
+    }
+  }
+  while index < len {
+    if let Some(name) = a.get(0..index)
+      && used_names.insert(name.to_lowercase())
+    {
+      return name.to_string();
+    }
+    index += 1;
+  }
+  a.to_string()
+}
+
+fn get_number_of_matching_size_types(size: &SplitChunkSizes, types: &[SourceType]) -> usize {
+  types.iter().filter(|ty| size.contains_key(ty)).count()
+}
+
+/// If the size of the `group`(or `considered_size`) is smaller than `min_size`, nodes having the
+/// problematic `SourceType`s are moved to the smallest result group with these `SourceType`s, so
+/// `min_size` is kept. Return `true` if the group is changed.
+fn remove_problematic_nodes(
+  group: &mut Group,
+  considered_size: Option<&SplitChunkSizes>,
+  min_size: &SplitChunkSizes,
+  results: &mut Vec<Group>,
+  fallbacks: &mut Vec<String>,
+) -> bool {
+  let problem_types = considered_size
+    .unwrap_or(&group.size)
+    .violating_min_size_types(min_size);
+  if problem_types.is_empty() {
+    return false;
+  }
+
+  let Some(problem_nodes) =
+    group.pop_nodes(|node| get_number_of_matching_size_types(&node.size, &problem_types) > 0)
+  else {
+    return false;
+  };
+
+  let best_group = results
+    .iter_mut()
+    .filter(|result| get_number_of_matching_size_types(&result.size, &problem_types) > 0)
+    .reduce(|min, result| {
+      let min_matches = get_number_of_matching_size_types(&min.size, &problem_types);
+      let result_matches = get_number_of_matching_size_types(&result.size, &problem_types);
+      if min_matches != result_matches {
+        if min_matches < result_matches {
+          result
+        } else {
+          min
+        }
+      } else if min.size.total_size_of(&problem_types) > result.size.total_size_of(&problem_types) {
+        result
+      } else {
+        min
+      }
+    });
+
+  if let Some(best_group) = best_group {
+    fallbacks.push(format!(
+      "merged {} module(s) smaller than minSize into the group of {}",
+      problem_nodes.len(),
+      best_group.nodes[0].key
+    ));
+    problem_nodes.into_iter().for_each(|node| {
+      best_group.size.add_by(&node.size);
+      best_group.nodes.push(node);
+    });
+    best_group.nodes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+    best_group.similarities = get_similarities(&best_group.nodes);
+  } else {
+    // There are no other groups with the same `SourceType`s. We create a new group and have to
+    // accept that it's smaller than minSize.
+    fallbacks.push(format!(
+      "created a group of {} module(s) smaller than minSize starting with {}",
+      problem_nodes.len(),
+      problem_nodes[0].key
+    ));
+    results.push(Group::new(problem_nodes, vec![]));
+  }
+  true
 }
 
 fn get_size(module: &dyn Module) -> SplitChunkSizes {
@@ -78,8 +225,10 @@ fn deterministic_grouping_for_modules(
   allow_max_size: &SplitChunkSizes,
   min_size: &SplitChunkSizes,
   delimiter: &str,
-) -> Vec<Group> {
+) -> (Vec<Group>, Vec<String>) {
   let mut results: Vec<Group> = Default::default();
+  // Describe every time the grouping can't fit both `min_size` and `allow_max_size`
+  let mut fallbacks: Vec<String> = Default::default();
 
   let items = compilation
     .chunk_graph
@@ -87,7 +236,7 @@ fn deterministic_grouping_for_modules(
 
   let context = compilation.options.context.as_ref();
 
-  let nodes: Vec<GroupItem> = items
+  let mut nodes: Vec<GroupItem> = items
     .into_par_iter()
     .map(|module| {
       let module: &dyn Module = &**module;
@@ -112,6 +261,9 @@ fn deterministic_grouping_for_modules(
     })
     .collect::<Vec<_>>();
 
+  // lexically ordering of keys, so groups are stable between builds
+  nodes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+
   let initial_nodes = nodes
     .into_iter()
     .filter_map(|node| {
@@ -124,8 +276,7 @@ fn deterministic_grouping_for_modules(
           node.size,
           allow_max_size
         );
-        let key = node.key.clone();
-        results.push(Group::new(vec![node], Some(key)));
+        results.push(Group::new(vec![node], vec![]));
         None
       } else {
         Some(node)
@@ -133,66 +284,141 @@ fn deterministic_grouping_for_modules(
     })
     .collect::<Vec<_>>();
 
-  let initial_group = Group::new(initial_nodes, None);
-
-  let mut queue = vec![initial_group];
+  if !initial_nodes.is_empty() {
+    let similarities = get_similarities(&initial_nodes);
+    let mut queue = vec![Group::new(initial_nodes, similarities)];
 
-  while let Some(mut group) = queue.pop() {
-    // only groups bigger than maxSize need to be split
-    if group.size.bigger_than(allow_max_size) || group.nodes.is_empty() {
-      continue;
-    }
+    while let Some(mut group) = queue.pop() {
+      // only groups bigger than maxSize need to be split
+      if !group.size.bigger_than(allow_max_size) {
+        results.push(group);
+        continue;
+      }
 
-    // find unsplittable area from left and right
-    // going minSize from left and right
-    // at least one node need to be included otherwise we get stuck
-    let mut left = 0;
-    let mut left_size = SplitChunkSizes::empty();
-    while left < group.nodes.len() && left_size.smaller_than(min_size) {
-      left_size.add_by(&group.nodes[left].size);
+      // If the group is already too small, we try to work only with the unproblematic nodes
+      if remove_problematic_nodes(&mut group, None, min_size, &mut results, &mut fallbacks) {
+        // This changed something, so we try this group again
+        queue.push(group);
+        continue;
+      }
 
-      if left != group.nodes.len() - 1 {
+      // find unsplittable area from left and right
+      // going minSize from left and right
+      // at least one node need to be included otherwise we get stuck
+      let nodes_len = group.nodes.len();
+      let mut left = 1;
+      let mut left_size = group.nodes[0].size.clone();
+      while left < nodes_len && left_size.smaller_than(min_size) {
+        left_size.add_by(&group.nodes[left].size);
         left += 1;
       }
-    }
 
-    let mut right = group.nodes.len() - 1;
-    let mut right_size = SplitChunkSizes::empty();
-    while right != 0 && right_size.smaller_than(min_size) {
-      right_size.add_by(&group.nodes[right].size);
-
-      if right != 0 {
-        right = right.saturating_sub(1);
+      let mut right = nodes_len as isize - 2;
+      let mut right_size = group.nodes[nodes_len - 1].size.clone();
+      while right >= 0 && right_size.smaller_than(min_size) {
+        right_size.add_by(&group.nodes[right as usize].size);
+        right -= 1;
       }
-    }
 
-    if left >= right {
-      // There are overlaps
+      //      left v   v right
+      // [ O O O ] O O O [ O O O ]
+      // ^^^^^^^^^ left_size
+      //       right_size ^^^^^^^^^
+      // Perfect split: [ O O O ] [ O O O ], right == left - 1
+      if left as isize - 1 > right {
+        // There are overlaps. We try to remove some problematic nodes to fix that.
+        let prev_size = if right < (nodes_len - left) as isize {
+          right_size.subtract_by(&group.nodes[(right + 1) as usize].size);
+          right_size
+        } else {
+          left_size.subtract_by(&group.nodes[left - 1].size);
+          left_size
+        };
+        if remove_problematic_nodes(
+          &mut group,
+          Some(&prev_size),
+          min_size,
+          &mut results,
+          &mut fallbacks,
+        ) {
+          queue.push(group);
+          continue;
+        }
+
+        // can't split group while holding minSize
+        // because minSize is preferred of maxSize we return
+        // the problematic nodes as result here even while it's too big
+        // To avoid this make sure maxSize > minSize * 3
+        fallbacks.push(format!(
+          "kept {} module(s) starting with {} in a group bigger than maxSize to hold minSize",
+          group.nodes.len(),
+          group.nodes[0].key
+        ));
+        results.push(group);
+        continue;
+      }
 
-      // TODO(hyf0): There are some algorithms we could do better in this
-      // situation.
+      if left as isize <= right {
+        // when there is a area between left and right we look for best split point.
+        // We split at the minimum similarity, where key space is separated the most.
+        // But we also need to make sure to not create too small groups.
+        let mut best = None;
+        let mut best_similarity = usize::MAX;
+        let mut right_size = SplitChunkSizes::empty();
+        group.nodes[left..]
+          .iter()
+          .for_each(|node| right_size.add_by(&node.size));
+        for pos in left..=(right + 1) as usize {
+          let similarity = group.similarities[pos - 1];
+          if similarity < best_similarity
+            && !left_size.smaller_than(min_size)
+            && !right_size.smaller_than(min_size)
+          {
+            best = Some(pos);
+            best_similarity = similarity;
+          }
+          left_size.add_by(&group.nodes[pos].size);
+          right_size.subtract_by(&group.nodes[pos].size);
+        }
 
-      // can't split group while holding minSize
-      // because minSize is preferred of maxSize we return
-      // the problematic nodes as result here even while it's too big
-      // To avoid this make sure maxSize > minSize * 3
-      group.key = group.nodes.first().map(|n| n.key.clone());
-      results.push(group);
-      continue;
-    }
+        let Some(best) = best else {
+          // This can't happen, but if that assumption is wrong, fallback to a big group
+          fallbacks.push(format!(
+            "found no split point for {} module(s) starting with {}",
+            group.nodes.len(),
+            group.nodes[0].key
+          ));
+          results.push(group);
+          continue;
+        };
+        left = best;
+      }
 
-    if left < right {
-      let right_nodes = group.nodes.split_off(left + 1);
-      let left_nodes = group.nodes;
-      queue.push(Group::new(right_nodes, None));
-      queue.push(Group::new(left_nodes, None));
+      // create two new groups for left and right area and queue them up
+      let right_nodes = group.nodes.split_off(left);
+      let right_similarities = group.similarities.split_off(left);
+      group.similarities.truncate(left - 1);
+      queue.push(Group::new(right_nodes, right_similarities));
+      queue.push(Group::new(group.nodes, group.similarities));
     }
   }
 
   // lexically ordering
   results.sort_unstable_by(|a, b| a.nodes[0].key.cmp(&b.nodes[0].key));
 
-  results
+  // give every group a stable name
+  let mut used_names = FxHashSet::default();
+  results.iter_mut().for_each(|group| {
+    let first = &group.nodes[0].key;
+    let last = &group.nodes[group.nodes.len() - 1].key;
+    group.key = Some(if group.nodes.len() == 1 {
+      first.clone()
+    } else {
+      get_name(first, last, &mut used_names)
+    });
+  });
+
+  (results, fallbacks)
 }
 
 struct ChunkWithSizeInfo<'a> {
@@ -286,14 +512,14 @@ impl SplitChunksPlugin {
     });
 
     let infos_with_results = chunks_with_size_info
-      .filter_map(|info| {
+      .map(|info| {
         let ChunkWithSizeInfo {
           chunk,
           allow_max_size,
           min_size,
           automatic_name_delimiter,
         } = &info;
-        let results = deterministic_grouping_for_modules(
+        let (results, fallbacks) = deterministic_grouping_for_modules(
           compilation_ref,
           chunk,
           allow_max_size,
@@ -301,9 +527,31 @@ impl SplitChunksPlugin {
           automatic_name_delimiter,
         );
 
+        (info, results, fallbacks)
+      })
+      .collect::<Vec<_>>();
+
+    let logger = compilation.get_logger(self.name());
+    let infos_with_results = infos_with_results
+      .into_iter()
+      .filter_map(|(info, results, fallbacks)| {
+        if !fallbacks.is_empty() {
+          let chunk = compilation.chunk_by_ukey.expect_get(&info.chunk);
+          let chunk_name = chunk
+            .name
+            .clone()
+            .unwrap_or_else(|| chunk.chunk_reasons.join("~"));
+          fallbacks.into_iter().for_each(|fallback| {
+            logger.log(format!(
+              "Chunk({chunk_name}) can't be split to fit both minSize and maxSize: {fallback}"
+            ));
+          });
+        }
+
         if results.len() <= 1 {
           tracing::debug!(
-            "Chunk({chunk:?}) skips the `maxSize` checking. Reason: results.len({:?}) <= 1",
+            "Chunk({:?}) skips the `maxSize` checking. Reason: results.len({:?}) <= 1",
+            info.chunk,
             results.len(),
           );
           return None;
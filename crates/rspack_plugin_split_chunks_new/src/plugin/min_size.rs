@@ -1,8 +1,8 @@
 use rayon::prelude::*;
-use rspack_core::{Compilation, SourceType};
+use rspack_core::{ChunkUkey, Compilation, SourceType};
 
 use super::ModuleGroupMap;
-use crate::{module_group::ModuleGroup, CacheGroup, SplitChunksPlugin};
+use crate::{module_group::ModuleGroup, CacheGroup, SplitChunkSizes, SplitChunksPlugin};
 
 impl SplitChunksPlugin {
   /// Return `true` if the `ModuleGroup` become empty.
@@ -39,7 +39,17 @@ impl SplitChunksPlugin {
     })
     .collect::<Box<[_]>>();
 
-    // Remove modules having violating SourceType
+    Self::remove_modules_with_source_types(compilation, module_group, &violating_source_types);
+
+    module_group.modules.is_empty()
+  }
+
+  /// Remove modules having any of the `source_types` from the `ModuleGroup`
+  pub(crate) fn remove_modules_with_source_types(
+    compilation: &Compilation,
+    module_group: &mut ModuleGroup,
+    source_types: &[SourceType],
+  ) {
     let violating_modules = module_group
       .modules
       .par_iter()
@@ -48,7 +58,7 @@ impl SplitChunksPlugin {
           .module_graph
           .module_by_identifier(module_id)
           .expect("Should have a module");
-        let having_violating_source_type = violating_source_types
+        let having_violating_source_type = source_types
           .iter()
           .any(|ty: &SourceType| module.source_types().contains(ty));
         if having_violating_source_type {
@@ -64,11 +74,35 @@ impl SplitChunksPlugin {
     violating_modules
       .into_iter()
       .for_each(|violating_module| module_group.remove_module(violating_module));
+  }
 
-    module_group.modules.is_empty()
+  /// Affected by `splitChunks.minRemainingSize`/`splitChunks.cacheGroups.{cacheGroup}.minRemainingSize`
+  ///
+  /// Return `SourceType`s whose size of the modules left in `chunk` after splitting is smaller
+  /// than `min_remaining_size`.
+  pub(crate) fn get_min_remaining_size_violating_source_types(
+    compilation: &Compilation,
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+    chunk: ChunkUkey,
+  ) -> Vec<SourceType> {
+    let mut remaining_sizes = SplitChunkSizes::empty();
+    compilation
+      .chunk_graph
+      .get_chunk_modules(&chunk, &compilation.module_graph)
+      .into_iter()
+      .filter(|module| !module_group.modules.contains(&module.identifier()))
+      .for_each(|module| {
+        module.source_types().iter().for_each(|ty| {
+          *remaining_sizes.entry(*ty).or_default() += module.size(ty);
+        });
+      });
+
+    remaining_sizes.violating_min_size_types(&cache_group.min_remaining_size)
   }
 
   /// Affected by `splitChunks.minSize`/`splitChunks.cacheGroups.{cacheGroup}.minSize`
+  /// Affected by `splitChunks.minSizeReduction`/`splitChunks.cacheGroups.{cacheGroup}.minSizeReduction`
   #[tracing::instrument(skip_all)]
   pub(crate) fn ensure_min_size_fit(
     &self,
@@ -86,19 +120,29 @@ impl SplitChunksPlugin {
             module_group_key,
             cache_group.key,
           );
-          return None;
-        }
-
-        if Self::remove_min_size_violating_modules(
+        } else if Self::remove_min_size_violating_modules(
           module_group_key,
           compilation,
           module_group,
           cache_group,
         ) {
-          Some(module_group_key.clone())
-        } else {
-          None
+          return Some(module_group_key.clone());
         }
+
+        if !module_group.sizes.fits_min_size_reduction(
+          &cache_group.min_size_reduction,
+          module_group.chunks.len(),
+        ) {
+          tracing::debug!(
+            "ModuleGroup({}) is removed. Reason: size reduction doesn't fit min_size_reduction {:?} of CacheGroup({})",
+            module_group_key,
+            cache_group.min_size_reduction,
+            cache_group.key,
+          );
+          return Some(module_group_key.clone());
+        }
+
+        None
       })
       .collect::<Vec<_>>();
 
@@ -94,9 +94,16 @@ impl SplitChunksPlugin {
         module_group.chunks.remove(&new_chunk);
       }
 
+      // `ModuleGroup`s reaching `enforce_size_threshold` are split regardless of `max_*_requests`
+      // and `min_remaining_size`
+      let enforced = cache_group.enforce_size_threshold.has_non_zero_sizes()
+        && module_group.sizes.fits_min_size(&cache_group.enforce_size_threshold);
+
       let mut used_chunks = Cow::Borrowed(&module_group.chunks);
 
-      self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      if !enforced {
+        self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      }
 
       if used_chunks.len() != module_group.chunks.len() {
         // There are some chunks removed by `ensure_max_request_fit`
@@ -113,6 +120,33 @@ impl SplitChunksPlugin {
         }
       }
 
+      if !enforced
+        && used_chunks.len() == 1
+        && cache_group.min_remaining_size.has_non_zero_sizes()
+      {
+        let chunk = *used_chunks.iter().next().expect("should have one chunk");
+        let violating_source_types = Self::get_min_remaining_size_violating_source_types(
+          compilation,
+          &module_group,
+          cache_group,
+          chunk,
+        );
+        if !violating_source_types.is_empty() {
+          tracing::trace!("ModuleGroup({module_group_key}) violates min_remaining_size with {violating_source_types:?}");
+          let old_modules_len = module_group.modules.len();
+          Self::remove_modules_with_source_types(
+            compilation,
+            &mut module_group,
+            &violating_source_types,
+          );
+          if !module_group.modules.is_empty() && module_group.modules.len() != old_modules_len {
+            // Queue the `ModuleGroup` again to be processed without the violating modules
+            module_group_map.insert(module_group_key.clone(), module_group);
+          }
+          return;
+        }
+      }
+
       if !cache_group.max_initial_size.is_empty() || !cache_group.max_async_size.is_empty() {
         max_size_setting_map.insert(
           new_chunk,
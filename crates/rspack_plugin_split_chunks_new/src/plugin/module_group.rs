@@ -329,6 +329,18 @@ impl SplitChunksPlugin {
           return Some(key.clone());
         }
 
+        // Validate `min_size_reduction` again
+        if !other_module_group
+          .sizes
+          .fits_min_size_reduction(&cache_group.min_size_reduction, other_module_group.chunks.len())
+        {
+          tracing::trace!(
+            "{key} is deleted for violating min_size_reduction {:#?}",
+            cache_group.min_size_reduction,
+          );
+          return Some(key.clone());
+        }
+
         None
       })
       .collect::<Vec<_>>();
@@ -303,6 +303,7 @@ pub fn minify(
               inline_sources_content: opts.inline_sources_content,
               emit_columns: true,
               names: source_map_names,
+              keep_mappings: false,
             },
             true,
             Some(&comments),
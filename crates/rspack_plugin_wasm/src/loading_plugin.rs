@@ -3,13 +3,98 @@ use rspack_core::{
   RuntimeGlobals, RuntimeModuleExt, RuntimeRequirementsInTreeArgs, WasmLoadingType,
 };
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{get_sync_wasm_modules, AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin.boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin.boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::new(false).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileWasmPlugin::new(true).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
+  }
+}
+
+/// Whether any chunk loaded on demand from the chunk contains a
+/// `webassembly/sync` module.
+fn has_async_sync_wasm_modules(args: &RuntimeRequirementsInTreeArgs) -> bool {
+  let compilation = &args.compilation;
+  compilation
+    .chunk_by_ukey
+    .expect_get(args.chunk)
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .any(|chunk| get_sync_wasm_modules(compilation, chunk).next().is_some())
+}
+
+/// The code fetching the wasm file, failed requests are retried with the
+/// `output.chunkLoadRetry` policy when it's enabled.
+fn render_fetch_load_code(args: &mut RuntimeRequirementsInTreeArgs) -> String {
+  args
+    .runtime_requirements_mut
+    .insert(RuntimeGlobals::PUBLIC_PATH);
+  if args
+    .compilation
+    .options
+    .output
+    .chunk_load_retry
+    .is_enabled()
+  {
+    args
+      .runtime_requirements_mut
+      .insert(RuntimeGlobals::CHUNK_LOAD_RETRY);
+    format!(
+      "{}.fetch({} + $PATH, \"wasm\")",
+      RuntimeGlobals::CHUNK_LOAD_RETRY,
+      RuntimeGlobals::PUBLIC_PATH
+    )
+  } else {
+    format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH)
+  }
+}
+
+#[derive(Debug)]
+pub struct FetchCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  async fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if !args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      || !has_async_sync_wasm_modules(args)
+    {
+      return Ok(());
+    }
+
+    let load_code = render_fetch_load_code(args);
+    args
+      .compilation
+      .add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(load_code, true, *args.chunk).boxed(),
+      )
+      .await?;
+
+    Ok(())
   }
 }
 
@@ -27,21 +112,16 @@ impl Plugin for FetchCompileAsyncWasmPlugin {
     _ctx: PluginContext,
     args: &mut RuntimeRequirementsInTreeArgs,
   ) -> PluginRuntimeRequirementsInTreeOutput {
-    let runtime_requirements = args.runtime_requirements;
-    let runtime_requirements_mut = &mut args.runtime_requirements_mut;
-
-    if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
-      runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::INSTANTIATE_WASM)
+    {
+      let load_code = render_fetch_load_code(args);
       args
         .compilation
         .add_runtime_module(
           args.chunk,
-          AsyncWasmLoadingRuntimeModule::new(
-            format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
-            true,
-            *args.chunk,
-          )
-          .boxed(),
+          AsyncWasmLoadingRuntimeModule::new(load_code, true, *args.chunk).boxed(),
         )
         .await?;
     }
@@ -50,6 +130,57 @@ impl Plugin for FetchCompileAsyncWasmPlugin {
   }
 }
 
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self { import }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  async fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if !args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      || !has_async_sync_wasm_modules(args)
+    {
+      return Ok(());
+    }
+
+    args
+      .compilation
+      .add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          if self.import {
+            include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+          } else {
+            include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+          },
+          false,
+          *args.chunk,
+        )
+        .boxed(),
+      )
+      .await?;
+
+    Ok(())
+  }
+}
+
 #[derive(Debug)]
 pub struct ReadFileCompileAsyncWasmPlugin {
   import: bool,
@@ -1,5 +1,5 @@
 use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use std::hash::Hasher;
 
 use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
@@ -62,6 +62,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
                     module.into(),
                     name.into(),
                     ty,
+                    None,
                   )));
                 }
                 Err(err) => diagnostic.push(Diagnostic::error(
@@ -125,7 +126,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
       ..
     } = generate_context;
     let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
-    let hash = hash_for_source(source);
+    let hash = hash_for_source(source.as_ref());
     let normal_module = module
       .as_normal_module()
       .expect("module should be a NormalModule in AsyncWasmParserAndGenerator::generate");
@@ -281,7 +282,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &Filename,
@@ -296,13 +297,13 @@ fn render_wasm_name(
   )
 }
 
-fn render_import_stmt(import_var: &str, module_id: &str) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &dyn Source) -> String {
   let mut hasher = DefaultHasher::new();
-  source.hash(&mut hasher);
+  source.update_hash(&mut hasher);
   format!("{:016x}", hasher.finish())
 }
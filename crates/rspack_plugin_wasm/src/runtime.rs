@@ -1,11 +1,15 @@
+use std::collections::BTreeMap;
+
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, BoxModule, ChunkUkey, Compilation,
+  ModuleType, PathData, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
 
+use crate::{hash_for_source, render_wasm_name};
+
 #[impl_runtime_module]
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -103,3 +107,144 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     "#
   )
 }
+
+/// Compiles the `webassembly/sync` modules of a chunk while the chunk is
+/// loaded, the compiled modules are instantiated synchronously when required.
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk,
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let mut wasm_module_map = BTreeMap::<&str, Vec<&str>>::new();
+    let mut wasm_module_files = BTreeMap::<&str, String>::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let Some(chunk_id) = compilation
+        .chunk_by_ukey
+        .expect_get(&chunk_ukey)
+        .id
+        .as_deref()
+      else {
+        continue;
+      };
+      for module in get_sync_wasm_modules(compilation, &chunk_ukey) {
+        let Some(normal_module) = module.as_normal_module() else {
+          continue;
+        };
+        let module_id = compilation
+          .chunk_graph
+          .get_module_id(module.identifier())
+          .as_deref()
+          .expect("should have module id");
+        let hash = module
+          .original_source()
+          .map(hash_for_source)
+          .unwrap_or_default();
+        let (filename, _) = render_wasm_name(
+          compilation,
+          normal_module,
+          &compilation.options.output.webassembly_module_filename,
+          &hash,
+        );
+        wasm_module_map.entry(chunk_id).or_default().push(module_id);
+        wasm_module_files.insert(module_id, filename);
+      }
+    }
+
+    RawSource::from(get_wasm_chunk_loading(
+      &serde_json::to_string(&wasm_module_map).expect("should be valid json"),
+      &serde_json::to_string(&wasm_module_files).expect("should be valid json"),
+      &self
+        .generate_load_binary_code
+        .replace("$PATH", "wasmModuleFiles[wasmModuleId]"),
+      self.supports_streaming,
+    ))
+    .boxed()
+  }
+}
+
+pub(crate) fn get_sync_wasm_modules<'a>(
+  compilation: &'a Compilation,
+  chunk: &ChunkUkey,
+) -> impl Iterator<Item = &'a BoxModule> {
+  compilation
+    .chunk_graph
+    .get_chunk_modules(chunk, &compilation.module_graph)
+    .into_iter()
+    .filter(|module| *module.module_type() == ModuleType::WasmSync)
+}
+
+fn get_wasm_chunk_loading(
+  wasm_module_map: &str,
+  wasm_module_files: &str,
+  req: &str,
+  supports_streaming: bool,
+) -> String {
+  let compile_code = if supports_streaming {
+    r#"
+        if (typeof WebAssembly.compileStreaming === 'function') {
+          promise = WebAssembly.compileStreaming(req);
+        } else {
+          promise = req.then(function(x) { return x.arrayBuffer(); }).then(function(bytes) { return WebAssembly.compile(bytes); });
+        }
+    "#
+  } else {
+    r#"
+        promise = req.then(function(x) { return x.arrayBuffer(); }).then(function(bytes) { return WebAssembly.compile(bytes); });
+    "#
+  };
+  format!(
+    r#"
+    var installedWasmModules = {{}};
+    var wasmModuleMap = {wasm_module_map};
+    var wasmModuleFiles = {wasm_module_files};
+    {wasm_instances} = {{}};
+    {ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+      var wasmModules = wasmModuleMap[chunkId] || [];
+      wasmModules.forEach(function(wasmModuleId) {{
+        var installedWasmModuleData = installedWasmModules[wasmModuleId];
+        // a Promise means "currently compiling", a WebAssembly.Module means "already compiled"
+        if (installedWasmModuleData) {{
+          if (installedWasmModuleData.then) promises.push(installedWasmModuleData);
+          return;
+        }}
+        var req = {req};
+        var promise;
+        {compile_code}
+        promises.push(installedWasmModules[wasmModuleId] = promise.then(function(wasmModule) {{
+          return {wasm_instances}[wasmModuleId] = installedWasmModules[wasmModuleId] = wasmModule;
+        }}));
+      }});
+    }};
+    "#,
+    wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+    ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+  )
+}
@@ -0,0 +1,298 @@
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, GenerateContext, Module, ModuleDependency, ModuleIdentifier,
+  ParseContext, ParseResult, ParserAndGenerator, RuntimeGlobals, SourceType,
+  StaticExportsDependency, UsedName,
+};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::atoms::Atom;
+use wasmparser::{
+  CompositeType, ExternalKind, FuncType, Import, Parser, Payload, TypeRef, ValType,
+};
+
+use crate::dependency::WasmImportDependency;
+use crate::{
+  hash_for_source, render_import_stmt, render_wasm_name, ModuleIdToFileName, WASM_SOURCE_TYPE,
+};
+
+/// Only these value types can be passed between JavaScript and WebAssembly
+/// without the BigInt integration.
+fn is_js_compatible(ty: &ValType) -> bool {
+  matches!(ty, ValType::I32 | ValType::F32 | ValType::F64)
+}
+
+fn get_js_incompatible_type(ty: &FuncType) -> Option<String> {
+  if let Some(param) = ty.params().iter().find(|ty| !is_js_compatible(ty)) {
+    return Some(format!("{param} as parameter"));
+  }
+  if let Some(result) = ty.results().iter().find(|ty| !is_js_compatible(ty)) {
+    return Some(format!("{result} as result"));
+  }
+  None
+}
+
+/// The webpack 4 style `webassembly/sync` modules. The binary is compiled while
+/// the chunk containing the module is loaded, see [crate::WasmChunkLoadingRuntimeModule],
+/// so it can be instantiated synchronously when the module is required.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut types: Vec<Option<FuncType>> = vec![];
+    // type index of every function, imported functions come first
+    let mut functions: Vec<u32> = vec![];
+    let mut exports = Vec::with_capacity(1);
+    let mut js_incompatible_exports = HashMap::default();
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for rec_group in s {
+              match rec_group {
+                Ok(rec_group) => {
+                  types.extend(rec_group.types().map(|ty| match &ty.composite_type {
+                    CompositeType::Func(func) => Some(func.clone()),
+                    _ => None,
+                  }))
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Type Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let only_direct_import = match ty {
+                    TypeRef::Memory(_) => Some("Memory".to_string()),
+                    TypeRef::Table(_) => Some("Table".to_string()),
+                    TypeRef::Func(index) => {
+                      functions.push(index);
+                      types
+                        .get(index as usize)
+                        .and_then(|ty| ty.as_ref())
+                        .and_then(get_js_incompatible_type)
+                        .map(|ty| format!("Non-JS-compatible Func Signature ({ty})"))
+                    }
+                    TypeRef::Global(global) if !is_js_compatible(&global.content_type) => Some(
+                      format!("Non-JS-compatible Global Type ({})", global.content_type),
+                    ),
+                    _ => None,
+                  };
+                  dependencies.push(Box::new(WasmImportDependency::new(
+                    module.into(),
+                    name.into(),
+                    ty,
+                    only_direct_import,
+                  )));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::FunctionSection(s) => {
+            for function in s {
+              match function {
+                Ok(index) => functions.push(index),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Function Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => {
+                  if export.kind == ExternalKind::Func
+                    && let Some(incompatible_type) = functions
+                      .get(export.index as usize)
+                      .and_then(|index| types.get(*index as usize))
+                      .and_then(|ty| ty.as_ref())
+                      .and_then(get_js_incompatible_type)
+                  {
+                    js_incompatible_exports.insert(Atom::from(export.name), incompatible_type);
+                  }
+                  exports.push(export.name.to_string());
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                )),
+              };
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    parse_context.build_info.js_incompatible_exports =
+      (!js_incompatible_exports.is_empty()).then_some(js_incompatible_exports);
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      exports.iter().cloned().map(Atom::from).collect::<Vec<_>>(),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => 95.0 + module.get_dependencies().len() as f64 * 20.0,
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let GenerateContext {
+      compilation,
+      runtime,
+      ..
+    } = generate_context;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source.as_ref());
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let module_graph = &compilation.module_graph;
+        let chunk_graph = &compilation.chunk_graph;
+
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        let mut wasm_deps_by_request = IndexMap::<&str, Vec<String>>::new();
+
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+        {
+          let Some(mgm) = module_graph.module_graph_module_by_dependency_id(dep.id()) else {
+            continue;
+          };
+          let dep = dep
+            .as_any()
+            .downcast_ref::<WasmImportDependency>()
+            .expect("should be wasm import dependency");
+
+          let import_var = match dep_modules.get(&mgm.module_identifier) {
+            Some((import_var, _)) => import_var.clone(),
+            None => {
+              let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+              dep_modules.insert(
+                mgm.module_identifier,
+                (import_var.clone(), mgm.id(chunk_graph)),
+              );
+              import_var
+            }
+          };
+
+          let used_name = module_graph
+            .get_exports_info(&mgm.module_identifier)
+            .id
+            .get_used_name(module_graph, *runtime, UsedName::Str(dep.name().into()));
+          let Some(UsedName::Str(used_name)) = used_name else {
+            continue;
+          };
+          wasm_deps_by_request
+            .entry(dep.request())
+            .or_default()
+            .push(format!(
+              "{}: {import_var}[{}]",
+              serde_json::to_string(dep.name()).expect("should be ok."),
+              serde_json::to_string(&used_name).expect("should convert to json string"),
+            ));
+        }
+
+        let imports_code = dep_modules
+          .values()
+          .map(|(import_var, module_id)| render_import_stmt(import_var, module_id))
+          .collect::<Vec<_>>()
+          .join("");
+
+        let imports_obj = wasm_deps_by_request
+          .into_iter()
+          .map(|(request, deps)| {
+            format!(
+              "{}: {{\n{}\n}}",
+              serde_json::to_string(request).expect("should be ok"),
+              deps.join(",\n")
+            )
+          })
+          .collect::<Vec<_>>()
+          .join(",\n");
+
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        Ok(
+          RawSource::from(format!(
+            "{imports_code}// instantiate the WebAssembly module compiled while the chunk was loaded\nmodule.exports = new WebAssembly.Instance({}[module.id], {{\n{imports_obj}\n}}).exports;\n",
+            RuntimeGlobals::WASM_INSTANCES,
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+}
@@ -0,0 +1,283 @@
+use rayon::prelude::*;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationArgs, CompilationParams, CompilerOptions,
+  Dependency, DependencyType, ExportInfoProvided, ModuleDependency, ModuleType, OptimizeChunksArgs,
+  ParserAndGenerator, Plugin, PluginCompilationHookOutput, PluginContext,
+  PluginOptimizeChunksOutput, PluginRenderManifestHookOutput, RenderManifestArgs,
+  RenderManifestEntry, SourceType,
+};
+use rspack_error::{codes, Diagnostic, IntoTWithDiagnosticArray, Result};
+use rspack_plugin_javascript::dependency::get_static_exports;
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::atoms::Atom;
+
+use crate::dependency::WasmImportDependency;
+use crate::{get_sync_wasm_modules, ModuleIdToFileName, SyncWasmParserAndGenerator};
+
+/// Port of webpack's `WebAssemblyModulesPlugin`, which handles the webpack 4
+/// style `webassembly/sync` modules.
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+impl SyncWasmPlugin {
+  pub fn new() -> SyncWasmPlugin {
+    Self {
+      module_id_to_filename_without_ext: Default::default(),
+    }
+  }
+}
+
+/// Checks the imports of the wasm modules, and the usages of the exports that
+/// can't be called from JavaScript.
+///
+/// The exports of the imported modules are flagged by `FlagDependencyExportsPlugin` with the
+/// new tree shaking, otherwise only the names a module exports itself are known.
+fn check_wasm_imports_and_exports(compilation: &Compilation) -> Vec<Diagnostic> {
+  let exports_flagged =
+    compilation.options.is_new_tree_shaking() && compilation.options.optimization.provided_exports;
+  let module_graph = &compilation.module_graph;
+  let context = &compilation.options.context;
+  let mut diagnostics = vec![];
+
+  for module in module_graph
+    .modules()
+    .values()
+    .filter(|module| *module.module_type() == ModuleType::WasmSync)
+  {
+    for dep in module
+      .get_dependencies()
+      .iter()
+      .filter_map(|id| module_graph.dependency_by_id(id))
+      .filter_map(|dep| dep.downcast_ref::<WasmImportDependency>())
+    {
+      let Some(imported_module) = module_graph.get_module(dep.id()) else {
+        continue;
+      };
+      if imported_module.module_type().is_wasm_like() {
+        continue;
+      }
+      if let Some(only_direct_import) = dep.only_direct_import() {
+        diagnostics.push(
+          Diagnostic::error(
+            codes::UNSUPPORTED_WEBASSEMBLY_FEATURE.into(),
+            format!(
+              "Import \"{}\" from \"{}\" with {only_direct_import} can only be used for direct wasm to wasm dependencies",
+              dep.name(),
+              dep.request(),
+            ),
+          )
+          .with_module_identifier(Some(module.identifier())),
+        );
+        continue;
+      }
+      // Only report the imports which are known to be missing, the exports of
+      // e.g. CommonJS modules are unknown until runtime
+      let name = Atom::from(dep.name());
+      let not_provided = if exports_flagged {
+        let exports_info = module_graph.get_exports_info(&imported_module.identifier());
+        let export_info_id = exports_info
+          .exports
+          .get(&name)
+          .unwrap_or(&exports_info.other_exports_info);
+        exports_info.redirect_to.is_none()
+          && matches!(
+            export_info_id.get_export_info(module_graph).provided,
+            Some(ExportInfoProvided::False)
+          )
+      } else {
+        get_static_exports(module_graph, &imported_module.identifier())
+          .is_some_and(|exports| !exports.contains(&name))
+      };
+      if not_provided {
+        diagnostics.push(
+          Diagnostic::error(
+            codes::WEBASSEMBLY_IMPORT_ERROR.into(),
+            format!(
+              "Import \"{}\" from \"{}\" was not found in \"{}\"",
+              dep.name(),
+              dep.request(),
+              imported_module.readable_identifier(context),
+            ),
+          )
+          .with_module_identifier(Some(module.identifier())),
+        );
+      }
+    }
+
+    let Some(js_incompatible_exports) = module
+      .build_info()
+      .and_then(|build_info| build_info.js_incompatible_exports.as_ref())
+    else {
+      continue;
+    };
+    for connection in module_graph.get_incoming_connections(module) {
+      let Some(origin_module) = connection
+        .original_module_identifier
+        .and_then(|id| module_graph.module_by_identifier(&id))
+      else {
+        continue;
+      };
+      if origin_module.module_type().is_wasm_like() {
+        continue;
+      }
+      let Some(dep) = module_graph.dependency_by_id(&connection.dependency_id) else {
+        continue;
+      };
+      if !matches!(
+        dep.dependency_type(),
+        DependencyType::EsmImportSpecifier | DependencyType::EsmExportImportedSpecifier
+      ) {
+        continue;
+      }
+      let ids = dep.get_ids(module_graph);
+      let Some((name, incompatible_type)) = ids
+        .first()
+        .and_then(|name| js_incompatible_exports.get_key_value(name))
+      else {
+        continue;
+      };
+      diagnostics.push(
+        Diagnostic::error(
+          codes::UNSUPPORTED_WEBASSEMBLY_FEATURE.into(),
+          format!(
+            "Export \"{name}\" with {incompatible_type} can only be used for direct wasm to wasm dependencies\nIt's used from {}",
+            origin_module.readable_identifier(context),
+          ),
+        )
+        .with_module_identifier(Some(origin_module.identifier())),
+      );
+    }
+  }
+
+  diagnostics
+}
+
+/// The issuer chain from the entry to the module, e.g. `./index.js --> ./module.wasm`.
+fn get_issuer_chain(compilation: &Compilation, module: &BoxModule) -> String {
+  let module_graph = &compilation.module_graph;
+  let context = &compilation.options.context;
+  let mut visited = HashSet::default();
+  let mut chain = vec![module.readable_identifier(context)];
+  let mut current = module;
+  while visited.insert(current.identifier())
+    && let Some(issuer) = module_graph.get_issuer(current)
+  {
+    chain.push(issuer.readable_identifier(context));
+    current = issuer;
+  }
+  chain.reverse();
+  chain.join(" --> ")
+}
+
+#[async_trait::async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmImport,
+      params.normal_module_factory.clone(),
+    );
+    Ok(())
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    let builder = move || {
+      Box::new({
+        SyncWasmParserAndGenerator {
+          module_id_to_filename: module_id_to_filename_without_ext.clone(),
+        }
+      }) as Box<dyn ParserAndGenerator>
+    };
+
+    ctx
+      .context
+      .register_parser_and_generator_builder(ModuleType::WasmSync, Box::new(builder));
+
+    Ok(())
+  }
+
+  // webpack checks them in `finishModules`, which runs twice with the new tree shaking
+  async fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<Option<()>> {
+    let diagnostics = check_wasm_imports_and_exports(compilation);
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(None)
+  }
+
+  async fn optimize_chunks(
+    &self,
+    _ctx: PluginContext,
+    args: OptimizeChunksArgs<'_>,
+  ) -> PluginOptimizeChunksOutput {
+    let compilation = args.compilation;
+    let mut diagnostics = vec![];
+    for (chunk_ukey, chunk) in compilation.chunk_by_ukey.iter() {
+      if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        continue;
+      }
+      for module in get_sync_wasm_modules(compilation, chunk_ukey) {
+        diagnostics.push(
+          Diagnostic::error(
+            codes::WEBASSEMBLY_IN_INITIAL_CHUNK.into(),
+            format!(
+              "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module:\n* {}",
+              get_issuer_chain(compilation, module),
+            ),
+          )
+          .with_module_identifier(Some(module.identifier())),
+        );
+      }
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    let compilation = args.compilation;
+    let chunk = args.chunk();
+    let module_graph = &compilation.module_graph;
+
+    let ordered_modules = compilation
+      .chunk_graph
+      .get_chunk_modules(&args.chunk_ukey, module_graph);
+
+    let files = ordered_modules
+      .par_iter()
+      .filter(|m| *m.module_type() == ModuleType::WasmSync)
+      .filter_map(|m| {
+        let code_gen_result = compilation
+          .code_generation_results
+          .get(&m.identifier(), Some(&chunk.runtime));
+
+        code_gen_result.get(&SourceType::Wasm).map(|source| {
+          let (output_path, asset_info) = self
+            .module_id_to_filename_without_ext
+            .get(&m.identifier())
+            .map(|s| s.clone())
+            .expect("should have wasm_filename");
+          RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+        })
+      })
+      .collect::<Vec<RenderManifestEntry>>();
+
+    Ok(files.with_empty_diagnostic())
+  }
+}
@@ -37,7 +37,8 @@ async fn main() {
   let raw: RawOptions = serde_json::from_slice(&raw).expect("ok");
   let mut plugins = Vec::new();
   let options = raw.apply(&mut plugins).expect("should be ok");
-  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
   compiler
     .build()
     .await
@@ -1,31 +1,31 @@
 use std::{fmt::Debug, path::Path, sync::Arc};
 
 use rspack_core::{
-  BoxLoader, CompilerOptions, NormalModule, Plugin, Resolver, BUILTIN_LOADER_PREFIX,
+  builtin_loader_factory, ApplyContext, BoxLoader, CompilerOptions, NormalModule, Plugin,
+  PluginContext, Resolver,
 };
 use rspack_error::{error, Result};
-use rspack_loader_swc::SWC_LOADER_IDENTIFIER;
+use rspack_loader_swc::{SwcLoader, SwcLoaderJsOptions, SWC_LOADER_IDENTIFIER};
 
 #[derive(Debug)]
 pub struct BuiltinLoaderResolver;
 
-fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
-  if builtin.starts_with(SWC_LOADER_IDENTIFIER) {
-    return Arc::new(
-      rspack_loader_swc::SwcLoader::new(
-        serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
-          panic!("Could not parse builtin:swc-loader options:{options:?},error: {e:?}")
-        }),
-      )
-      .with_identifier(builtin.into()),
+#[async_trait::async_trait]
+impl Plugin for BuiltinLoaderResolver {
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.register_builtin_loader(
+      SWC_LOADER_IDENTIFIER,
+      builtin_loader_factory(|identifier, options: SwcLoaderJsOptions| {
+        Arc::new(SwcLoader::new(options).with_identifier(identifier.into()))
+      }),
     );
+    Ok(())
   }
 
-  unreachable!("Unexpected builtin loader: {builtin}")
-}
-
-#[async_trait::async_trait]
-impl Plugin for BuiltinLoaderResolver {
   async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
     let contains_inline = module.contains_inline_loader();
 
@@ -44,12 +44,8 @@ impl Plugin for BuiltinLoaderResolver {
     _context: &Path,
     _resolver: &Resolver,
     loader_request: &str,
-    loader_options: Option<&str>,
+    _loader_options: Option<&str>,
   ) -> Result<Option<BoxLoader>> {
-    if loader_request.starts_with(BUILTIN_LOADER_PREFIX) {
-      return Ok(Some(get_builtin_loader(loader_request, loader_options)));
-    }
-
     Err(error!(
       "JS loaders are not supported in Rust tests: {loader_request}"
     ))
@@ -1,11 +1,11 @@
 use std::collections::{HashMap, HashSet};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use cargo_rst::{helper::make_relative_from, rst::RstBuilder};
 use insta::{assert_snapshot, Settings};
 use itertools::Itertools;
 use rspack_binding_options::RawOptions;
-use rspack_core::{BoxPlugin, Compiler, CompilerOptions};
+use rspack_core::{BoxPlugin, Compiler, CompilerOptions, LogType};
 use rspack_fs::AsyncNativeFileSystem;
 use rspack_tracing::enable_tracing_by_env;
 
@@ -114,7 +114,8 @@ pub async fn test_fixture_share(
   if options.output.path.exists() {
     std::fs::remove_dir_all(&options.output.path).expect("should remove output");
   }
-  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
 
   compiler
     .build()
@@ -203,7 +204,8 @@ pub async fn test_rebuild_fixture(
   if options.output.path.exists() {
     std::fs::remove_dir_all(&options.output.path).expect("should remove output");
   }
-  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
   compiler
     .build()
     .await
@@ -302,3 +304,184 @@ pub async fn test_rebuild_fixture(
     cb(compiler);
   }
 }
+
+/// Copy `from` into `to`, skipping the entries of `from` named in `exclude`.
+fn copy_dir(from: &Path, to: &Path, exclude: &[&str]) {
+  std::fs::create_dir_all(to).expect("should create dir");
+  for entry in std::fs::read_dir(from).expect("should read dir") {
+    let entry = entry.expect("should read dir entry");
+    if exclude.iter().any(|name| entry.file_name() == *name) {
+      continue;
+    }
+    let target = to.join(entry.file_name());
+    if entry.path().is_dir() {
+      copy_dir(&entry.path(), &target, &[]);
+    } else {
+      std::fs::copy(entry.path(), target).expect("should copy file");
+    }
+  }
+}
+
+/// Apply a step of [test_rebuild_steps_fixture] to `work_dir`, returns the changed and removed files.
+fn apply_rebuild_step(step_dir: &Path, work_dir: &Path) -> (HashSet<String>, HashSet<String>) {
+  let mut changed_files = HashSet::new();
+  let mut removed_files = HashSet::new();
+  for kind in ["created", "changed", "removed"] {
+    let kind_dir = step_dir.join(kind);
+    for file in read_dir_reverse(&kind_dir) {
+      let relative = Path::new(&file)
+        .strip_prefix(&kind_dir)
+        .expect("should be in the step dir");
+      let target = work_dir.join(relative);
+      if kind == "removed" {
+        std::fs::remove_file(&target)
+          .unwrap_or_else(|e| panic!("failed to remove {}: {e}", target.display()));
+        removed_files.insert(target.to_string_lossy().to_string());
+      } else {
+        if let Some(parent) = target.parent() {
+          std::fs::create_dir_all(parent).expect("should create dir");
+        }
+        std::fs::copy(&file, &target).expect("should copy file");
+        changed_files.insert(target.to_string_lossy().to_string());
+      }
+    }
+  }
+  (changed_files, removed_files)
+}
+
+/// Snapshot of the emitted assets and the diagnostics of the last (re)build.
+fn snapshot_rebuild_step(
+  compiler: &Compiler<AsyncNativeFileSystem>,
+  work_dir: &Path,
+  stats_filter: &dyn Fn(&str) -> bool,
+) -> String {
+  let compilation = &compiler.compilation;
+  let assets = compilation
+    .emitted_assets
+    .iter()
+    .map(|filename| filename.key().clone())
+    .filter(|filename| stats_filter(filename))
+    .sorted()
+    .map(|filename| {
+      let content = compilation
+        .assets()
+        .get(&filename)
+        .and_then(|asset| asset.get_source())
+        .map(|source| source.source().to_string())
+        .unwrap_or(String::from("this is an empty asset"));
+      let tag = Path::new(&filename)
+        .extension()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or(String::from("txt"));
+      format!("```{tag} title={filename}\n{content}\n```")
+    })
+    .join("\n\n");
+  let diagnostics = compilation
+    .get_stats()
+    .emit_diagnostics_string(true)
+    .expect("failed to emit diagnostics to string");
+  let diagnostics = if diagnostics.is_empty() {
+    String::from("No diagnostics")
+  } else {
+    diagnostics
+  };
+  let mut content = format!("# Assets\n\n{assets}\n\n# Diagnostics\n\n{diagnostics}");
+  if compiler.options.experiments.cache_unaffected {
+    // The modules flagged as affected show which memory caches are dropped and which are reused
+    let module_mem_caches = compilation
+      .get_logging()
+      .get("rspack.Compilation")
+      .map(|logs| {
+        logs
+          .iter()
+          .filter_map(|log| match log {
+            LogType::Log { message } if message.contains("flagged as affected") => {
+              Some(message.clone())
+            }
+            _ => None,
+          })
+          .join("\n")
+      })
+      .unwrap_or_default();
+    content.push_str(&format!(
+      "\n\n# Module memory caches\n\n{module_mem_caches}"
+    ));
+  }
+  content.replace(&*work_dir.to_string_lossy(), "<fixture>")
+}
+
+/// Build the fixture, then rebuild it after each step in `steps`, e.g. `steps/1`, `steps/2`.
+///
+/// Like [test_rebuild_fixture], a step has `created`, `changed` and `removed` directories mirroring
+/// the files of the fixture. The files of `removed` are only names, their content is ignored.
+/// The emitted assets, e.g. hot update chunks and manifests, and the diagnostics of the build
+/// and of every step are snapshotted as `step-0`, `step-1` and so on.
+///
+/// The fixture is copied into a temporary directory, so the steps never touch the fixture.
+#[tokio::main]
+pub async fn test_rebuild_steps_fixture(
+  fixture_path: &Path,
+  stats_filter: &dyn Fn(&str) -> bool,
+  mut mut_settings: Box<MutTestOptionsFn>,
+) -> Compiler<AsyncNativeFileSystem> {
+  enable_tracing_by_env(&std::env::var("TRACE").ok().unwrap_or_default(), "stdout");
+
+  let mut settings = Settings::clone_current();
+  settings.set_snapshot_path(fixture_path.join("snapshot"));
+  settings.set_omit_expression(true);
+  settings.set_prepend_module_to_snapshot(false);
+
+  let work_dir = fixture_path
+    .components()
+    .filter_map(|component| match component {
+      Component::Normal(component) => Some(component),
+      _ => None,
+    })
+    .fold(
+      std::env::temp_dir().join("rspack_rebuild_steps"),
+      |dir, component| dir.join(component),
+    );
+  if work_dir.exists() {
+    std::fs::remove_dir_all(&work_dir).expect("should remove work dir");
+  }
+  copy_dir(fixture_path, &work_dir, &["steps", "snapshot", "dist"]);
+
+  let mut steps = std::fs::read_dir(fixture_path.join("steps"))
+    .expect("should have steps")
+    .map(|entry| entry.expect("should read dir entry").path())
+    .map(|path| {
+      let step = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.parse::<usize>().ok())
+        .unwrap_or_else(|| panic!("step {} should be named by a number", path.display()));
+      (step, path)
+    })
+    .collect::<Vec<_>>();
+  steps.sort_unstable();
+
+  let (mut options, mut plugins) = apply_from_fixture(&work_dir);
+  mut_settings(&mut plugins, &mut options);
+  let mut compiler =
+    Compiler::new(options, plugins, AsyncNativeFileSystem).expect("should create compiler");
+  compiler
+    .build()
+    .await
+    .unwrap_or_else(|e| panic!("failed to compile in fixture {fixture_path:?}, {e:#?}"));
+  let content = snapshot_rebuild_step(&compiler, &work_dir, stats_filter);
+  settings.bind(|| assert_snapshot!("step-0", content));
+
+  for (step, step_dir) in steps {
+    let (changed_files, removed_files) = apply_rebuild_step(&step_dir, &work_dir);
+    compiler
+      .rebuild(changed_files, removed_files)
+      .await
+      .unwrap_or_else(|e| {
+        panic!("failed to rebuild step {step} in fixture {fixture_path:?}, {e:#?}")
+      });
+    let content = snapshot_rebuild_step(&compiler, &work_dir, stats_filter);
+    settings.bind(|| assert_snapshot!(format!("step-{step}"), content));
+  }
+
+  compiler
+}
@@ -75,6 +75,10 @@ fn default_optimization_false_string_lit() -> String {
   "false".to_string()
 }
 
+fn default_performance_size_limit() -> f64 {
+  250000.0
+}
+
 fn true_by_default() -> bool {
   true
 }
@@ -105,6 +109,32 @@ pub struct TestConfig {
   pub devtool: String,
   #[serde(default)]
   pub experiments: Experiments,
+  #[serde(default)]
+  pub dev_server: DevServer,
+  #[serde(default)]
+  pub performance: Performance,
+  /// Relative to the fixture directory
+  #[serde(default)]
+  pub records_input_path: Option<String>,
+}
+
+#[derive(Debug, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DevServer {
+  #[serde(default)]
+  pub hot: bool,
+}
+
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Performance {
+  /// "false", "warning" or "error"
+  #[serde(default = "default_optimization_false_string_lit")]
+  pub hints: String,
+  #[serde(default = "default_performance_size_limit")]
+  pub max_asset_size: f64,
+  #[serde(default = "default_performance_size_limit")]
+  pub max_entrypoint_size: f64,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -113,6 +143,23 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub sync_web_assembly: bool,
+  #[serde(default)]
+  pub cache_unaffected: bool,
+  #[serde(default)]
+  pub inline_const: bool,
+  #[serde(default)]
+  pub incremental_rebuild: IncrementalRebuild,
+}
+
+#[derive(Debug, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct IncrementalRebuild {
+  #[serde(default)]
+  pub make: bool,
+  #[serde(default)]
+  pub emit_asset: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -241,6 +288,34 @@ pub struct Output {
   pub source_map_filename: String,
   #[serde(default)]
   pub library: Option<LibraryOptions>,
+  #[serde(default)]
+  pub chunk_load_retry: Option<ChunkLoadRetry>,
+  /// ES5 runtime code by default, so the snapshots don't depend on the target
+  #[serde(default)]
+  pub environment: Environment,
+}
+
+#[derive(Debug, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct Environment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+  pub destructuring: bool,
+  pub for_of: bool,
+  pub dynamic_import: bool,
+  pub module: bool,
+  pub optional_chaining: bool,
+  pub template_literal: bool,
+}
+
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChunkLoadRetry {
+  pub max_retries: u32,
+  #[serde(default)]
+  pub retry_delay: u32,
+  #[serde(default)]
+  pub fallback_public_paths: Vec<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -289,6 +364,7 @@ impl_serde_default!(Builtins);
 impl_serde_default!(EntryItem);
 impl_serde_default!(Module);
 impl_serde_default!(Optimization);
+impl_serde_default!(Performance);
 
 impl From<PresetEnv> for rspack_core::PresetEnv {
   fn from(preset_env: PresetEnv) -> Self {
@@ -314,7 +390,7 @@ impl From<ModuleRuleUse> for rspack_core::ModuleRuleUseLoader {
 }
 
 macro_rules! rule {
-  ($test:expr, $type:literal) => {
+  ($test:expr, $type:expr) => {
     rspack_core::ModuleRule {
       test: Some(rspack_core::RuleSetCondition::Regexp(
         RspackRegex::new($test).expect("should be valid regex"),
@@ -329,13 +405,20 @@ impl TestConfig {
   pub fn apply(self, context: PathBuf) -> (CompilerOptions, Vec<BoxPlugin>) {
     use rspack_core as c;
 
+    // Same as webpack, `asyncWebAssembly` takes precedence over `syncWebAssembly`
+    let wasm_module_type =
+      if !self.experiments.async_web_assembly && self.experiments.sync_web_assembly {
+        "webassembly/sync"
+      } else {
+        "webassembly/async"
+      };
     let mut rules = vec![
       rule!("\\.json$", "json"),
       rule!("\\.mjs$", "js/esm"),
       rule!("\\.cjs$", "js/dynamic"),
       rule!("\\.js$", "js/auto"),
       rule!("\\.css$", "css"),
-      rule!("\\.wasm$", "webassembly/async"),
+      rule!("\\.wasm$", wasm_module_type),
     ];
     rules.extend(self.module.rules.into_iter().map(|rule| c::ModuleRule {
       test: rule.test.map(|test| match test {
@@ -361,6 +444,17 @@ impl TestConfig {
         filename: c::Filename::from_str(&self.output.filename).expect("Should exist"),
         chunk_filename: c::Filename::from_str(&self.output.chunk_filename).expect("Should exist"),
         cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
+        charset: true,
+        chunk_load_timeout: 120000,
+        chunk_load_retry: self
+          .output
+          .chunk_load_retry
+          .map(|retry| c::ChunkLoadRetry {
+            max_retries: retry.max_retries,
+            retry_delay: retry.retry_delay,
+            fallback_public_paths: retry.fallback_public_paths,
+          })
+          .unwrap_or_default(),
         css_filename: c::Filename::from_str(&self.output.css_filename).expect("Should exist"),
         css_chunk_filename: c::Filename::from_str(&self.output.css_chunk_filename)
           .expect("Should exist"),
@@ -404,6 +498,16 @@ impl TestConfig {
         worker_wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
         worker_public_path: String::new(),
         script_type: String::from("false"),
+        environment: c::Environment {
+          arrow_function: self.output.environment.arrow_function,
+          r#const: self.output.environment.r#const,
+          destructuring: self.output.environment.destructuring,
+          for_of: self.output.environment.for_of,
+          dynamic_import: self.output.environment.dynamic_import,
+          module: self.output.environment.module,
+          optional_chaining: self.output.environment.optional_chaining,
+          template_literal: self.output.environment.template_literal,
+        },
       },
       mode: c::Mode::from(self.mode),
       target: c::Target::new(&self.target).expect("Can't construct target"),
@@ -434,13 +538,33 @@ impl TestConfig {
       stats: Default::default(),
       snapshot: Default::default(),
       cache: c::CacheOptions::Disabled,
-      experiments: Default::default(),
-      dev_server: Default::default(),
+      experiments: c::Experiments {
+        cache_unaffected: self.experiments.cache_unaffected,
+        inline_const: self.experiments.inline_const,
+        incremental_rebuild: c::IncrementalRebuild {
+          make: self
+            .experiments
+            .incremental_rebuild
+            .make
+            .then(c::IncrementalRebuildMakeState::default),
+          emit_asset: self.experiments.incremental_rebuild.emit_asset,
+        },
+        ..Default::default()
+      },
+      dev_server: c::DevServerOptions {
+        hot: self.dev_server.hot,
+      },
       node: Some(c::NodeOption {
         dirname: "mock".to_string(),
         filename: "mock".to_string(),
         global: "warn".to_string(),
       }),
+      performance: c::PerformanceOptions {
+        hints: c::PerformanceHints::from(self.performance.hints.as_str()),
+        max_asset_size: self.performance.max_asset_size,
+        max_entrypoint_size: self.performance.max_entrypoint_size,
+        asset_filter: None,
+      },
       optimization: c::Optimization {
         remove_available_modules: self.optimization.remove_available_modules,
         side_effects: c::SideEffectOption::from(self.optimization.side_effects.as_str()),
@@ -451,6 +575,10 @@ impl TestConfig {
         concatenate_modules: self.optimization.concatenate_modules,
       },
       profile: false,
+      records_input_path: self.records_input_path.map(|path| context.join(path)),
+      records_output_path: None,
+      ignore_warnings: Default::default(),
+      diagnostic_severity: Default::default(),
     };
     let mut plugins = Vec::new();
     for (name, desc) in &self.entry {
@@ -461,7 +589,12 @@ impl TestConfig {
             request.to_owned(),
             rspack_core::EntryOptions {
               name: Some(name.clone()),
-              runtime: Some("runtime".to_string()),
+              runtime: Some(
+                desc
+                  .runtime
+                  .clone()
+                  .unwrap_or_else(|| "runtime".to_string()),
+              ),
               chunk_loading: None,
               async_chunks: Some(true),
               public_path: None,
@@ -540,15 +673,18 @@ impl TestConfig {
       );
     }
 
-    if self.optimization.module_ids == "named" {
-      plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed());
+    match self.optimization.module_ids.as_str() {
+      "named" => plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed()),
+      "natural" => plugins.push(rspack_ids::NaturalModuleIdsPlugin::default().boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceModuleIdsPlugin::new(true).boxed()),
+      "hashed" => plugins.push(rspack_ids::HashedModuleIdsPlugin::default().boxed()),
+      _ => plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed()),
     }
-    if self.optimization.chunk_ids == "named" {
-      plugins.push(rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed());
+    match self.optimization.chunk_ids.as_str() {
+      "named" => plugins.push(rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed()),
+      "natural" => plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceChunkIdsPlugin::new(true).boxed()),
+      _ => plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed()),
     }
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
@@ -556,10 +692,17 @@ impl TestConfig {
     plugins.push(rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin.boxed());
 
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
+    if options.performance.hints.is_enable() {
+      plugins.push(rspack_plugin_size_limits::SizeLimitsPlugin.boxed());
+    }
     if self.experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
+    if self.experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::FetchCompileWasmPlugin {}.boxed());
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     plugins.push(rspack_plugin_externals::http_externals_rspack_plugin(
       true, false,
     ));
@@ -0,0 +1,18 @@
+import { RawAggressiveSplittingPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type AggressiveSplittingPluginOptions = {
+	minSize?: number;
+	maxSize?: number;
+	chunkOverhead?: number;
+	entryChunkMultiplicator?: number;
+};
+
+export const AggressiveSplittingPlugin = create(
+	BuiltinPluginName.AggressiveSplittingPlugin,
+	(
+		options: AggressiveSplittingPluginOptions = {}
+	): RawAggressiveSplittingPluginOptions => {
+		return options;
+	}
+);
@@ -0,0 +1,19 @@
+import { RawHashedModuleIdsPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type HashedModuleIdsPluginOptions = {
+	context?: string;
+	hashFunction?: "md4" | "xxhash64";
+	hashDigest?: "hex" | "base64";
+	hashDigestLength?: number;
+};
+
+export const HashedModuleIdsPlugin = create(
+	BuiltinPluginName.HashedModuleIdsPlugin,
+	(
+		options: HashedModuleIdsPluginOptions = {}
+	): RawHashedModuleIdsPluginOptions => {
+		return options;
+	},
+	"compilation"
+);
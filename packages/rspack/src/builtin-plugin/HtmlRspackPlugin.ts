@@ -14,6 +14,8 @@ const htmlRspackPluginOptions = z.strictObject({
 	chunks: z.string().array().optional(),
 	excludedChunks: z.string().array().optional(),
 	sri: z.enum(["sha256", "sha384", "sha512"]).optional(),
+	sriMode: z.enum(["initial", "all"]).optional(),
+	resourceHint: z.enum(["preload", "prefetch"]).optional(),
 	minify: z.boolean().optional(),
 	title: z.string().optional(),
 	favicon: z.string().optional(),
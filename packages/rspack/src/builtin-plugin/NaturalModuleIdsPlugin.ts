@@ -0,0 +1,7 @@
+import { BuiltinPluginName, create } from "./base";
+
+export const NaturalModuleIdsPlugin = create(
+	BuiltinPluginName.NaturalModuleIdsPlugin,
+	() => {},
+	"compilation"
+);
@@ -0,0 +1,14 @@
+import { RawOccurrenceIdsPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type OccurrenceChunkIdsPluginOptions = {
+	prioritiseInitial?: boolean;
+};
+
+export const OccurrenceChunkIdsPlugin = create(
+	BuiltinPluginName.OccurrenceChunkIdsPlugin,
+	(options: OccurrenceChunkIdsPluginOptions = {}): RawOccurrenceIdsPluginOptions => {
+		return options;
+	},
+	"compilation"
+);
@@ -0,0 +1,14 @@
+import { RawOccurrenceIdsPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type OccurrenceModuleIdsPluginOptions = {
+	prioritiseInitial?: boolean;
+};
+
+export const OccurrenceModuleIdsPlugin = create(
+	BuiltinPluginName.OccurrenceModuleIdsPlugin,
+	(options: OccurrenceModuleIdsPluginOptions = {}): RawOccurrenceIdsPluginOptions => {
+		return options;
+	},
+	"compilation"
+);
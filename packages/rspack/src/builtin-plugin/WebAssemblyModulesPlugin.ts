@@ -0,0 +1,7 @@
+import { BuiltinPluginName, create } from "./base";
+
+export const WebAssemblyModulesPlugin = create(
+	BuiltinPluginName.WebAssemblyModulesPlugin,
+	() => {},
+	"compilation"
+);
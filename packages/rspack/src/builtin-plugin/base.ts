@@ -25,6 +25,7 @@ export enum BuiltinPluginName {
 	SwcJsMinimizerRspackPlugin = "SwcJsMinimizerRspackPlugin",
 	SwcCssMinimizerRspackPlugin = "SwcCssMinimizerRspackPlugin",
 	LimitChunkCountPlugin = "LimitChunkCountPlugin",
+	AggressiveSplittingPlugin = "AggressiveSplittingPlugin",
 	WorkerPlugin = "WorkerPlugin",
 	WebWorkerTemplatePlugin = "WebWorkerTemplatePlugin",
 	MergeDuplicateChunksPlugin = "MergeDuplicateChunksPlugin",
@@ -38,6 +39,11 @@ export enum BuiltinPluginName {
 	DeterministicModuleIdsPlugin = "DeterministicModuleIdsPlugin",
 	NamedChunkIdsPlugin = "NamedChunkIdsPlugin",
 	DeterministicChunkIdsPlugin = "DeterministicChunkIdsPlugin",
+	NaturalModuleIdsPlugin = "NaturalModuleIdsPlugin",
+	NaturalChunkIdsPlugin = "NaturalChunkIdsPlugin",
+	OccurrenceModuleIdsPlugin = "OccurrenceModuleIdsPlugin",
+	OccurrenceChunkIdsPlugin = "OccurrenceChunkIdsPlugin",
+	HashedModuleIdsPlugin = "HashedModuleIdsPlugin",
 	RealContentHashPlugin = "RealContentHashPlugin",
 	RemoveEmptyChunksPlugin = "RemoveEmptyChunksPlugin",
 	EnsureChunkConditionsPlugin = "EnsureChunkConditionsPlugin",
@@ -49,6 +55,7 @@ export enum BuiltinPluginName {
 	InferAsyncModulesPlugin = "InferAsyncModulesPlugin",
 	JavascriptModulesPlugin = "JavascriptModulesPlugin",
 	AsyncWebAssemblyModulesPlugin = "AsyncWebAssemblyModulesPlugin",
+	WebAssemblyModulesPlugin = "WebAssemblyModulesPlugin",
 	AssetModulesPlugin = "AssetModulesPlugin",
 	SourceMapDevToolPlugin = "SourceMapDevToolPlugin",
 	EvalSourceMapDevToolPlugin = "EvalSourceMapDevToolPlugin",
@@ -20,12 +20,18 @@ export * from "./HotModuleReplacementPlugin";
 export * from "./WebWorkerTemplatePlugin";
 export * from "./WorkerPlugin";
 export * from "./LimitChunkCountPlugin";
+export * from "./AggressiveSplittingPlugin";
 export * from "./MergeDuplicateChunksPlugin";
 export * from "./SplitChunksPlugin";
 export * from "./NamedModuleIdsPlugin";
 export * from "./DeterministicModuleIdsPlugin";
 export * from "./NamedChunkIdsPlugin";
 export * from "./DeterministicChunkIdsPlugin";
+export * from "./NaturalModuleIdsPlugin";
+export * from "./NaturalChunkIdsPlugin";
+export * from "./OccurrenceModuleIdsPlugin";
+export * from "./OccurrenceChunkIdsPlugin";
+export * from "./HashedModuleIdsPlugin";
 export * from "./RealContentHashPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./EnsureChunkConditionsPlugin";
@@ -37,6 +43,7 @@ export * from "./JsonModulesPlugin";
 export * from "./InferAsyncModulesPlugin";
 export * from "./JavascriptModulesPlugin";
 export * from "./AsyncWebAssemblyModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./AssetModulesPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./EvalSourceMapDevToolPlugin";
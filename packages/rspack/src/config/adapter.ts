@@ -54,7 +54,9 @@ import {
 	JavascriptParserOptions,
 	LibraryName,
 	EntryRuntime,
-	ChunkLoading
+	ChunkLoading,
+	Environment,
+	Performance
 } from "./zod";
 import {
 	ExperimentsNormalized,
@@ -92,6 +94,7 @@ export const getRawOptions = (
 		}),
 		devtool,
 		optimization: getRawOptimization(options.optimization),
+		performance: getRawPerformance(options.performance),
 		stats: getRawStats(options.stats),
 		snapshot: getRawSnapshotOptions(options.snapshot),
 		cache: {
@@ -112,6 +115,8 @@ export const getRawOptions = (
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// TODO: remove this
 		builtins: options.builtins as any
 	};
@@ -172,6 +177,21 @@ function getRawResolve(resolve: Resolve): RawOptions["resolve"] {
 	};
 }
 
+function getRawEnvironment(
+	environment: Environment
+): RawOptions["output"]["environment"] {
+	return {
+		arrowFunction: !!environment.arrowFunction,
+		const: !!environment.const,
+		destructuring: !!environment.destructuring,
+		dynamicImport: !!environment.dynamicImport,
+		forOf: !!environment.forOf,
+		module: !!environment.module,
+		optionalChaining: !!environment.optionalChaining,
+		templateLiteral: !!environment.templateLiteral
+	};
+}
+
 function getRawCrossOriginLoading(
 	crossOriginLoading: CrossOriginLoading
 ): RawOptions["output"]["crossOriginLoading"] {
@@ -195,6 +215,13 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		chunkFilename: output.chunkFilename!,
 		chunkLoading: getRawChunkLoading(chunkLoading),
 		crossOriginLoading: getRawCrossOriginLoading(output.crossOriginLoading!),
+		charset: output.charset!,
+		chunkLoadTimeout: output.chunkLoadTimeout!,
+		chunkLoadRetry: output.chunkLoadRetry && {
+			maxRetries: output.chunkLoadRetry.maxRetries,
+			retryDelay: output.chunkLoadRetry.retryDelay ?? 0,
+			fallbackPublicPaths: output.chunkLoadRetry.fallbackPublicPaths ?? []
+		},
 		cssFilename: output.cssFilename!,
 		cssChunkFilename: output.cssChunkFilename!,
 		hotUpdateChunkFilename: output.hotUpdateChunkFilename!,
@@ -225,7 +252,8 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		workerWasmLoading:
 			workerWasmLoading === false ? "false" : workerWasmLoading,
 		workerPublicPath: output.workerPublicPath!,
-		scriptType: output.scriptType === false ? "false" : output.scriptType!
+		scriptType: output.scriptType === false ? "false" : output.scriptType!,
+		environment: getRawEnvironment(output.environment!)
 	};
 }
 
@@ -700,6 +728,22 @@ function getRawOptimization(
 	};
 }
 
+function getRawPerformance(
+	performance: Performance | undefined
+): RawOptions["performance"] {
+	if (!performance || !performance.hints) {
+		return undefined;
+	}
+	return {
+		hints: performance.hints,
+		maxAssetSize: performance.maxAssetSize!,
+		maxEntrypointSize: performance.maxEntrypointSize!,
+		assetFilter: performance.assetFilter
+			? getRawRuleSetCondition(performance.assetFilter)
+			: undefined
+	};
+}
+
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
@@ -728,14 +772,26 @@ function getRawSnapshotOptions(
 function getRawExperiments(
 	experiments: ExperimentsNormalized
 ): RawOptions["experiments"] {
-	const { newSplitChunks, topLevelAwait, rspackFuture } = experiments;
+	const {
+		newSplitChunks,
+		topLevelAwait,
+		cacheUnaffected,
+		inlineConst,
+		rspackFuture
+	} = experiments;
 	assert(
-		!isNil(newSplitChunks) && !isNil(topLevelAwait) && !isNil(rspackFuture)
+		!isNil(newSplitChunks) &&
+			!isNil(topLevelAwait) &&
+			!isNil(cacheUnaffected) &&
+			!isNil(inlineConst) &&
+			!isNil(rspackFuture)
 	);
 
 	return {
 		newSplitChunks,
 		topLevelAwait,
+		cacheUnaffected,
+		inlineConst,
 		rspackFuture: getRawRspackFutureOptions(rspackFuture)
 	};
 }
@@ -766,7 +822,8 @@ function getRawNode(node: Node): RawOptions["node"] {
 function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
-		colors: statsOptions.colors ?? false
+		colors: statsOptions.colors ?? false,
+		diagnosticsFormat: statsOptions.diagnosticsFormat
 	};
 }
 
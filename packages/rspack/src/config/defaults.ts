@@ -29,9 +29,11 @@ import type {
 	ModuleOptions,
 	Node,
 	Optimization,
+	Performance,
 	ResolveOptions,
 	RuleSetRules,
-	SnapshotOptions
+	SnapshotOptions,
+	StatsValue
 } from "./zod";
 import {
 	EntryDescriptionNormalized,
@@ -77,6 +79,8 @@ export const applyRspackOptionsDefaults = (
 	D(options, "watch", false);
 	D(options, "profile", false);
 	D(options, "bail", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
@@ -88,7 +92,7 @@ export const applyRspackOptionsDefaults = (
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
-		// syncWebAssembly: options.experiments.syncWebAssembly,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
 		css: options.experiments.css!
 	});
@@ -123,6 +127,17 @@ export const applyRspackOptionsDefaults = (
 
 	applyOptimizationDefaults(options.optimization, { production, development });
 
+	F(options, "performance", () =>
+		production &&
+		targetProperties &&
+		(targetProperties.browser || targetProperties.browser === null)
+			? {}
+			: false
+	);
+	applyPerformanceDefaults(options.performance!, { production });
+
+	applyStatsDefaults(options.stats);
+
 	options.resolve = cleverMerge(
 		getResolveDefaults({
 			targetProperties,
@@ -162,9 +177,12 @@ const applyExperimentsDefaults = (
 ) => {
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
+	D(experiments, "cacheUnaffected", false);
+	D(experiments, "inlineConst", false);
 
 	D(experiments, "rspackFuture", {});
 	if (typeof experiments.rspackFuture === "object") {
@@ -207,9 +225,11 @@ const applyJavascriptParserOptionsDefaults = (
 const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
+		syncWebAssembly,
 		asyncWebAssembly,
 		css
 	}: {
+		syncWebAssembly: boolean;
 		asyncWebAssembly: boolean;
 		css: boolean;
 	}
@@ -307,6 +327,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -586,12 +628,33 @@ const applyOutputDefaults = (
 	D(output, "importFunctionName", "import");
 	F(output, "clean", () => !!output.clean);
 	D(output, "crossOriginLoading", false);
+	D(output, "charset", true);
+	D(output, "chunkLoadTimeout", 120000);
 	D(output, "workerPublicPath", "");
 	F(output, "sourceMapFilename", () => {
 		return "[file].map";
 	});
 	F(output, "scriptType", () => (output.module ? "module" : false));
 
+	// Only the syntax the targets are known to support is used in the runtime
+	// code, which falls back to ES5 when the targets don't tell
+	const environment = output.environment!;
+	const supports = (v: boolean | null | undefined) => !!(tp && v);
+	const conditionallySupports = (v: boolean | null | undefined, c?: boolean) =>
+		v === undefined || v === null ? !!c : v;
+	F(environment, "arrowFunction", () => supports(tp.arrowFunction));
+	F(environment, "const", () => supports(tp.const));
+	F(environment, "destructuring", () => supports(tp.destructuring));
+	F(environment, "forOf", () => supports(tp.forOf));
+	F(environment, "optionalChaining", () => supports(tp.optionalChaining));
+	F(environment, "templateLiteral", () => supports(tp.templateLiteral));
+	F(environment, "dynamicImport", () =>
+		conditionallySupports(tp && tp.dynamicImport, output.module)
+	);
+	F(environment, "module", () =>
+		conditionallySupports(tp && tp.module, output.module)
+	);
+
 	const { trustedTypes } = output;
 	if (trustedTypes) {
 		F(
@@ -705,6 +768,22 @@ const applyNodeDefaults = (
 	});
 };
 
+const applyPerformanceDefaults = (
+	performance: Performance,
+	{ production }: { production: boolean }
+) => {
+	if (performance === false) return;
+	D(performance, "maxAssetSize", 250000);
+	D(performance, "maxEntrypointSize", 250000);
+	F(performance, "hints", () => (production ? "warning" : false));
+};
+
+const applyStatsDefaults = (stats: StatsValue) => {
+	// presets are normalized to objects
+	if (typeof stats !== "object") return;
+	D(stats, "diagnosticsFormat", "graphical");
+};
+
 const applyOptimizationDefaults = (
 	optimization: Optimization,
 	{ production, development }: { production: boolean; development: boolean }
@@ -719,7 +798,7 @@ const applyOptimizationDefaults = (
 	F(optimization, "chunkIds", (): "named" | "deterministic" => {
 		if (production) return "deterministic";
 		if (development) return "named";
-		return "named"; // we have not implemented 'natural' so use 'named' now
+		return "named"; // TODO: use 'natural' like webpack, needs the snapshots to be updated
 	});
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "mangleExports", production);
@@ -749,8 +828,8 @@ const applyOptimizationDefaults = (
 		// D(splitChunks, "usedExports", optimization.usedExports === true);
 		D(splitChunks, "minChunks", 1);
 		F(splitChunks, "minSize", () => (production ? 20000 : 10000));
-		// F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
-		// F(splitChunks, "enforceSizeThreshold", () => (production ? 50000 : 30000));
+		F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
+		F(splitChunks, "enforceSizeThreshold", () => (production ? 50000 : 30000));
 		F(splitChunks, "maxAsyncRequests", () => (production ? 30 : Infinity));
 		F(splitChunks, "maxInitialRequests", () => (production ? 30 : Infinity));
 		D(splitChunks, "automaticNameDelimiter", "-");
@@ -37,6 +37,8 @@ import type {
 	DevServer,
 	Profile,
 	Bail,
+	Performance,
+	RecordsPath,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
@@ -47,6 +49,9 @@ import type {
 	Filename,
 	ChunkFilename,
 	CrossOriginLoading,
+	Charset,
+	ChunkLoadTimeout,
+	ChunkLoadRetry,
 	CssFilename,
 	CssChunkFilename,
 	HotUpdateMainFilename,
@@ -76,6 +81,7 @@ import type {
 	RspackFutureOptions,
 	HotUpdateGlobal,
 	ScriptType,
+	Environment,
 	DevtoolNamespace,
 	DevtoolModuleFilenameTemplate,
 	DevtoolFallbackModuleFilenameTemplate
@@ -128,6 +134,9 @@ export const getNormalizedRspackOptions = (
 				chunkLoading: output.chunkLoading,
 				chunkFilename: output.chunkFilename,
 				crossOriginLoading: output.crossOriginLoading,
+				charset: output.charset,
+				chunkLoadTimeout: output.chunkLoadTimeout,
+				chunkLoadRetry: output.chunkLoadRetry,
 				cssFilename: output.cssFilename,
 				cssChunkFilename: output.cssChunkFilename,
 				hotUpdateMainFilename: output.hotUpdateMainFilename,
@@ -196,6 +205,7 @@ export const getNormalizedRspackOptions = (
 				workerWasmLoading: output.workerWasmLoading,
 				workerPublicPath: output.workerPublicPath,
 				scriptType: output.scriptType,
+				environment: { ...output.environment },
 				devtoolNamespace: output.devtoolNamespace,
 				devtoolModuleFilenameTemplate: output.devtoolModuleFilenameTemplate,
 				devtoolFallbackModuleFilenameTemplate:
@@ -282,6 +292,10 @@ export const getNormalizedRspackOptions = (
 				)
 			};
 		}),
+		performance: optionalNestedConfig(config.performance, performance => {
+			if (performance === false) return false;
+			return { ...performance };
+		}),
 		plugins: nestedArray(config.plugins, p => [...p]),
 		experiments: nestedConfig(config.experiments, experiments => ({
 			...experiments
@@ -291,6 +305,14 @@ export const getNormalizedRspackOptions = (
 		devServer: config.devServer,
 		profile: config.profile,
 		bail: config.bail,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
 		}))
@@ -428,6 +450,9 @@ export interface OutputNormalized {
 	filename?: Filename;
 	chunkFilename?: ChunkFilename;
 	crossOriginLoading?: CrossOriginLoading;
+	charset?: Charset;
+	chunkLoadTimeout?: ChunkLoadTimeout;
+	chunkLoadRetry?: ChunkLoadRetry;
 	cssFilename?: CssFilename;
 	cssChunkFilename?: CssChunkFilename;
 	hotUpdateMainFilename?: HotUpdateMainFilename;
@@ -460,6 +485,7 @@ export interface OutputNormalized {
 	workerWasmLoading?: WasmLoading;
 	workerPublicPath?: WorkerPublicPath;
 	scriptType?: ScriptType;
+	environment?: Environment;
 	devtoolNamespace?: DevtoolNamespace;
 	devtoolModuleFilenameTemplate?: DevtoolModuleFilenameTemplate;
 	devtoolFallbackModuleFilenameTemplate?: DevtoolFallbackModuleFilenameTemplate;
@@ -475,11 +501,14 @@ export interface ModuleOptionsNormalized {
 export interface ExperimentsNormalized {
 	lazyCompilation?: boolean;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
 	futureDefaults?: boolean;
+	cacheUnaffected?: boolean;
+	inlineConst?: boolean;
 	rspackFuture?: RspackFutureOptions;
 }
 
@@ -515,6 +544,7 @@ export interface RspackOptionsNormalized {
 	cache?: CacheOptions;
 	stats: StatsValue;
 	optimization: Optimization;
+	performance?: Performance;
 	plugins: Plugins;
 	experiments: ExperimentsNormalized;
 	watch?: Watch;
@@ -523,5 +553,7 @@ export interface RspackOptionsNormalized {
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	profile?: Profile;
 	bail?: Bail;
+	recordsInputPath?: RecordsPath;
+	recordsOutputPath?: RecordsPath;
 	builtins: Builtins;
 }
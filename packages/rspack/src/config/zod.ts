@@ -71,6 +71,18 @@ export type WasmLoading = z.infer<typeof wasmLoading>;
 const scriptType = z.enum(["text/javascript", "module"]).or(z.literal(false));
 export type ScriptType = z.infer<typeof scriptType>;
 
+const environment = z.strictObject({
+	arrowFunction: z.boolean().optional(),
+	const: z.boolean().optional(),
+	destructuring: z.boolean().optional(),
+	dynamicImport: z.boolean().optional(),
+	forOf: z.boolean().optional(),
+	module: z.boolean().optional(),
+	optionalChaining: z.boolean().optional(),
+	templateLiteral: z.boolean().optional()
+});
+export type Environment = z.infer<typeof environment>;
+
 const libraryCustomUmdObject = z.strictObject({
 	amd: z.string().optional(),
 	commonjs: z.string().optional(),
@@ -204,6 +216,19 @@ const crossOriginLoading = z
 	.or(z.enum(["anonymous", "use-credentials"]));
 export type CrossOriginLoading = z.infer<typeof crossOriginLoading>;
 
+const charset = z.boolean();
+export type Charset = z.infer<typeof charset>;
+
+const chunkLoadTimeout = z.number();
+export type ChunkLoadTimeout = z.infer<typeof chunkLoadTimeout>;
+
+const chunkLoadRetry = z.strictObject({
+	maxRetries: z.number(),
+	retryDelay: z.number().optional(),
+	fallbackPublicPaths: z.array(z.string()).optional()
+});
+export type ChunkLoadRetry = z.infer<typeof chunkLoadRetry>;
+
 const cssFilename = filenameTemplate;
 export type CssFilename = z.infer<typeof cssFilename>;
 
@@ -308,6 +333,9 @@ const output = z.strictObject({
 	filename: filename.optional(),
 	chunkFilename: chunkFilename.optional(),
 	crossOriginLoading: crossOriginLoading.optional(),
+	charset: charset.optional(),
+	chunkLoadTimeout: chunkLoadTimeout.optional(),
+	chunkLoadRetry: chunkLoadRetry.optional(),
 	cssFilename: cssFilename.optional(),
 	cssChunkFilename: cssChunkFilename.optional(),
 	hotUpdateMainFilename: hotUpdateMainFilename.optional(),
@@ -346,6 +374,7 @@ const output = z.strictObject({
 	workerWasmLoading: wasmLoading.optional(),
 	workerPublicPath: workerPublicPath.optional(),
 	scriptType: scriptType.optional(),
+	environment: environment.optional(),
 	devtoolNamespace: devtoolNamespace.optional(),
 	devtoolModuleFilenameTemplate: devtoolModuleFilenameTemplate.optional(),
 	devtoolFallbackModuleFilenameTemplate:
@@ -902,6 +931,7 @@ const statsOptions = z.strictObject({
 	errors: z.boolean().optional(),
 	errorsCount: z.boolean().optional(),
 	colors: z.boolean().optional(),
+	diagnosticsFormat: z.enum(["graphical", "json", "sarif"]).optional(),
 	hash: z.boolean().optional(),
 	version: z.boolean().optional(),
 	reasons: z.boolean().optional(),
@@ -988,6 +1018,9 @@ const sharedOptimizationSplitChunksCacheGroup = {
 	minChunks: z.number().optional(),
 	name: optimizationSplitChunksName.optional(),
 	minSize: optimizationSplitChunksSizes.optional(),
+	minSizeReduction: optimizationSplitChunksSizes.optional(),
+	minRemainingSize: optimizationSplitChunksSizes.optional(),
+	enforceSizeThreshold: optimizationSplitChunksSizes.optional(),
 	maxSize: optimizationSplitChunksSizes.optional(),
 	maxAsyncSize: optimizationSplitChunksSizes.optional(),
 	maxInitialSize: optimizationSplitChunksSizes.optional(),
@@ -1039,8 +1072,10 @@ export type OptimizationSplitChunksOptions = z.infer<
 >;
 
 const optimization = z.strictObject({
-	moduleIds: z.enum(["named", "deterministic"]).optional(),
-	chunkIds: z.enum(["named", "deterministic"]).optional(),
+	moduleIds: z
+		.enum(["natural", "named", "deterministic", "size", "hashed"])
+		.optional(),
+	chunkIds: z.enum(["natural", "named", "deterministic", "size"]).optional(),
 	minimize: z.boolean().optional(),
 	minimizer: z.literal("...").or(plugin).array().optional(),
 	mergeDuplicateChunks: z.boolean().optional(),
@@ -1079,6 +1114,7 @@ export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional(),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	newSplitChunks: z
@@ -1100,6 +1136,8 @@ const experiments = z.strictObject({
 		}),
 	css: z.boolean().optional(),
 	futureDefaults: z.boolean().optional(),
+	cacheUnaffected: z.boolean().optional(),
+	inlineConst: z.boolean().optional(),
 	rspackFuture: rspackFutureOptions.optional()
 });
 export type Experiments = z.infer<typeof experiments>;
@@ -1154,6 +1192,23 @@ const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
 //#endregion
 
+//#region Performance
+const performance = z
+	.strictObject({
+		assetFilter: z.function().args(z.string()).returns(z.boolean()).optional(),
+		hints: z.enum(["error", "warning"]).or(z.literal(false)).optional(),
+		maxAssetSize: z.number().optional(),
+		maxEntrypointSize: z.number().optional()
+	})
+	.or(z.literal(false));
+export type Performance = z.infer<typeof performance>;
+//#endregion
+
+//#region RecordsPath
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+//#endregion
+
 //#region Builtins (deprecated)
 const builtins = z.custom<oldBuiltins.Builtins>();
 export type Builtins = z.infer<typeof builtins>;
@@ -1181,6 +1236,7 @@ export const rspackOptions = z.strictObject({
 	stats: statsValue.optional(),
 	snapshot: snapshotOptions.optional(),
 	optimization: optimization.optional(),
+	performance: performance.optional(),
 	resolve: resolve.optional(),
 	resolveLoader: resolve.optional(),
 	plugins: plugins.optional(),
@@ -1188,7 +1244,10 @@ export const rspackOptions = z.strictObject({
 	builtins: builtins.optional(),
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
-	bail: bail.optional()
+	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsPath.optional(),
+	recordsOutputPath: recordsPath.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
 export type Configuration = RspackOptions;
@@ -119,8 +119,35 @@ export const javascript = { EnableChunkLoadingPlugin };
 import { WebWorkerTemplatePlugin } from "./builtin-plugin";
 export const webworker = { WebWorkerTemplatePlugin };
 
-import { LimitChunkCountPlugin } from "./builtin-plugin";
-export const optimize = { LimitChunkCountPlugin };
+import {
+	LimitChunkCountPlugin,
+	AggressiveSplittingPlugin
+} from "./builtin-plugin";
+export const optimize = { LimitChunkCountPlugin, AggressiveSplittingPlugin };
+
+import {
+	NaturalModuleIdsPlugin,
+	NamedModuleIdsPlugin,
+	DeterministicModuleIdsPlugin,
+	OccurrenceModuleIdsPlugin,
+	HashedModuleIdsPlugin,
+	NaturalChunkIdsPlugin,
+	NamedChunkIdsPlugin,
+	DeterministicChunkIdsPlugin,
+	OccurrenceChunkIdsPlugin
+} from "./builtin-plugin";
+export type { HashedModuleIdsPluginOptions } from "./builtin-plugin";
+export const ids = {
+	NaturalModuleIdsPlugin,
+	NamedModuleIdsPlugin,
+	DeterministicModuleIdsPlugin,
+	OccurrenceModuleIdsPlugin,
+	HashedModuleIdsPlugin,
+	NaturalChunkIdsPlugin,
+	NamedChunkIdsPlugin,
+	DeterministicChunkIdsPlugin,
+	OccurrenceChunkIdsPlugin
+};
 
 import { ModuleFederationPlugin } from "./container/ModuleFederationPlugin";
 export type { ModuleFederationPluginOptions } from "./container/ModuleFederationPlugin";
@@ -40,6 +40,11 @@ import {
 	DeterministicModuleIdsPlugin,
 	NamedChunkIdsPlugin,
 	DeterministicChunkIdsPlugin,
+	NaturalModuleIdsPlugin,
+	NaturalChunkIdsPlugin,
+	OccurrenceModuleIdsPlugin,
+	OccurrenceChunkIdsPlugin,
+	HashedModuleIdsPlugin,
 	RealContentHashPlugin,
 	RemoveEmptyChunksPlugin,
 	EnsureChunkConditionsPlugin,
@@ -49,6 +54,7 @@ import {
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	AsyncWebAssemblyModulesPlugin,
+	WebAssemblyModulesPlugin,
 	RuntimePlugin,
 	InferAsyncModulesPlugin,
 	WorkerPlugin,
@@ -228,6 +234,9 @@ export class RspackOptionsApply {
 		new JavascriptModulesPlugin().apply(compiler);
 		new JsonModulesPlugin().apply(compiler);
 		new AssetModulesPlugin().apply(compiler);
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
@@ -292,6 +301,10 @@ export class RspackOptionsApply {
 		const moduleIds = options.optimization.moduleIds;
 		if (moduleIds) {
 			switch (moduleIds) {
+				case "natural": {
+					new NaturalModuleIdsPlugin().apply(compiler);
+					break;
+				}
 				case "named": {
 					new NamedModuleIdsPlugin().apply(compiler);
 					break;
@@ -300,6 +313,16 @@ export class RspackOptionsApply {
 					new DeterministicModuleIdsPlugin().apply(compiler);
 					break;
 				}
+				case "size": {
+					new OccurrenceModuleIdsPlugin({
+						prioritiseInitial: true
+					}).apply(compiler);
+					break;
+				}
+				case "hashed": {
+					new HashedModuleIdsPlugin().apply(compiler);
+					break;
+				}
 				default:
 					throw new Error(`moduleIds: ${moduleIds} is not implemented`);
 			}
@@ -307,6 +330,10 @@ export class RspackOptionsApply {
 		const chunkIds = options.optimization.chunkIds;
 		if (chunkIds) {
 			switch (chunkIds) {
+				case "natural": {
+					new NaturalChunkIdsPlugin().apply(compiler);
+					break;
+				}
 				case "named": {
 					new NamedChunkIdsPlugin().apply(compiler);
 					break;
@@ -315,6 +342,12 @@ export class RspackOptionsApply {
 					new DeterministicChunkIdsPlugin().apply(compiler);
 					break;
 				}
+				case "size": {
+					new OccurrenceChunkIdsPlugin({
+						prioritiseInitial: true
+					}).apply(compiler);
+					break;
+				}
 				default:
 					throw new Error(`chunkIds: ${chunkIds} is not implemented`);
 			}
@@ -132,9 +132,11 @@ describe("snapshots", () => {
 		-     "sideEffects": "flag",
 		+     "sideEffects": true,
 		@@ ... @@
+		-       "enforceSizeThreshold": 30000,
 		-       "hidePathInfo": false,
 		-       "maxAsyncRequests": Infinity,
 		-       "maxInitialRequests": Infinity,
+		+       "enforceSizeThreshold": 50000,
 		+       "hidePathInfo": true,
 		+       "maxAsyncRequests": 30,
 		+       "maxInitialRequests": 30,
@@ -145,6 +147,13 @@ describe("snapshots", () => {
 		-     "usedExports": false,
 		+     "usedExports": true,
 		@@ ... @@
+		-   "performance": false,
+		+   "performance": Object {
+		+     "hints": "warning",
+		+     "maxAssetSize": 250000,
+		+     "maxEntrypointSize": 250000,
+		+   },
+		@@ ... @@
 		-       "hash": false,
 		+       "hash": true,
 		@@ ... @@
@@ -183,9 +192,11 @@ describe("snapshots", () => {
 		-     "sideEffects": "flag",
 		+     "sideEffects": true,
 		@@ ... @@
+		-       "enforceSizeThreshold": 30000,
 		-       "hidePathInfo": false,
 		-       "maxAsyncRequests": Infinity,
 		-       "maxInitialRequests": Infinity,
+		+       "enforceSizeThreshold": 50000,
 		+       "hidePathInfo": true,
 		+       "maxAsyncRequests": 30,
 		+       "maxInitialRequests": 30,
@@ -196,6 +207,13 @@ describe("snapshots", () => {
 		-     "usedExports": false,
 		+     "usedExports": true,
 		@@ ... @@
+		-   "performance": false,
+		+   "performance": Object {
+		+     "hints": "warning",
+		+     "maxAssetSize": 250000,
+		+     "maxEntrypointSize": 250000,
+		+   },
+		@@ ... @@
 		-       "hash": false,
 		+       "hash": true,
 		@@ ... @@
@@ -218,6 +236,9 @@ describe("snapshots", () => {
 		-     "nodeEnv": false,
 		+     "nodeEnv": "development",
 		@@ ... @@
+		-       "minRemainingSize": undefined,
+		+       "minRemainingSize": 0,
+		@@ ... @@
 		-       "production",
 		+       "development",
 	`)
@@ -251,6 +272,12 @@ describe("snapshots", () => {
 		-     "chunkFilename": "[name].js",
 		+     "chunkFilename": "[name].mjs",
 		@@ ... @@
+		-       "dynamicImport": false,
+		+       "dynamicImport": true,
+		@@ ... @@
+		-       "module": false,
+		+       "module": true,
+		@@ ... @@
 		-     "filename": "[name].js",
 		+     "filename": "[name].mjs",
 		@@ ... @@
@@ -925,7 +952,16 @@ describe("snapshots", () => {
 	`)
 	);
 	test("records", { recordsPath: "some-path" }, e =>
-		e.toMatchInlineSnapshot(`Compared values have no visual difference.`)
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-   "recordsInputPath": false,
+		-   "recordsOutputPath": false,
+		+   "recordsInputPath": "some-path",
+		+   "recordsOutputPath": "some-path",
+	`)
 	);
 	test("ecmaVersion", { output: { ecmaVersion: 2020 } }, e =>
 		e.toMatchInlineSnapshot(`Compared values have no visual difference.`)
@@ -1082,10 +1118,7 @@ describe("snapshots", () => {
 		+ Received
 
 		@@ ... @@
-		-   "stats": Object {},
-		+   "stats": Object {
 		+     "preset": "normal",
-		+   },
 	`)
 	);
 
@@ -1095,10 +1128,7 @@ describe("snapshots", () => {
 		+ Received
 
 		@@ ... @@
-		-   "stats": Object {},
-		+   "stats": Object {
 		+     "preset": "none",
-		+   },
 	`)
 	);
 
@@ -1108,10 +1138,7 @@ describe("snapshots", () => {
 		+ Received
 
 		@@ ... @@
-		-   "stats": Object {},
-		+   "stats": Object {
 		+     "preset": "minimal",
-		+   },
 	`)
 	);
 
@@ -61,10 +61,10 @@ describe("Stats", () => {
 		"PublicPath: auto
 		asset main.js 739 bytes [emitted] (name: main)
 		Entrypoint main 739 bytes = main.js
+		./fixtures/abc.js
 		./fixtures/a.js
 		./fixtures/b.js
 		./fixtures/c.js
-		./fixtures/abc.js
 
 		ERROR in ./fixtures/b.js
 		  × Module parse failed:
@@ -208,13 +208,13 @@ describe("Stats", () => {
 		expect(
 			stats?.toString({ all: false, modules: true }).replace(/\d+ ms/g, "X ms")
 		).toMatchInlineSnapshot(`
-		"./fixtures/a.js
+		"./fixtures/abc.js
+		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		./fixtures/a.js
 		  X ms (resolving: X ms, integration: X ms, building: X ms)
 		./fixtures/b.js
 		  X ms (resolving: X ms, integration: X ms, building: X ms)
 		./fixtures/c.js
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
-		./fixtures/abc.js
 		  X ms (resolving: X ms, integration: X ms, building: X ms)"
 	`);
 	});
@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should load the split chunks of a template reference before its entry chunk", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	const libIndex = htmlContent.search("lib.js");
+	const pageIndex = htmlContent.search("index.html~page.js");
+	expect(libIndex).toBeGreaterThan(-1);
+	expect(pageIndex).toBeGreaterThan(-1);
+	expect(libIndex < pageIndex).toBe(true);
+});
@@ -0,0 +1,28 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				lib: {
+					test: /lib\.js/,
+					name: "lib",
+					chunks: "all",
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			template: "./index.html"
+		})
+	]
+};
@@ -0,0 +1,18 @@
+it("should retry a failed chunk with the fallback public path", function () {
+	const promise = import("./chunk" /* webpackChunkName: "retried" */);
+
+	var script = document.head._children.pop();
+	expect(script.src).toBe("https://test.cases/path/retried.web.js");
+	script.onerror({ type: "error", target: script });
+
+	return new Promise(resolve => setTimeout(resolve, 10))
+		.then(() => {
+			var retried = document.head._children.pop();
+			expect(retried.src).toBe("https://cdn.test.cases/path/retried.web.js");
+			__non_webpack_require__("./retried.web.js");
+			return promise;
+		})
+		.then(module => {
+			expect(module.default).toBe("retried");
+		});
+});
@@ -0,0 +1,15 @@
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://test.cases/path/",
+		chunkFilename: "retried.web.js",
+		chunkLoadRetry: {
+			maxRetries: 1,
+			retryDelay: 0,
+			fallbackPublicPaths: ["https://cdn.test.cases/path/"]
+		}
+	},
+	optimization: {
+		minimize: false
+	}
+};
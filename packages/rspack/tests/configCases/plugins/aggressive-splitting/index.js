@@ -0,0 +1,10 @@
+import fs from "fs";
+
+it("should split the big chunk into smaller parts", () =>
+	import("./parts").then(({ default: parts }) => {
+		expect(parts).toHaveLength(4);
+		const chunks = fs
+			.readdirSync(__dirname)
+			.filter(file => file.endsWith(".chunk.js"));
+		expect(chunks.length).toBeGreaterThan(1);
+	}));
@@ -0,0 +1,6 @@
+import a from "./part-a";
+import b from "./part-b";
+import c from "./part-c";
+import d from "./part-d";
+
+export default [a, b, c, d];
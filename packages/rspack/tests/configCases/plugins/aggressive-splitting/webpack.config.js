@@ -0,0 +1,18 @@
+const { optimize } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[id].chunk.js"
+	},
+	plugins: [
+		new optimize.AggressiveSplittingPlugin({
+			minSize: 150,
+			maxSize: 250
+		})
+	]
+};
@@ -0,0 +1,10 @@
+import fs from "fs";
+
+it("should split the group into parts fitting both minSize and maxSize", () =>
+	import(/* webpackChunkName: "parts" */ "./parts").then(({ default: parts }) => {
+		expect(parts).toHaveLength(4);
+		const files = fs
+			.readdirSync(__dirname)
+			.filter(file => file.startsWith("vendor-parts"));
+		expect(files).toHaveLength(2);
+	}));
@@ -0,0 +1,21 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	mode: "development",
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				parts: {
+					test: /part-[a-d]\.js/,
+					name: "vendor-parts",
+					minSize: 150,
+					maxSize: 250
+				}
+			}
+		}
+	}
+};
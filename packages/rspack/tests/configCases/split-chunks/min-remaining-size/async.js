@@ -0,0 +1,3 @@
+import a from "./lib-a";
+import b from "./lib-b";
+export default a + b;
@@ -0,0 +1,10 @@
+() => import(/* webpackChunkName: "async" */ "./async");
+
+import fs from "fs";
+import path from "path";
+
+it("should respect minRemainingSize unless enforceSizeThreshold is reached", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "./async.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "./lib-a.js"))).toBe(false);
+	expect(fs.existsSync(path.resolve(__dirname, "./lib-b.js"))).toBe(true);
+});
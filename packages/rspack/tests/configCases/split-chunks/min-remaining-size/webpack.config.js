@@ -0,0 +1,25 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			minSize: 1,
+			minRemainingSize: 100000,
+			cacheGroups: {
+				libA: {
+					test: /lib-a\.js/,
+					name: "lib-a"
+				},
+				libB: {
+					test: /lib-b\.js/,
+					name: "lib-b",
+					enforceSizeThreshold: 1
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1,11 @@
+() => import(/* webpackChunkName: "a" */ "./a");
+() => import(/* webpackChunkName: "b" */ "./b");
+
+import fs from "fs";
+import path from "path";
+
+it("should not split chunks when size reduction is too small", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "./a.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "./b.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "./shared.js"))).toBe(false);
+});
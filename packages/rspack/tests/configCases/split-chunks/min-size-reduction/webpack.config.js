@@ -0,0 +1,21 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			minSize: 1,
+			cacheGroups: {
+				shared: {
+					test: /shared\.js/,
+					name: "shared",
+					minChunks: 2,
+					minSizeReduction: 100000
+				}
+			}
+		}
+	}
+};
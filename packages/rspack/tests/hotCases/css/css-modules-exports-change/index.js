@@ -0,0 +1,15 @@
+import style from './index.module.css';
+
+it("css modules with changed exports should be accepted by the importer", (done) => {
+	expect(style.div).toMatch(/div/);
+	expect(style.span).toBeUndefined();
+	module.hot.accept("./index.module.css", () => {
+		expect(style.div).toBeUndefined();
+		expect(style.span).toMatch(/span/);
+		if (__webpack_require__.hmrC.css) {
+			expect(document.head.children[0].href).toContain("main.css?hmr");
+		}
+		done();
+	});
+	NEXT(require("../../update")(done));
+});
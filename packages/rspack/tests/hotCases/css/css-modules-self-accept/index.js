@@ -0,0 +1,12 @@
+import style from './index.module.css';
+
+it("css modules with unchanged exports should be updated without reload", (done) => {
+	expect(style.div).toBe("index-module-css__div");
+	NEXT(require("../../update")(done, true, () => {
+		expect(style.div).toBe("index-module-css__div");
+		if (__webpack_require__.hmrC.css) {
+			expect(document.head.children[0].href).toContain("main.css?hmr");
+		}
+		done();
+	}));
+});
@@ -0,0 +1,13 @@
+module.exports = {
+  entry: { 
+    main: './index.js',
+  },
+  module: {
+    rules: [
+      {
+        test: /\.module\.css$/,
+        type: 'css/module'
+      }
+    ]
+  }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+import module from "./module";
+
+it("should update a module shared by the main runtime and a worker runtime", done => {
+	const worker = new Worker(new URL("worker.js", import.meta.url));
+	let counter = 1;
+	worker.onmessage = ({ data: msg }) => {
+		switch (msg) {
+			case "next":
+				expect(module).toBe(counter);
+				counter++;
+				NEXT(
+					require("../../update")(done, true, () => {
+						worker.postMessage("next");
+					})
+				);
+				break;
+			case "done":
+				expect(module).toBe(42);
+				Promise.resolve(worker.terminate()).then(() => done(), done);
+				break;
+			default:
+				throw new Error(`Unexpected message: ${msg}`);
+		}
+	};
+	worker.postMessage("test");
+});
+
+import.meta.webpackHot.accept("./module");